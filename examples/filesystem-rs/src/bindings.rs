@@ -3,91 +3,304 @@
 
 // Generated by `wit-bindgen` 0.37.0. DO NOT EDIT!
 // Options used:
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_list_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")]
-    _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::list_directory(_rt::string_lift(bytes0));
-    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-    match result1 {
-        Ok(e) => {
-            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
-            let vec4 = e;
-            let len4 = vec4.len();
-            let layout4 = _rt::alloc::Layout::from_size_align_unchecked(vec4.len() * 8, 4);
-            let result4 = if layout4.size() != 0 {
-                let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
-                if ptr.is_null() {
-                    _rt::alloc::handle_alloc_error(layout4);
-                }
-                ptr
-            } else {
-                ::core::ptr::null_mut()
-            };
-            for (i, e) in vec4.into_iter().enumerate() {
-                let base = result4.add(i * 8);
-                {
-                    let vec3 = (e.into_bytes()).into_boxed_slice();
-                    let ptr3 = vec3.as_ptr().cast::<u8>();
-                    let len3 = vec3.len();
-                    ::core::mem::forget(vec3);
-                    *base.add(4).cast::<usize>() = len3;
-                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
-                }
-            }
-            *ptr2.add(8).cast::<usize>() = len4;
-            *ptr2.add(4).cast::<*mut u8>() = result4;
+/// Structured metadata about a file or directory.
+#[derive(Clone)]
+pub struct FileInfo {
+    /// The resolved path
+    pub path: _rt::String,
+    /// One of "File", "Directory", "Symlink" or "Unknown"
+    pub file_type: _rt::String,
+    /// Size in bytes
+    pub size_bytes: u64,
+    /// Whether the read-only permission flag is set
+    pub readonly: bool,
+    /// Last modification time in seconds since the Unix epoch, if available
+    pub modified_secs: Option<u64>,
+    /// Last modification time as an ISO-8601 UTC timestamp, if available
+    pub modified: Option<_rt::String>,
+    /// Last access time as an ISO-8601 UTC timestamp, if available
+    pub accessed: Option<_rt::String>,
+    /// Creation time as an ISO-8601 UTC timestamp, if the platform records it
+    pub created: Option<_rt::String>,
+    /// Unix permission bits in octal (e.g. "644"), when the platform exposes them
+    pub mode_octal: Option<_rt::String>,
+    /// For WebAssembly binaries, the little-endian version field after the \0asm magic: \
+    /// 1 for core modules, 0x1000d for components
+    pub wasm_module_version: Option<u32>,
+}
+impl ::core::fmt::Debug for FileInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FileInfo")
+            .field("path", &self.path)
+            .field("file-type", &self.file_type)
+            .field("size-bytes", &self.size_bytes)
+            .field("readonly", &self.readonly)
+            .field("modified-secs", &self.modified_secs)
+            .field("modified", &self.modified)
+            .field("accessed", &self.accessed)
+            .field("created", &self.created)
+            .field("mode-octal", &self.mode_octal)
+            .field("wasm-module-version", &self.wasm_module_version)
+            .finish()
+    }
+}
+/// Space usage of the filesystem containing a path.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DiskUsage {
+    /// Total size of the filesystem in bytes
+    pub total_bytes: u64,
+    /// Bytes available to unprivileged users
+    pub available_bytes: u64,
+    /// Bytes in use
+    pub used_bytes: u64,
+}
+impl ::core::fmt::Debug for DiskUsage {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("DiskUsage")
+            .field("total-bytes", &self.total_bytes)
+            .field("available-bytes", &self.available_bytes)
+            .field("used-bytes", &self.used_bytes)
+            .finish()
+    }
+}
+/// A summary of everything under a directory, from get-directory-stats.
+#[derive(Clone)]
+pub struct DirectoryStats {
+    /// Regular files, not counting symlinks to them
+    pub total_files: u64,
+    /// Subdirectories, not counting the directory itself
+    pub total_directories: u64,
+    pub total_symlinks: u64,
+    /// Combined size of the regular files
+    pub total_size_bytes: u64,
+    /// Relative path and size in bytes of the largest file
+    pub largest_file: Option<(_rt::String, u64)>,
+    /// Relative path and modification time, in seconds since the Unix epoch, of the file \
+    /// modified longest ago
+    pub oldest_file: Option<(_rt::String, u64)>,
+}
+impl ::core::fmt::Debug for DirectoryStats {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("DirectoryStats")
+            .field("total-files", &self.total_files)
+            .field("total-directories", &self.total_directories)
+            .field("total-symlinks", &self.total_symlinks)
+            .field("total-size-bytes", &self.total_size_bytes)
+            .field("largest-file", &self.largest_file)
+            .field("oldest-file", &self.oldest_file)
+            .finish()
+    }
+}
+/// An item exported by a WebAssembly binary, from wasm-component-exports.
+#[derive(Clone)]
+pub struct ExportEntry {
+    pub name: _rt::String,
+    /// "func", "table", "memory", "global" or "tag" for core modules; "func", "instance", \
+    /// "module", "component", "type" or "value" for components
+    pub kind: _rt::String,
+}
+impl ::core::fmt::Debug for ExportEntry {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("ExportEntry")
+            .field("name", &self.name)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+/// An item a WebAssembly binary imports, from wasm-component-imports.
+#[derive(Clone)]
+pub struct ImportEntry {
+    /// The module a core module imports from; empty for components, whose import names \
+    /// such as "wasi:cli/environment@0.2.0" already say where they come from
+    pub module: _rt::String,
+    pub name: _rt::String,
+    /// One of the kinds listed for export-entry
+    pub kind: _rt::String,
+}
+impl ::core::fmt::Debug for ImportEntry {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("ImportEntry")
+            .field("module", &self.module)
+            .field("name", &self.name)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+/// The kind of a directory entry.
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum EntryType {
+    File,
+    Dir,
+    Symlink,
+    Unknown,
+}
+impl ::core::fmt::Debug for EntryType {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            EntryType::File => f.debug_tuple("EntryType::File").finish(),
+            EntryType::Dir => f.debug_tuple("EntryType::Dir").finish(),
+            EntryType::Symlink => f.debug_tuple("EntryType::Symlink").finish(),
+            EntryType::Unknown => f.debug_tuple("EntryType::Unknown").finish(),
         }
-        Err(e) => {
-            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
-            let vec5 = (e.into_bytes()).into_boxed_slice();
-            let ptr5 = vec5.as_ptr().cast::<u8>();
-            let len5 = vec5.len();
-            ::core::mem::forget(vec5);
-            *ptr2.add(8).cast::<usize>() = len5;
-            *ptr2.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+    }
+}
+
+impl EntryType {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> EntryType {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
         }
-    };
-    ptr2
+
+        match val {
+            0 => EntryType::File,
+            1 => EntryType::Dir,
+            2 => EntryType::Symlink,
+            3 => EntryType::Unknown,
+
+            _ => panic!("invalid enum discriminant"),
+        }
+    }
 }
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_list_directory<T: Guest>(arg0: *mut u8) {
-    let l0 = i32::from(*arg0.add(0).cast::<u8>());
-    match l0 {
-        0 => {
-            let l1 = *arg0.add(4).cast::<*mut u8>();
-            let l2 = *arg0.add(8).cast::<usize>();
-            let base5 = l1;
-            let len5 = l2;
-            for i in 0..len5 {
-                let base = base5.add(i * 8);
-                {
-                    let l3 = *base.add(0).cast::<*mut u8>();
-                    let l4 = *base.add(4).cast::<usize>();
-                    _rt::cabi_dealloc(l3, l4, 1);
-                }
-            }
-            _rt::cabi_dealloc(base5, len5 * 8, 4);
+
+/// How to order directory entries.
+#[repr(u8)]
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SortOrder {
+    /// By file name
+    Name,
+    /// By size in bytes; directories and symlinks count as 0
+    Size,
+    /// By last modification time
+    Modified,
+    /// By file extension, ignoring case
+    Extension,
+}
+impl ::core::fmt::Debug for SortOrder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            SortOrder::Name => f.debug_tuple("SortOrder::Name").finish(),
+            SortOrder::Size => f.debug_tuple("SortOrder::Size").finish(),
+            SortOrder::Modified => f.debug_tuple("SortOrder::Modified").finish(),
+            SortOrder::Extension => f.debug_tuple("SortOrder::Extension").finish(),
         }
-        _ => {
-            let l6 = *arg0.add(4).cast::<*mut u8>();
-            let l7 = *arg0.add(8).cast::<usize>();
-            _rt::cabi_dealloc(l6, l7, 1);
+    }
+}
+
+impl SortOrder {
+    #[doc(hidden)]
+    pub unsafe fn _lift(val: u8) -> SortOrder {
+        if !cfg!(debug_assertions) {
+            return ::core::mem::transmute(val);
+        }
+
+        match val {
+            0 => SortOrder::Name,
+            1 => SortOrder::Size,
+            2 => SortOrder::Modified,
+            3 => SortOrder::Extension,
+
+            _ => panic!("invalid enum discriminant"),
         }
     }
 }
+
+/// A single entry in a directory listing.
+#[derive(Clone)]
+pub struct DirEntry {
+    /// The entry's file name
+    pub name: _rt::String,
+    /// Path relative to the listed directory, using "/" as separator
+    pub relative_path: _rt::String,
+    /// The kind of entry; symlinks are reported as such and not followed
+    pub entry_type: EntryType,
+    /// Size in bytes, 0 for directories and symlinks
+    pub size_bytes: u64,
+}
+impl ::core::fmt::Debug for DirEntry {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("DirEntry")
+            .field("name", &self.name)
+            .field("relative-path", &self.relative_path)
+            .field("entry-type", &self.entry_type)
+            .field("size-bytes", &self.size_bytes)
+            .finish()
+    }
+}
+/// The text of a file decoded from the encoding that was detected.
+#[derive(Clone)]
+pub struct FileContent {
+    /// The decoded text, without any byte order mark
+    pub text: _rt::String,
+    /// One of "utf-8", "utf-8-bom", "utf-16le", "utf-16be" or "latin-1"
+    pub encoding_detected: _rt::String,
+}
+impl ::core::fmt::Debug for FileContent {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FileContent")
+            .field("text", &self.text)
+            .field("encoding-detected", &self.encoding_detected)
+            .finish()
+    }
+}
+/// Part of a text file returned by read-file-range.
+#[derive(Clone)]
+pub struct TextRange {
+    /// The text that was read
+    pub text: _rt::String,
+    /// How many bytes were read, fewer than requested when the range passed the end of the file
+    pub bytes_read: u64,
+}
+impl ::core::fmt::Debug for TextRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("TextRange")
+            .field("text", &self.text)
+            .field("bytes-read", &self.bytes_read)
+            .finish()
+    }
+}
+/// Part of a file returned by read-file-bytes-range.
+#[derive(Clone)]
+pub struct BytesRange {
+    /// The bytes that were read
+    pub data: _rt::Vec<u8>,
+    /// How many bytes were read, fewer than requested when the range passed the end of the file
+    pub bytes_read: u64,
+}
+impl ::core::fmt::Debug for BytesRange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("BytesRange")
+            .field("data", &self.data)
+            .field("bytes-read", &self.bytes_read)
+            .finish()
+    }
+}
+/// The outcome of ensure-directory.
+#[derive(Clone)]
+pub struct EnsureResult {
+    /// The resolved path of the directory
+    pub path: _rt::String,
+    /// Whether the directory was already there, as opposed to created by this call
+    pub already_existed: bool,
+}
+impl ::core::fmt::Debug for EnsureResult {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("EnsureResult")
+            .field("path", &self.path)
+            .field("already-existed", &self.already_existed)
+            .finish()
+    }
+}
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_read_file_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+pub unsafe fn _export_set_root_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::read_file(_rt::string_lift(bytes0));
+    let result1 = T::set_root_directory(_rt::string_lift(bytes0));
     let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
     match result1 {
         Ok(e) => {
@@ -113,7 +326,7 @@ pub unsafe fn _export_read_file_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *m
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_read_file<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_set_root_directory<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -130,45 +343,58 @@ pub unsafe fn __post_return_read_file<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_write_file_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-) -> *mut u8 {
+pub unsafe fn _export_get_root_directory_cabi<T: Guest>() -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let result2 = T::write_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
-    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-    match result2 {
+    let result0 = T::get_root_directory();
+    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    let vec2 = (result0.into_bytes()).into_boxed_slice();
+    let ptr2 = vec2.as_ptr().cast::<u8>();
+    let len2 = vec2.len();
+    ::core::mem::forget(vec2);
+    *ptr1.add(4).cast::<usize>() = len2;
+    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+    ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_root_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0.add(4).cast::<usize>();
+    _rt::cabi_dealloc(l0, l1, 1);
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_set_write_size_limit_cabi<T: Guest>(arg0: i64) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let result0 = T::set_write_size_limit(arg0 as u64);
+    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result0 {
         Ok(e) => {
-            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
-            let vec4 = (e.into_bytes()).into_boxed_slice();
-            let ptr4 = vec4.as_ptr().cast::<u8>();
-            let len4 = vec4.len();
-            ::core::mem::forget(vec4);
-            *ptr3.add(8).cast::<usize>() = len4;
-            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+            let vec2 = (e.into_bytes()).into_boxed_slice();
+            let ptr2 = vec2.as_ptr().cast::<u8>();
+            let len2 = vec2.len();
+            ::core::mem::forget(vec2);
+            *ptr1.add(8).cast::<usize>() = len2;
+            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
         }
         Err(e) => {
-            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
-            let vec5 = (e.into_bytes()).into_boxed_slice();
-            let ptr5 = vec5.as_ptr().cast::<u8>();
-            let len5 = vec5.len();
-            ::core::mem::forget(vec5);
-            *ptr3.add(8).cast::<usize>() = len5;
-            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr1.add(8).cast::<usize>() = len3;
+            *ptr1.add(4).cast::<*mut u8>() = ptr3.cast_mut();
         }
     };
-    ptr3
+    ptr1
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_write_file<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_set_write_size_limit<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -185,12 +411,12 @@ pub unsafe fn __post_return_write_file<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_create_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+pub unsafe fn _export_set_jail_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::create_directory(_rt::string_lift(bytes0));
+    let result1 = T::set_jail_directory(_rt::string_lift(bytes0));
     let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
     match result1 {
         Ok(e) => {
@@ -216,7 +442,7 @@ pub unsafe fn _export_create_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_create_directory<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_set_jail_directory<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -233,67 +459,34 @@ pub unsafe fn __post_return_create_directory<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_move_path_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-) -> *mut u8 {
+pub unsafe fn _export_clear_jail_cabi<T: Guest>() -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let result2 = T::move_path(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
-    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-    match result2 {
-        Ok(e) => {
-            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
-            let vec4 = (e.into_bytes()).into_boxed_slice();
-            let ptr4 = vec4.as_ptr().cast::<u8>();
-            let len4 = vec4.len();
-            ::core::mem::forget(vec4);
-            *ptr3.add(8).cast::<usize>() = len4;
-            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
-        }
-        Err(e) => {
-            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
-            let vec5 = (e.into_bytes()).into_boxed_slice();
-            let ptr5 = vec5.as_ptr().cast::<u8>();
-            let len5 = vec5.len();
-            ::core::mem::forget(vec5);
-            *ptr3.add(8).cast::<usize>() = len5;
-            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
-        }
-    };
-    ptr3
+    let result0 = T::clear_jail();
+    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    let vec2 = (result0.into_bytes()).into_boxed_slice();
+    let ptr2 = vec2.as_ptr().cast::<u8>();
+    let len2 = vec2.len();
+    ::core::mem::forget(vec2);
+    *ptr1.add(4).cast::<usize>() = len2;
+    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+    ptr1
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_move_path<T: Guest>(arg0: *mut u8) {
-    let l0 = i32::from(*arg0.add(0).cast::<u8>());
-    match l0 {
-        0 => {
-            let l1 = *arg0.add(4).cast::<*mut u8>();
-            let l2 = *arg0.add(8).cast::<usize>();
-            _rt::cabi_dealloc(l1, l2, 1);
-        }
-        _ => {
-            let l3 = *arg0.add(4).cast::<*mut u8>();
-            let l4 = *arg0.add(8).cast::<usize>();
-            _rt::cabi_dealloc(l3, l4, 1);
-        }
-    }
+pub unsafe fn __post_return_clear_jail<T: Guest>(arg0: *mut u8) {
+    let l0 = *arg0.add(0).cast::<*mut u8>();
+    let l1 = *arg0.add(4).cast::<usize>();
+    _rt::cabi_dealloc(l0, l1, 1);
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_delete_file_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+pub unsafe fn _export_normalize_path_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::delete_file(_rt::string_lift(bytes0));
+    let result1 = T::normalize_path(_rt::string_lift(bytes0));
     let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
     match result1 {
         Ok(e) => {
@@ -319,7 +512,7 @@ pub unsafe fn _export_delete_file_cabi<T: Guest>(arg0: *mut u8, arg1: usize) ->
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_delete_file<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_normalize_path<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -336,12 +529,15 @@ pub unsafe fn __post_return_delete_file<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_delete_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+pub unsafe fn _export_get_environment_variable_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::delete_directory(_rt::string_lift(bytes0));
+    let result1 = T::get_environment_variable(_rt::string_lift(bytes0));
     let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
     match result1 {
         Ok(e) => {
@@ -367,7 +563,7 @@ pub unsafe fn _export_delete_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_delete_directory<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_get_environment_variable<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -384,58 +580,4606 @@ pub unsafe fn __post_return_delete_directory<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_file_exists_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+pub unsafe fn _export_list_environment_variables_cabi<T: Guest>() -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::file_exists(_rt::string_lift(bytes0));
-    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-    match result1 {
+    let result0 = T::list_environment_variables();
+    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result0 {
         Ok(e) => {
-            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
-            *ptr2.add(4).cast::<u8>() = (match e {
-                true => 1,
-                false => 0,
-            }) as u8;
+            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = e;
+            let len3 = vec3.len();
+            let layout3 = _rt::alloc::Layout::from_size_align_unchecked(vec3.len() * 8, 4);
+            let result3 = if layout3.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout3);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec3.into_iter().enumerate() {
+                let base = result3.add(i * 8);
+                {
+                    let vec2 = (e.into_bytes()).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *base.add(4).cast::<usize>() = len2;
+                    *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                }
+            }
+            *ptr1.add(8).cast::<usize>() = len3;
+            *ptr1.add(4).cast::<*mut u8>() = result3;
         }
         Err(e) => {
-            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
-            let vec3 = (e.into_bytes()).into_boxed_slice();
-            let ptr3 = vec3.as_ptr().cast::<u8>();
-            let len3 = vec3.len();
-            ::core::mem::forget(vec3);
-            *ptr2.add(8).cast::<usize>() = len3;
-            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr1.add(8).cast::<usize>() = len4;
+            *ptr1.add(4).cast::<*mut u8>() = ptr4.cast_mut();
         }
     };
-    ptr2
+    ptr1
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_file_exists<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_list_environment_variables<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
-        0 => (),
-        _ => {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base5 = l1;
+            let len5 = l2;
+            for i in 0..len5 {
+                let base = base5.add(i * 8);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+            }
+            _rt::cabi_dealloc(base5, len5 * 8, 4);
+        }
+        _ => {
+            let l6 = *arg0.add(4).cast::<*mut u8>();
+            let l7 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_temp_directory_cabi<T: Guest>() -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let result0 = T::get_temp_directory();
+    let ptr1 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result0 {
+        Ok(e) => {
+            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+            let vec2 = (e.into_bytes()).into_boxed_slice();
+            let ptr2 = vec2.as_ptr().cast::<u8>();
+            let len2 = vec2.len();
+            ::core::mem::forget(vec2);
+            *ptr1.add(8).cast::<usize>() = len2;
+            *ptr1.add(4).cast::<*mut u8>() = ptr2.cast_mut();
+        }
+        Err(e) => {
+            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr1.add(8).cast::<usize>() = len3;
+            *ptr1.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr1
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_temp_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_list_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::list_directory(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec6 = e;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 32, 8);
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * 32);
+                {
+                    let DirEntry {
+                        name: name3,
+                        relative_path: relative_path3,
+                        entry_type: entry_type3,
+                        size_bytes: size_bytes3,
+                    } = e;
+                    let vec4 = (name3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base.add(4).cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                    let vec5 = (relative_path3.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(12).cast::<usize>() = len5;
+                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                    *base.add(16).cast::<u8>() = (entry_type3.clone() as i32) as u8;
+                    *base.add(24).cast::<i64>() = _rt::as_i64(size_bytes3);
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len6;
+            *ptr2.add(4).cast::<*mut u8>() = result6;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr2.add(8).cast::<usize>() = len7;
+            *ptr2.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_list_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base7 = l1;
+            let len7 = l2;
+            for i in 0..len7 {
+                let base = base7.add(i * 32);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base.add(8).cast::<*mut u8>();
+                    let l6 = *base.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+            _rt::cabi_dealloc(base7, len7 * 32, 8);
+        }
+        _ => {
+            let l8 = *arg0.add(4).cast::<*mut u8>();
+            let l9 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l8, l9, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_list_directory_visible_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::list_directory_visible(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec6 = e;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 32, 8);
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * 32);
+                {
+                    let DirEntry {
+                        name: name3,
+                        relative_path: relative_path3,
+                        entry_type: entry_type3,
+                        size_bytes: size_bytes3,
+                    } = e;
+                    let vec4 = (name3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base.add(4).cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                    let vec5 = (relative_path3.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(12).cast::<usize>() = len5;
+                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                    *base.add(16).cast::<u8>() = (entry_type3.clone() as i32) as u8;
+                    *base.add(24).cast::<i64>() = _rt::as_i64(size_bytes3);
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len6;
+            *ptr2.add(4).cast::<*mut u8>() = result6;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr2.add(8).cast::<usize>() = len7;
+            *ptr2.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_list_directory_visible<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base7 = l1;
+            let len7 = l2;
+            for i in 0..len7 {
+                let base = base7.add(i * 32);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base.add(8).cast::<*mut u8>();
+                    let l6 = *base.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+            _rt::cabi_dealloc(base7, len7 * 32, 8);
+        }
+        _ => {
+            let l8 = *arg0.add(4).cast::<*mut u8>();
+            let l9 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l8, l9, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_list_directory_all_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::list_directory_all(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec6 = e;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 32, 8);
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * 32);
+                {
+                    let DirEntry {
+                        name: name3,
+                        relative_path: relative_path3,
+                        entry_type: entry_type3,
+                        size_bytes: size_bytes3,
+                    } = e;
+                    let vec4 = (name3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base.add(4).cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                    let vec5 = (relative_path3.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(12).cast::<usize>() = len5;
+                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                    *base.add(16).cast::<u8>() = (entry_type3.clone() as i32) as u8;
+                    *base.add(24).cast::<i64>() = _rt::as_i64(size_bytes3);
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len6;
+            *ptr2.add(4).cast::<*mut u8>() = result6;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr2.add(8).cast::<usize>() = len7;
+            *ptr2.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_list_directory_all<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base7 = l1;
+            let len7 = l2;
+            for i in 0..len7 {
+                let base = base7.add(i * 32);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base.add(8).cast::<*mut u8>();
+                    let l6 = *base.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+            _rt::cabi_dealloc(base7, len7 * 32, 8);
+        }
+        _ => {
+            let l8 = *arg0.add(4).cast::<*mut u8>();
+            let l9 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l8, l9, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_list_directory_sorted_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+    arg3: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::list_directory_sorted(
+        _rt::string_lift(bytes0),
+        SortOrder::_lift(arg2 as u8),
+        _rt::bool_lift(arg3 as u8),
+    );
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec6 = e;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 32, 8);
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * 32);
+                {
+                    let DirEntry {
+                        name: name3,
+                        relative_path: relative_path3,
+                        entry_type: entry_type3,
+                        size_bytes: size_bytes3,
+                    } = e;
+                    let vec4 = (name3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base.add(4).cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                    let vec5 = (relative_path3.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(12).cast::<usize>() = len5;
+                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                    *base.add(16).cast::<u8>() = (entry_type3.clone() as i32) as u8;
+                    *base.add(24).cast::<i64>() = _rt::as_i64(size_bytes3);
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len6;
+            *ptr2.add(4).cast::<*mut u8>() = result6;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr2.add(8).cast::<usize>() = len7;
+            *ptr2.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_list_directory_sorted<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base7 = l1;
+            let len7 = l2;
+            for i in 0..len7 {
+                let base = base7.add(i * 32);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base.add(8).cast::<*mut u8>();
+                    let l6 = *base.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+            _rt::cabi_dealloc(base7, len7 * 32, 8);
+        }
+        _ => {
+            let l8 = *arg0.add(4).cast::<*mut u8>();
+            let l9 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l8, l9, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_list_directory_formatted_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::list_directory_formatted(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = e;
+            let len4 = vec4.len();
+            let layout4 = _rt::alloc::Layout::from_size_align_unchecked(vec4.len() * 8, 4);
+            let result4 = if layout4.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout4);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec4.into_iter().enumerate() {
+                let base = result4.add(i * 8);
+                {
+                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *base.add(4).cast::<usize>() = len3;
+                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = result4;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr2.add(8).cast::<usize>() = len5;
+            *ptr2.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_list_directory_formatted<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base5 = l1;
+            let len5 = l2;
+            for i in 0..len5 {
+                let base = base5.add(i * 8);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+            }
+            _rt::cabi_dealloc(base5, len5 * 8, 4);
+        }
+        _ => {
+            let l6 = *arg0.add(4).cast::<*mut u8>();
+            let l7 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_list_directory_recursive_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::list_directory_recursive(_rt::string_lift(bytes0), arg2 as u32);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec6 = e;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 32, 8);
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * 32);
+                {
+                    let DirEntry {
+                        name: name3,
+                        relative_path: relative_path3,
+                        entry_type: entry_type3,
+                        size_bytes: size_bytes3,
+                    } = e;
+                    let vec4 = (name3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base.add(4).cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                    let vec5 = (relative_path3.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(12).cast::<usize>() = len5;
+                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                    *base.add(16).cast::<u8>() = (entry_type3.clone() as i32) as u8;
+                    *base.add(24).cast::<i64>() = _rt::as_i64(size_bytes3);
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len6;
+            *ptr2.add(4).cast::<*mut u8>() = result6;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr2.add(8).cast::<usize>() = len7;
+            *ptr2.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_list_directory_recursive<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base7 = l1;
+            let len7 = l2;
+            for i in 0..len7 {
+                let base = base7.add(i * 32);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base.add(8).cast::<*mut u8>();
+                    let l6 = *base.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+            _rt::cabi_dealloc(base7, len7 * 32, 8);
+        }
+        _ => {
+            let l8 = *arg0.add(4).cast::<*mut u8>();
+            let l9 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l8, l9, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_file_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::read_file(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_file_with_encoding_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::read_file_with_encoding(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let FileContent {
+                text: text3,
+                encoding_detected: encoding_detected3,
+            } = e;
+            let vec4 = (text3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+            let vec5 = (encoding_detected3.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr2.add(16).cast::<usize>() = len5;
+            *ptr2.add(12).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr2.add(8).cast::<usize>() = len6;
+            *ptr2.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_file_with_encoding<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+            let l3 = *arg0.add(12).cast::<*mut u8>();
+            let l4 = *arg0.add(16).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+        _ => {
+            let l5 = *arg0.add(4).cast::<*mut u8>();
+            let l6 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l5, l6, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_file_bytes_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::read_file_bytes(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_file_bytes<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = *arg0.add(4).cast::<*mut u8>();
+            let l5 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l4, l5, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_file_range_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i64,
+    arg3: i64,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::read_file_range(_rt::string_lift(bytes0), arg2 as u64, arg3 as u64);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let TextRange {
+                text: text3,
+                bytes_read: bytes_read3,
+            } = e;
+            let vec4 = (text3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(12).cast::<usize>() = len4;
+            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+            *ptr2.add(16).cast::<i64>() = _rt::as_i64(bytes_read3);
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr2.add(12).cast::<usize>() = len5;
+            *ptr2.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_file_range<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(8).cast::<*mut u8>();
+            let l4 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_file_bytes_range_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i64,
+    arg3: i64,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::read_file_bytes_range(_rt::string_lift(bytes0), arg2 as u64, arg3 as u64);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let BytesRange {
+                data: data3,
+                bytes_read: bytes_read3,
+            } = e;
+            let vec4 = (data3).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(12).cast::<usize>() = len4;
+            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+            *ptr2.add(16).cast::<i64>() = _rt::as_i64(bytes_read3);
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr2.add(12).cast::<usize>() = len5;
+            *ptr2.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_file_bytes_range<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0.add(12).cast::<usize>();
+            let base3 = l1;
+            let len3 = l2;
+            _rt::cabi_dealloc(base3, len3 * 1, 1);
+        }
+        _ => {
+            let l4 = *arg0.add(8).cast::<*mut u8>();
+            let l5 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l4, l5, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_lines_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+    arg3: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::read_lines(
+        _rt::string_lift(bytes0),
+        _rt::bool_lift(arg2 as u8),
+        _rt::bool_lift(arg3 as u8),
+    );
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = e;
+            let len4 = vec4.len();
+            let layout4 = _rt::alloc::Layout::from_size_align_unchecked(vec4.len() * 8, 4);
+            let result4 = if layout4.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout4);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec4.into_iter().enumerate() {
+                let base = result4.add(i * 8);
+                {
+                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *base.add(4).cast::<usize>() = len3;
+                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = result4;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr2.add(8).cast::<usize>() = len5;
+            *ptr2.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_lines<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base5 = l1;
+            let len5 = l2;
+            for i in 0..len5 {
+                let base = base5.add(i * 8);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+            }
+            _rt::cabi_dealloc(base5, len5 * 8, 4);
+        }
+        _ => {
+            let l6 = *arg0.add(4).cast::<*mut u8>();
+            let l7 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_lines_range_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i64,
+    arg3: i64,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::read_lines_range(_rt::string_lift(bytes0), arg2 as u64, arg3 as u64);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = e;
+            let len4 = vec4.len();
+            let layout4 = _rt::alloc::Layout::from_size_align_unchecked(vec4.len() * 8, 4);
+            let result4 = if layout4.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout4);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec4.into_iter().enumerate() {
+                let base = result4.add(i * 8);
+                {
+                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *base.add(4).cast::<usize>() = len3;
+                    *base.add(0).cast::<*mut u8>() = ptr3.cast_mut();
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = result4;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr2.add(8).cast::<usize>() = len5;
+            *ptr2.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_lines_range<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base5 = l1;
+            let len5 = l2;
+            for i in 0..len5 {
+                let base = base5.add(i * 8);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+            }
+            _rt::cabi_dealloc(base5, len5 * 8, 4);
+        }
+        _ => {
+            let l6 = *arg0.add(4).cast::<*mut u8>();
+            let l7 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_json_value_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::read_json_value(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_json_value<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_write_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::write_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_write_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_write_file_bounded_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: i64,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::write_file_bounded(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        arg4 as u64,
+    );
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_write_file_bounded<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_atomic_write_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::atomic_write_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_atomic_write_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_write_lines_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let base4 = arg2;
+    let len4 = arg3;
+    let mut result4 = _rt::Vec::with_capacity(len4);
+    for i in 0..len4 {
+        let base = base4.add(i * 8);
+        let e4 = {
+            let l1 = *base.add(0).cast::<*mut u8>();
+            let l2 = *base.add(4).cast::<usize>();
+            let len3 = l2;
+            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+
+            _rt::string_lift(bytes3)
+        };
+        result4.push(e4);
+    }
+    _rt::cabi_dealloc(base4, len4 * 8, 4);
+    let len5 = arg5;
+    let bytes5 = _rt::Vec::from_raw_parts(arg4.cast(), len5, len5);
+    let result6 = T::write_lines(_rt::string_lift(bytes0), result4, _rt::string_lift(bytes5));
+    let ptr7 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result6 {
+        Ok(e) => {
+            *ptr7.add(0).cast::<u8>() = (0i32) as u8;
+            let vec8 = (e.into_bytes()).into_boxed_slice();
+            let ptr8 = vec8.as_ptr().cast::<u8>();
+            let len8 = vec8.len();
+            ::core::mem::forget(vec8);
+            *ptr7.add(8).cast::<usize>() = len8;
+            *ptr7.add(4).cast::<*mut u8>() = ptr8.cast_mut();
+        }
+        Err(e) => {
+            *ptr7.add(0).cast::<u8>() = (1i32) as u8;
+            let vec9 = (e.into_bytes()).into_boxed_slice();
+            let ptr9 = vec9.as_ptr().cast::<u8>();
+            let len9 = vec9.len();
+            ::core::mem::forget(vec9);
+            *ptr7.add(8).cast::<usize>() = len9;
+            *ptr7.add(4).cast::<*mut u8>() = ptr9.cast_mut();
+        }
+    };
+    ptr7
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_write_lines<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_write_json_value_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let len2 = arg5;
+    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+    let result3 = T::write_json_value(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        _rt::string_lift(bytes2),
+    );
+    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result3 {
+        Ok(e) => {
+            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr4.add(8).cast::<usize>() = len5;
+            *ptr4.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+        Err(e) => {
+            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr4.add(8).cast::<usize>() = len6;
+            *ptr4.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr4
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_write_json_value<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_merge_json_files_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+    arg6: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let len2 = arg5;
+    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+    let result3 = T::merge_json_files(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        _rt::string_lift(bytes2),
+        _rt::bool_lift(arg6 as u8),
+    );
+    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result3 {
+        Ok(e) => {
+            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr4.add(8).cast::<usize>() = len5;
+            *ptr4.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+        Err(e) => {
+            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr4.add(8).cast::<usize>() = len6;
+            *ptr4.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr4
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_merge_json_files<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_replace_in_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+    arg6: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let len2 = arg5;
+    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+    let result3 = T::replace_in_file(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        _rt::string_lift(bytes2),
+        arg6 as u32,
+    );
+    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result3 {
+        Ok(e) => {
+            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr4.add(8).cast::<usize>() = len5;
+            *ptr4.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+        Err(e) => {
+            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr4.add(8).cast::<usize>() = len6;
+            *ptr4.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr4
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_replace_in_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_replace_regex_in_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+    arg6: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let len2 = arg5;
+    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+    let result3 = T::replace_regex_in_file(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        _rt::string_lift(bytes2),
+        arg6 as u32,
+    );
+    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result3 {
+        Ok(e) => {
+            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr4.add(8).cast::<usize>() = len5;
+            *ptr4.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+        Err(e) => {
+            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr4.add(8).cast::<usize>() = len6;
+            *ptr4.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr4
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_replace_regex_in_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_insert_line_at_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i64,
+    arg3: *mut u8,
+    arg4: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg4;
+    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+    let result2 = T::insert_line_at(
+        _rt::string_lift(bytes0),
+        arg2 as u64,
+        _rt::string_lift(bytes1),
+    );
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_insert_line_at<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_delete_line_at_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i64,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::delete_line_at(_rt::string_lift(bytes0), arg2 as u64);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_delete_line_at<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_write_file_bytes_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let result2 = T::write_file_bytes(
+        _rt::string_lift(bytes0),
+        _rt::Vec::from_raw_parts(arg2.cast(), len1, len1),
+    );
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_write_file_bytes<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_append_to_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::append_to_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_append_to_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_truncate_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i64,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::truncate_file(_rt::string_lift(bytes0), arg2 as u64);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_truncate_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_rotate_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i64,
+    arg3: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::rotate_file(_rt::string_lift(bytes0), arg2 as u64, arg3 as u32);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_rotate_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_create_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::create_directory(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_create_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_ensure_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::ensure_directory(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let EnsureResult {
+                path: path3,
+                already_existed: already_existed3,
+            } = e;
+            let vec4 = (path3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+            *ptr2.add(12).cast::<u8>() = (match already_existed3 {
+                true => 1,
+                false => 0,
+            }) as u8;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr2.add(8).cast::<usize>() = len5;
+            *ptr2.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_ensure_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_create_temp_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::create_temp_directory(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_create_temp_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_delete_temp_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::delete_temp_directory(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_delete_temp_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_move_path_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::move_path(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_move_path<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_copy_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::copy_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_copy_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_copy_directory_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::copy_directory(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_copy_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_zip_directory_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::zip_directory(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_zip_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_unzip_to_directory_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::unzip_to_directory(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_unzip_to_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_create_symlink_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::create_symlink(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_create_symlink<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_create_hard_link_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::create_hard_link(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_create_hard_link<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_read_symlink_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::read_symlink(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_read_symlink<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_delete_file_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::delete_file(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_delete_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_delete_directory_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::delete_directory(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_delete_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_delete_directory_recursive_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::delete_directory_recursive(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_delete_directory_recursive<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_set_permissions_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::set_permissions(_rt::string_lift(bytes0), _rt::bool_lift(arg2 as u8));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_set_permissions<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_set_unix_permissions_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::set_unix_permissions(_rt::string_lift(bytes0), arg2 as u32);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_set_unix_permissions<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_file_exists_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::file_exists(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(4).cast::<u8>() = (match e {
+                true => 1,
+                false => 0,
+            }) as u8;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_file_exists<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_directory_exists_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::directory_exists(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(4).cast::<u8>() = (match e {
+                true => 1,
+                false => 0,
+            }) as u8;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_directory_exists<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_symlink_exists_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::symlink_exists(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(4).cast::<u8>() = (match e {
+                true => 1,
+                false => 0,
+            }) as u8;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_symlink_exists<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_any_path_exists_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::any_path_exists(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(4).cast::<u8>() = (match e {
+                true => 1,
+                false => 0,
+            }) as u8;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_any_path_exists<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_directory_tree_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+    arg3: *mut u8,
+    arg4: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg4;
+    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+    let result2 = T::get_directory_tree(
+        _rt::string_lift(bytes0),
+        arg2 as u32,
+        _rt::string_lift(bytes1),
+    );
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_directory_tree<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_directory_tree_with_sizes_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_directory_tree_with_sizes(_rt::string_lift(bytes0), arg2 as u32);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_directory_tree_with_sizes<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_directory_tree_json_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_directory_tree_json(_rt::string_lift(bytes0), arg2 as u32);
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_directory_tree_json<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_directory_tree_filtered_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: i32,
+    arg3: *mut u8,
+    arg4: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg4;
+    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+    let result2 = T::get_directory_tree_filtered(
+        _rt::string_lift(bytes0),
+        arg2 as u32,
+        _rt::string_lift(bytes1),
+    );
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_directory_tree_filtered<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_search_file_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let len2 = arg5;
+    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+    let result3 = T::search_file(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        _rt::string_lift(bytes2),
+    );
+    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result3 {
+        Ok(e) => {
+            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr4.add(8).cast::<usize>() = len5;
+            *ptr4.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+        Err(e) => {
+            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr4.add(8).cast::<usize>() = len6;
+            *ptr4.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr4
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_search_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_search_file_relative_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let len2 = arg5;
+    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+    let result3 = T::search_file_relative(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        _rt::string_lift(bytes2),
+    );
+    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result3 {
+        Ok(e) => {
+            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr4.add(8).cast::<usize>() = len5;
+            *ptr4.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+        Err(e) => {
+            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr4.add(8).cast::<usize>() = len6;
+            *ptr4.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr4
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_search_file_relative<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_find_files_by_extension_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::find_files_by_extension(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec5 = e;
+            let len5 = vec5.len();
+            let layout5 = _rt::alloc::Layout::from_size_align_unchecked(vec5.len() * 8, 4);
+            let result5 = if layout5.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout5);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec5.into_iter().enumerate() {
+                let base = result5.add(i * 8);
+                {
+                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base.add(4).cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                }
+            }
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = result5;
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr3.add(8).cast::<usize>() = len6;
+            *ptr3.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_find_files_by_extension<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base5 = l1;
+            let len5 = l2;
+            for i in 0..len5 {
+                let base = base5.add(i * 8);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+            }
+            _rt::cabi_dealloc(base5, len5 * 8, 4);
+        }
+        _ => {
+            let l6 = *arg0.add(4).cast::<*mut u8>();
+            let l7 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_search_file_content_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+    arg6: i32,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let len2 = arg5;
+    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+    let result3 = T::search_file_content(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        _rt::string_lift(bytes2),
+        arg6 as u32,
+    );
+    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result3 {
+        Ok(e) => {
+            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+            let vec6 = e;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 8, 4);
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * 8);
+                {
+                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(4).cast::<usize>() = len5;
+                    *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
+                }
+            }
+            *ptr4.add(8).cast::<usize>() = len6;
+            *ptr4.add(4).cast::<*mut u8>() = result6;
+        }
+        Err(e) => {
+            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr4.add(8).cast::<usize>() = len7;
+            *ptr4.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+        }
+    };
+    ptr4
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_search_file_content<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base5 = l1;
+            let len5 = l2;
+            for i in 0..len5 {
+                let base = base5.add(i * 8);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+            }
+            _rt::cabi_dealloc(base5, len5 * 8, 4);
+        }
+        _ => {
+            let l6 = *arg0.add(4).cast::<*mut u8>();
+            let l7 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_count_matching_lines_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::count_matching_lines(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr3.add(8).cast::<i64>() = _rt::as_i64(e);
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(12).cast::<usize>() = len4;
+            *ptr3.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_count_matching_lines<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_count_matching_lines_in_directory_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 =
+        T::count_matching_lines_in_directory(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec6 = e;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 16, 8);
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * 16);
+                {
+                    let (t4_0, t4_1) = e;
+                    let vec5 = (t4_0.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(4).cast::<usize>() = len5;
+                    *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
+                    *base.add(8).cast::<i64>() = _rt::as_i64(t4_1);
+                }
+            }
+            *ptr3.add(8).cast::<usize>() = len6;
+            *ptr3.add(4).cast::<*mut u8>() = result6;
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr3.add(8).cast::<usize>() = len7;
+            *ptr3.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_count_matching_lines_in_directory<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base5 = l1;
+            let len5 = l2;
+            for i in 0..len5 {
+                let base = base5.add(i * 16);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                }
+            }
+            _rt::cabi_dealloc(base5, len5 * 16, 8);
+        }
+        _ => {
+            let l6 = *arg0.add(4).cast::<*mut u8>();
+            let l7 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l6, l7, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_file_info_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_file_info(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_file_info<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_file_info_structured_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_file_info_structured(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let FileInfo {
+                path: path3,
+                file_type: file_type3,
+                size_bytes: size_bytes3,
+                readonly: readonly3,
+                modified_secs: modified_secs3,
+                modified: modified3,
+                accessed: accessed3,
+                created: created3,
+                mode_octal: mode_octal3,
+                wasm_module_version: wasm_module_version3,
+            } = e;
+            let vec4 = (path3.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(12).cast::<usize>() = len4;
+            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+            let vec5 = (file_type3.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr2.add(20).cast::<usize>() = len5;
+            *ptr2.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+            *ptr2.add(24).cast::<i64>() = _rt::as_i64(size_bytes3);
+            *ptr2.add(32).cast::<u8>() = (match readonly3 {
+                true => 1,
+                false => 0,
+            }) as u8;
+            match modified_secs3 {
+                Some(e) => {
+                    *ptr2.add(40).cast::<u8>() = (1i32) as u8;
+                    *ptr2.add(48).cast::<i64>() = _rt::as_i64(e);
+                }
+                None => {
+                    *ptr2.add(40).cast::<u8>() = (0i32) as u8;
+                }
+            };
+            match modified3 {
+                Some(e) => {
+                    *ptr2.add(56).cast::<u8>() = (1i32) as u8;
+                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *ptr2.add(64).cast::<usize>() = len6;
+                    *ptr2.add(60).cast::<*mut u8>() = ptr6.cast_mut();
+                }
+                None => {
+                    *ptr2.add(56).cast::<u8>() = (0i32) as u8;
+                }
+            };
+            match accessed3 {
+                Some(e) => {
+                    *ptr2.add(68).cast::<u8>() = (1i32) as u8;
+                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2.add(76).cast::<usize>() = len7;
+                    *ptr2.add(72).cast::<*mut u8>() = ptr7.cast_mut();
+                }
+                None => {
+                    *ptr2.add(68).cast::<u8>() = (0i32) as u8;
+                }
+            };
+            match created3 {
+                Some(e) => {
+                    *ptr2.add(80).cast::<u8>() = (1i32) as u8;
+                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                    let len8 = vec8.len();
+                    ::core::mem::forget(vec8);
+                    *ptr2.add(88).cast::<usize>() = len8;
+                    *ptr2.add(84).cast::<*mut u8>() = ptr8.cast_mut();
+                }
+                None => {
+                    *ptr2.add(80).cast::<u8>() = (0i32) as u8;
+                }
+            };
+            match mode_octal3 {
+                Some(e) => {
+                    *ptr2.add(92).cast::<u8>() = (1i32) as u8;
+                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr2.add(100).cast::<usize>() = len9;
+                    *ptr2.add(96).cast::<*mut u8>() = ptr9.cast_mut();
+                }
+                None => {
+                    *ptr2.add(92).cast::<u8>() = (0i32) as u8;
+                }
+            };
+            match wasm_module_version3 {
+                Some(e) => {
+                    *ptr2.add(104).cast::<u8>() = (1i32) as u8;
+                    *ptr2.add(108).cast::<i32>() = _rt::as_i32(e);
+                }
+                None => {
+                    *ptr2.add(104).cast::<u8>() = (0i32) as u8;
+                }
+            };
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec10 = (e.into_bytes()).into_boxed_slice();
+            let ptr10 = vec10.as_ptr().cast::<u8>();
+            let len10 = vec10.len();
+            ::core::mem::forget(vec10);
+            *ptr2.add(12).cast::<usize>() = len10;
+            *ptr2.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_file_info_structured<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+            let l3 = *arg0.add(16).cast::<*mut u8>();
+            let l4 = *arg0.add(20).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+            let l5 = i32::from(*arg0.add(56).cast::<u8>());
+            match l5 {
+                0 => (),
+                _ => {
+                    let l6 = *arg0.add(60).cast::<*mut u8>();
+                    let l7 = *arg0.add(64).cast::<usize>();
+                    _rt::cabi_dealloc(l6, l7, 1);
+                }
+            }
+            let l8 = i32::from(*arg0.add(68).cast::<u8>());
+            match l8 {
+                0 => (),
+                _ => {
+                    let l9 = *arg0.add(72).cast::<*mut u8>();
+                    let l10 = *arg0.add(76).cast::<usize>();
+                    _rt::cabi_dealloc(l9, l10, 1);
+                }
+            }
+            let l11 = i32::from(*arg0.add(80).cast::<u8>());
+            match l11 {
+                0 => (),
+                _ => {
+                    let l12 = *arg0.add(84).cast::<*mut u8>();
+                    let l13 = *arg0.add(88).cast::<usize>();
+                    _rt::cabi_dealloc(l12, l13, 1);
+                }
+            }
+            let l14 = i32::from(*arg0.add(92).cast::<u8>());
+            match l14 {
+                0 => (),
+                _ => {
+                    let l15 = *arg0.add(96).cast::<*mut u8>();
+                    let l16 = *arg0.add(100).cast::<usize>();
+                    _rt::cabi_dealloc(l15, l16, 1);
+                }
+            }
+        }
+        _ => {
+            let l17 = *arg0.add(8).cast::<*mut u8>();
+            let l18 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l17, l18, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_validate_wasm_file_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::validate_wasm_file(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_validate_wasm_file<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_wasm_component_exports_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::wasm_component_exports(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec6 = e;
+            let len6 = vec6.len();
+            let layout6 = _rt::alloc::Layout::from_size_align_unchecked(vec6.len() * 16, 4);
+            let result6 = if layout6.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout6);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec6.into_iter().enumerate() {
+                let base = result6.add(i * 16);
+                {
+                    let ExportEntry {
+                        name: name3,
+                        kind: kind3,
+                    } = e;
+                    let vec4 = (name3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base.add(4).cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                    let vec5 = (kind3.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(12).cast::<usize>() = len5;
+                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len6;
+            *ptr2.add(4).cast::<*mut u8>() = result6;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec7 = (e.into_bytes()).into_boxed_slice();
+            let ptr7 = vec7.as_ptr().cast::<u8>();
+            let len7 = vec7.len();
+            ::core::mem::forget(vec7);
+            *ptr2.add(8).cast::<usize>() = len7;
+            *ptr2.add(4).cast::<*mut u8>() = ptr7.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_wasm_component_exports<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base7 = l1;
+            let len7 = l2;
+            for i in 0..len7 {
+                let base = base7.add(i * 16);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base.add(8).cast::<*mut u8>();
+                    let l6 = *base.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+            _rt::cabi_dealloc(base7, len7 * 16, 4);
+        }
+        _ => {
+            let l8 = *arg0.add(4).cast::<*mut u8>();
+            let l9 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l8, l9, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_wasm_component_imports_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::wasm_component_imports(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec7 = e;
+            let len7 = vec7.len();
+            let layout7 = _rt::alloc::Layout::from_size_align_unchecked(vec7.len() * 24, 4);
+            let result7 = if layout7.size() != 0 {
+                let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                if ptr.is_null() {
+                    _rt::alloc::handle_alloc_error(layout7);
+                }
+                ptr
+            } else {
+                ::core::ptr::null_mut()
+            };
+            for (i, e) in vec7.into_iter().enumerate() {
+                let base = result7.add(i * 24);
+                {
+                    let ImportEntry {
+                        module: module3,
+                        name: name3,
+                        kind: kind3,
+                    } = e;
+                    let vec4 = (module3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *base.add(4).cast::<usize>() = len4;
+                    *base.add(0).cast::<*mut u8>() = ptr4.cast_mut();
+                    let vec5 = (name3.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *base.add(12).cast::<usize>() = len5;
+                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                    let vec6 = (kind3.into_bytes()).into_boxed_slice();
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    ::core::mem::forget(vec6);
+                    *base.add(20).cast::<usize>() = len6;
+                    *base.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                }
+            }
+            *ptr2.add(8).cast::<usize>() = len7;
+            *ptr2.add(4).cast::<*mut u8>() = result7;
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec8 = (e.into_bytes()).into_boxed_slice();
+            let ptr8 = vec8.as_ptr().cast::<u8>();
+            let len8 = vec8.len();
+            ::core::mem::forget(vec8);
+            *ptr2.add(8).cast::<usize>() = len8;
+            *ptr2.add(4).cast::<*mut u8>() = ptr8.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_wasm_component_imports<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            let base9 = l1;
+            let len9 = l2;
+            for i in 0..len9 {
+                let base = base9.add(i * 24);
+                {
+                    let l3 = *base.add(0).cast::<*mut u8>();
+                    let l4 = *base.add(4).cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = *base.add(8).cast::<*mut u8>();
+                    let l6 = *base.add(12).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                    let l7 = *base.add(16).cast::<*mut u8>();
+                    let l8 = *base.add(20).cast::<usize>();
+                    _rt::cabi_dealloc(l7, l8, 1);
+                }
+            }
+            _rt::cabi_dealloc(base9, len9 * 24, 4);
+        }
+        _ => {
+            let l10 = *arg0.add(4).cast::<*mut u8>();
+            let l11 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l10, l11, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_compose_components_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+    arg4: *mut u8,
+    arg5: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let len2 = arg5;
+    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
+    let result3 = T::compose_components(
+        _rt::string_lift(bytes0),
+        _rt::string_lift(bytes1),
+        _rt::string_lift(bytes2),
+    );
+    let ptr4 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result3 {
+        Ok(e) => {
+            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr4.add(8).cast::<usize>() = len5;
+            *ptr4.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+        Err(e) => {
+            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+            let vec6 = (e.into_bytes()).into_boxed_slice();
+            let ptr6 = vec6.as_ptr().cast::<u8>();
+            let len6 = vec6.len();
+            ::core::mem::forget(vec6);
+            *ptr4.add(8).cast::<usize>() = len6;
+            *ptr4.add(4).cast::<*mut u8>() = ptr6.cast_mut();
+        }
+    };
+    ptr4
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_compose_components<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_disk_usage_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_disk_usage(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let DiskUsage {
+                total_bytes: total_bytes3,
+                available_bytes: available_bytes3,
+                used_bytes: used_bytes3,
+            } = e;
+            *ptr2.add(8).cast::<i64>() = _rt::as_i64(total_bytes3);
+            *ptr2.add(16).cast::<i64>() = _rt::as_i64(available_bytes3);
+            *ptr2.add(24).cast::<i64>() = _rt::as_i64(used_bytes3);
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(12).cast::<usize>() = len4;
+            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_disk_usage<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_directory_size_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_directory_size(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_directory_size<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
             let l1 = *arg0.add(4).cast::<*mut u8>();
             let l2 = *arg0.add(8).cast::<usize>();
             _rt::cabi_dealloc(l1, l2, 1);
         }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
     }
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_get_directory_tree_cabi<T: Guest>(
+pub unsafe fn _export_get_directory_stats_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_directory_stats(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let DirectoryStats {
+                total_files: total_files3,
+                total_directories: total_directories3,
+                total_symlinks: total_symlinks3,
+                total_size_bytes: total_size_bytes3,
+                largest_file: largest_file3,
+                oldest_file: oldest_file3,
+            } = e;
+            *ptr2.add(8).cast::<i64>() = _rt::as_i64(total_files3);
+            *ptr2.add(16).cast::<i64>() = _rt::as_i64(total_directories3);
+            *ptr2.add(24).cast::<i64>() = _rt::as_i64(total_symlinks3);
+            *ptr2.add(32).cast::<i64>() = _rt::as_i64(total_size_bytes3);
+            match largest_file3 {
+                Some(e) => {
+                    *ptr2.add(40).cast::<u8>() = (1i32) as u8;
+                    let (t4_0, t4_1) = e;
+                    let vec5 = (t4_0.into_bytes()).into_boxed_slice();
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    ::core::mem::forget(vec5);
+                    *ptr2.add(52).cast::<usize>() = len5;
+                    *ptr2.add(48).cast::<*mut u8>() = ptr5.cast_mut();
+                    *ptr2.add(56).cast::<i64>() = _rt::as_i64(t4_1);
+                }
+                None => {
+                    *ptr2.add(40).cast::<u8>() = (0i32) as u8;
+                }
+            };
+            match oldest_file3 {
+                Some(e) => {
+                    *ptr2.add(64).cast::<u8>() = (1i32) as u8;
+                    let (t6_0, t6_1) = e;
+                    let vec7 = (t6_0.into_bytes()).into_boxed_slice();
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    ::core::mem::forget(vec7);
+                    *ptr2.add(76).cast::<usize>() = len7;
+                    *ptr2.add(72).cast::<*mut u8>() = ptr7.cast_mut();
+                    *ptr2.add(80).cast::<i64>() = _rt::as_i64(t6_1);
+                }
+                None => {
+                    *ptr2.add(64).cast::<u8>() = (0i32) as u8;
+                }
+            };
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec8 = (e.into_bytes()).into_boxed_slice();
+            let ptr8 = vec8.as_ptr().cast::<u8>();
+            let len8 = vec8.len();
+            ::core::mem::forget(vec8);
+            *ptr2.add(12).cast::<usize>() = len8;
+            *ptr2.add(8).cast::<*mut u8>() = ptr8.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_directory_stats<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = i32::from(*arg0.add(40).cast::<u8>());
+            match l1 {
+                0 => (),
+                _ => {
+                    let l2 = *arg0.add(48).cast::<*mut u8>();
+                    let l3 = *arg0.add(52).cast::<usize>();
+                    _rt::cabi_dealloc(l2, l3, 1);
+                }
+            }
+            let l4 = i32::from(*arg0.add(64).cast::<u8>());
+            match l4 {
+                0 => (),
+                _ => {
+                    let l5 = *arg0.add(72).cast::<*mut u8>();
+                    let l6 = *arg0.add(76).cast::<usize>();
+                    _rt::cabi_dealloc(l5, l6, 1);
+                }
+            }
+        }
+        _ => {
+            let l7 = *arg0.add(8).cast::<*mut u8>();
+            let l8 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l7, l8, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_find_duplicate_files_cabi<T: Guest>(
     arg0: *mut u8,
     arg1: usize,
-    arg2: i32,
+    arg2: *mut u8,
+    arg3: usize,
 ) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::get_directory_tree(_rt::string_lift(bytes0), arg2 as u32);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::find_duplicate_files(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec5 = (e.into_bytes()).into_boxed_slice();
+            let ptr5 = vec5.as_ptr().cast::<u8>();
+            let len5 = vec5.len();
+            ::core::mem::forget(vec5);
+            *ptr3.add(8).cast::<usize>() = len5;
+            *ptr3.add(4).cast::<*mut u8>() = ptr5.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_find_duplicate_files<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_count_lines_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::count_lines(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(8).cast::<i64>() = _rt::as_i64(e);
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(12).cast::<usize>() = len3;
+            *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_count_lines<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_count_bytes_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::count_bytes(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr2.add(8).cast::<i64>() = _rt::as_i64(e);
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(12).cast::<usize>() = len3;
+            *ptr2.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_count_bytes<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(8).cast::<*mut u8>();
+            let l2 = *arg0.add(12).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_summarise_wit_file_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::summarise_wit_file(_rt::string_lift(bytes0));
     let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
     match result1 {
         Ok(e) => {
@@ -461,7 +5205,7 @@ pub unsafe fn _export_get_directory_tree_cabi<T: Guest>(
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_get_directory_tree<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_summarise_wit_file<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -478,7 +5222,7 @@ pub unsafe fn __post_return_get_directory_tree<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_search_file_cabi<T: Guest>(
+pub unsafe fn _export_get_file_hash_cabi<T: Guest>(
     arg0: *mut u8,
     arg1: usize,
     arg2: *mut u8,
@@ -490,7 +5234,7 @@ pub unsafe fn _export_search_file_cabi<T: Guest>(
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
     let len1 = arg3;
     let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let result2 = T::search_file(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let result2 = T::get_file_hash(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
     let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
     match result2 {
         Ok(e) => {
@@ -516,7 +5260,104 @@ pub unsafe fn _export_search_file_cabi<T: Guest>(
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_search_file<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_get_file_hash<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+        _ => {
+            let l3 = *arg0.add(4).cast::<*mut u8>();
+            let l4 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l3, l4, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_compare_files_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::compare_files(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
+        Ok(e) => {
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr3.add(4).cast::<u8>() = (match e {
+                true => 1,
+                false => 0,
+            }) as u8;
+        }
+        Err(e) => {
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr3
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_compare_files<T: Guest>(arg0: *mut u8) {
+    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+    match l0 {
+        0 => (),
+        _ => {
+            let l1 = *arg0.add(4).cast::<*mut u8>();
+            let l2 = *arg0.add(8).cast::<usize>();
+            _rt::cabi_dealloc(l1, l2, 1);
+        }
+    }
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn _export_get_file_change_token_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+    #[cfg(target_arch = "wasm32")]
+    _rt::run_ctors_once();
+    let len0 = arg1;
+    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+    let result1 = T::get_file_change_token(_rt::string_lift(bytes0));
+    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result1 {
+        Ok(e) => {
+            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+            let vec3 = (e.into_bytes()).into_boxed_slice();
+            let ptr3 = vec3.as_ptr().cast::<u8>();
+            let len3 = vec3.len();
+            ::core::mem::forget(vec3);
+            *ptr2.add(8).cast::<usize>() = len3;
+            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+        }
+        Err(e) => {
+            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            let vec4 = (e.into_bytes()).into_boxed_slice();
+            let ptr4 = vec4.as_ptr().cast::<u8>();
+            let len4 = vec4.len();
+            ::core::mem::forget(vec4);
+            *ptr2.add(8).cast::<usize>() = len4;
+            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+        }
+    };
+    ptr2
+}
+#[doc(hidden)]
+#[allow(non_snake_case)]
+pub unsafe fn __post_return_get_file_change_token<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
         0 => {
@@ -533,91 +5374,571 @@ pub unsafe fn __post_return_search_file<T: Guest>(arg0: *mut u8) {
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn _export_get_file_info_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
+pub unsafe fn _export_has_file_changed_since_cabi<T: Guest>(
+    arg0: *mut u8,
+    arg1: usize,
+    arg2: *mut u8,
+    arg3: usize,
+) -> *mut u8 {
     #[cfg(target_arch = "wasm32")]
     _rt::run_ctors_once();
     let len0 = arg1;
     let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::get_file_info(_rt::string_lift(bytes0));
-    let ptr2 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
-    match result1 {
+    let len1 = arg3;
+    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
+    let result2 = T::has_file_changed_since(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
+    let ptr3 = _RET_AREA.0.as_mut_ptr().cast::<u8>();
+    match result2 {
         Ok(e) => {
-            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
-            let vec3 = (e.into_bytes()).into_boxed_slice();
-            let ptr3 = vec3.as_ptr().cast::<u8>();
-            let len3 = vec3.len();
-            ::core::mem::forget(vec3);
-            *ptr2.add(8).cast::<usize>() = len3;
-            *ptr2.add(4).cast::<*mut u8>() = ptr3.cast_mut();
+            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+            *ptr3.add(4).cast::<u8>() = (match e {
+                true => 1,
+                false => 0,
+            }) as u8;
         }
         Err(e) => {
-            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
             let vec4 = (e.into_bytes()).into_boxed_slice();
             let ptr4 = vec4.as_ptr().cast::<u8>();
             let len4 = vec4.len();
             ::core::mem::forget(vec4);
-            *ptr2.add(8).cast::<usize>() = len4;
-            *ptr2.add(4).cast::<*mut u8>() = ptr4.cast_mut();
+            *ptr3.add(8).cast::<usize>() = len4;
+            *ptr3.add(4).cast::<*mut u8>() = ptr4.cast_mut();
         }
     };
-    ptr2
+    ptr3
 }
 #[doc(hidden)]
 #[allow(non_snake_case)]
-pub unsafe fn __post_return_get_file_info<T: Guest>(arg0: *mut u8) {
+pub unsafe fn __post_return_has_file_changed_since<T: Guest>(arg0: *mut u8) {
     let l0 = i32::from(*arg0.add(0).cast::<u8>());
     match l0 {
-        0 => {
+        0 => (),
+        _ => {
             let l1 = *arg0.add(4).cast::<*mut u8>();
             let l2 = *arg0.add(8).cast::<usize>();
             _rt::cabi_dealloc(l1, l2, 1);
         }
-        _ => {
-            let l3 = *arg0.add(4).cast::<*mut u8>();
-            let l4 = *arg0.add(8).cast::<usize>();
-            _rt::cabi_dealloc(l3, l4, 1);
-        }
     }
 }
 pub trait Guest {
+    /// Set the directory that relative paths are resolved against for the lifetime of this \
+    /// component instance. The directory must exist. Absolute paths and paths starting with ~ \
+    /// are not affected. Pass an empty string to go back to the process working directory.
+    fn set_root_directory(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Get the directory that relative paths are resolved against, or "." when relative paths \
+    /// use the process working directory.
+    fn get_root_directory() -> _rt::String;
+    /// Limit every later write-file call on this component instance to max-bytes of content. \
+    /// Pass 0 to remove the limit.
+    fn set_write_size_limit(max_bytes: u64) -> Result<_rt::String, _rt::String>;
+    /// Restrict every path to the given directory for the lifetime of this component instance. \
+    /// Paths are checked after resolving ".." and symlinks, so neither can be used to escape.
+    fn set_jail_directory(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Remove the restriction set by set-jail-directory.
+    fn clear_jail() -> _rt::String;
+    /// Normalise a path: resolve ~ and the root directory, drop "." and trailing separators, \
+    /// resolve ".." and follow symlinks. Paths that don't exist yet are normalised as far as \
+    /// their deepest existing parent. Fails if a relative path leads outside the root directory.
+    fn normalize_path(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Get the value of an environment variable. Sandboxed hosts only expose the variables \
+    /// allowed by the policy configuration, so a variable may be unavailable even if it is set on the host.
+    fn get_environment_variable(name: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// List the environment variables visible to the component as "NAME=VALUE" strings, sorted by name. \
+    /// Sandboxed hosts only expose the variables allowed by the policy configuration, so the list may be empty.
+    fn list_environment_variables() -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Get the system temporary directory.
+    fn get_temp_directory() -> Result<_rt::String, _rt::String>;
     /// Get a detailed listing of all files and directories in a specified path.
-    /// Results clearly distinguish between files and directories with [FILE] and [DIR] \
-    /// prefixes. This tool is essential for understanding directory structure and \
-    /// finding specific files within a directory. Only works within allowed directories.
-    fn list_directory(path: _rt::String) -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Each entry reports its name, type and size in bytes. \
+    /// This tool is essential for understanding directory structure and \
+    /// finding specific files within a directory. Only works within allowed directories. \
+    /// Hidden files are included, as with list-directory-all.
+    fn list_directory(path: _rt::String) -> Result<_rt::Vec<DirEntry>, _rt::String>;
+    /// Get a detailed listing of a directory like list-directory, leaving out hidden entries: \
+    /// names starting with "." and, on Windows, entries with the hidden attribute.
+    fn list_directory_visible(path: _rt::String) -> Result<_rt::Vec<DirEntry>, _rt::String>;
+    /// Get a detailed listing of a directory including hidden entries.
+    fn list_directory_all(path: _rt::String) -> Result<_rt::Vec<DirEntry>, _rt::String>;
+    /// Get a detailed listing of a directory like list-directory, ordered by sort-by. \
+    /// Entries that compare equal are ordered by name.
+    fn list_directory_sorted(
+        path: _rt::String,
+        sort_by: SortOrder,
+        descending: bool,
+    ) -> Result<_rt::Vec<DirEntry>, _rt::String>;
+    /// Get a listing of all files and directories in a specified path as formatted strings. \
+    /// Results distinguish between files and directories with [FILE] and [DIR] prefixes.
+    fn list_directory_formatted(path: _rt::String) -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Recursively list all files and directories under a path as a flat list. \
+    /// Each entry carries its path relative to the root. max-depth follows the same \
+    /// semantics as get-directory-tree (0 lists only the immediate children). \
+    /// Symlinked directories are listed but not followed.
+    fn list_directory_recursive(
+        path: _rt::String,
+        max_depth: u32,
+    ) -> Result<_rt::Vec<DirEntry>, _rt::String>;
     /// Read the complete contents of a file from the file system.
     fn read_file(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Read a text file whatever its encoding. A byte order mark identifies UTF-8 or UTF-16 \
+    /// and is stripped; otherwise the file is read as UTF-8, falling back to Latin-1, which \
+    /// maps every byte to a character. Use this for legacy files that read-file rejects.
+    fn read_file_with_encoding(path: _rt::String) -> Result<FileContent, _rt::String>;
+    /// Read the complete contents of a file as raw bytes. Use this for binary files.
+    fn read_file_bytes(path: _rt::String) -> Result<_rt::Vec<u8>, _rt::String>;
+    /// Read up to length bytes of a text file starting at byte offset, without loading the whole file. \
+    /// A range extending past the end of the file is shortened, as reported by bytes-read; an offset \
+    /// past the end is an error. Fails if the range isn't valid UTF-8, e.g. when it splits a \
+    /// character; use read-file-bytes-range then.
+    fn read_file_range(
+        path: _rt::String,
+        offset: u64,
+        length: u64,
+    ) -> Result<TextRange, _rt::String>;
+    /// Read up to length bytes of a file starting at byte offset, without loading the whole file. \
+    /// A range extending past the end of the file is shortened, so bytes-read may be less than \
+    /// length; an offset past the end is an error.
+    fn read_file_bytes_range(
+        path: _rt::String,
+        offset: u64,
+        length: u64,
+    ) -> Result<BytesRange, _rt::String>;
+    /// Read a text file as a list of lines without their line endings, streaming it line by line. \
+    /// skip-empty drops empty lines and trim-whitespace trims each line; with both, lines holding \
+    /// only whitespace are dropped too. Fails if the file isn't valid UTF-8.
+    fn read_lines(
+        path: _rt::String,
+        skip_empty: bool,
+        trim_whitespace: bool,
+    ) -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Read lines start-line through end-line (1-based, inclusive) of a text file, stopping once \
+    /// end-line is reached. A range extending past the end of the file is shortened; a start-line \
+    /// past the end is an error.
+    fn read_lines_range(
+        path: _rt::String,
+        start_line: u64,
+        end_line: u64,
+    ) -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Read a JSON file and return the value at json-pointer (RFC 6901, e.g. "/servers/0/name") \
+    /// serialized as JSON. An empty pointer returns the whole document.
+    fn read_json_value(
+        path: _rt::String,
+        json_pointer: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
     /// Write content to a file. Creates the file if it doesn't exist, or overwrites if it does. \
     /// Requires write permission in the policy configuration.
     fn write_file(path: _rt::String, content: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Write content to a file like write-file, but fail without touching the file if content \
+    /// is larger than max-bytes. Requires write permission in the policy configuration.
+    fn write_file_bounded(
+        path: _rt::String,
+        content: _rt::String,
+        max_bytes: u64,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Atomically write content to a file by writing a temporary file in the same directory \
+    /// and renaming it into place, so readers never observe a partially written file. An existing \
+    /// file keeps its permissions, and a symlink is kept with its target rewritten. \
+    /// Requires write permission in the policy configuration.
+    fn atomic_write_file(
+        path: _rt::String,
+        content: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Atomically write lines to a file, ending each one with line-ending: "lf", "crlf", or \
+    /// "platform" for the host's convention. The counterpart of read-lines. Reports the number \
+    /// of lines and bytes written. Requires write permission in the policy configuration.
+    fn write_lines(
+        path: _rt::String,
+        lines: _rt::Vec<_rt::String>,
+        line_ending: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Set the value at json-pointer (RFC 6901) in a JSON file and atomically write it back. \
+    /// value must be valid JSON. Missing object keys are added, "-" appends to an array, and an \
+    /// empty pointer replaces the whole document. The file is left untouched if it or value isn't \
+    /// valid JSON. Requires write permission in the policy configuration.
+    fn write_json_value(
+        path: _rt::String,
+        json_pointer: _rt::String,
+        value: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Deep-merge two JSON files whose top-level values are objects and atomically write the \
+    /// result to output-path. Keys from override-path win; nested objects are merged key by key. \
+    /// Arrays from override-path replace those in base-path, or are appended to them when \
+    /// array-merge is true. Requires write permission in the policy configuration.
+    fn merge_json_files(
+        base_path: _rt::String,
+        override_path: _rt::String,
+        output_path: _rt::String,
+        array_merge: bool,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Replace occurrences of the literal text find with replace in a file, streaming it through \
+    /// a temporary file that is then renamed into place. At most max-replacements occurrences are \
+    /// replaced, from the start of the file; 0 means no limit. The file is left untouched when \
+    /// nothing matches. Reports the number of replacements made. Requires write permission in \
+    /// the policy configuration.
+    fn replace_in_file(
+        path: _rt::String,
+        find: _rt::String,
+        replace: _rt::String,
+        max_replacements: u32,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Replace matches of the regular expression pattern like replace-in-file. The file is \
+    /// processed line by line, so matches never span lines, and replace may refer to capture \
+    /// groups as $1 or $name. Fails if the file isn't valid UTF-8. Requires write permission \
+    /// in the policy configuration.
+    fn replace_regex_in_file(
+        path: _rt::String,
+        pattern: _rt::String,
+        replace: _rt::String,
+        max_replacements: u32,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Insert content as a new line at line-number (1-based), moving that line and the ones \
+    /// after it down. The new line takes the line ending of the line it displaces. Fails if \
+    /// line-number is past the last line or content holds a line break. The file is streamed \
+    /// and atomically written back. Requires write permission in the policy configuration.
+    fn insert_line_at(
+        path: _rt::String,
+        line_number: u64,
+        content: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Delete the line at line-number (1-based), streaming the file and atomically writing it \
+    /// back. Fails if line-number is past the last line. Requires write permission in the \
+    /// policy configuration.
+    fn delete_line_at(path: _rt::String, line_number: u64) -> Result<_rt::String, _rt::String>;
+    /// Write raw bytes to a file. Creates the file if it doesn't exist, or overwrites if it does. \
+    /// Use this for binary files. Requires write permission in the policy configuration.
+    fn write_file_bytes(
+        path: _rt::String,
+        content: _rt::Vec<u8>,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Append content to the end of a file. Creates the file if it doesn't exist. \
+    /// Requires write permission in the policy configuration.
+    fn append_to_file(path: _rt::String, content: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Truncate a file to the given length in bytes. Refuses to extend a file beyond its current size. \
+    /// Requires write permission in the policy configuration.
+    fn truncate_file(path: _rt::String, size_bytes: u64) -> Result<_rt::String, _rt::String>;
+    /// Rotate a log file once it is larger than max-size-bytes: file.log.1 becomes file.log.2 \
+    /// and so on up to max-backups, file.log becomes file.log.1, and an empty file.log is \
+    /// created. The oldest backup is deleted; with max-backups 0 the file is just emptied. \
+    /// A file.log.lock file guards against concurrent rotations. Returns how many files were \
+    /// renamed. Requires write permission in the policy configuration.
+    fn rotate_file(
+        path: _rt::String,
+        max_size_bytes: u64,
+        max_backups: u32,
+    ) -> Result<_rt::String, _rt::String>;
     /// Create a new directory at the specified path. Creates parent directories if needed. \
     /// Requires write permission in the policy configuration.
     fn create_directory(path: _rt::String) -> Result<_rt::String, _rt::String>;
-    /// Move or rename a file or directory from source to destination path. \
+    /// Create a directory and its parents unless it already exists, reporting which of the two \
+    /// happened. Fails if the path exists but isn't a directory. \
+    /// Requires write permission in the policy configuration.
+    fn ensure_directory(path: _rt::String) -> Result<EnsureResult, _rt::String>;
+    /// Create a uniquely named directory under the system temporary directory and return its \
+    /// path. The name is prefix followed by a random suffix. \
     /// Requires write permission in the policy configuration.
+    fn create_temp_directory(prefix: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Delete a directory made by create-temp-directory, along with its contents. Other \
+    /// directories are refused. Requires write permission in the policy configuration.
+    fn delete_temp_directory(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Move or rename a file or directory from source to destination path. \
+    /// Falls back to copying and deleting the source when moving across filesystems, recreating \
+    /// symlinks inside a moved directory. Requires write permission in the policy configuration.
     fn move_path(source: _rt::String, destination: _rt::String)
         -> Result<_rt::String, _rt::String>;
+    /// Copy a file from source to destination path, overwriting the destination file if it exists. \
+    /// Creates parent directories of the destination if needed. \
+    /// Requires write permission in the policy configuration.
+    fn copy_file(source: _rt::String, destination: _rt::String)
+        -> Result<_rt::String, _rt::String>;
+    /// Recursively copy a directory and all of its contents to a new destination path. \
+    /// The destination must not already exist. Symlinks inside the source are skipped. \
+    /// Requires write permission in the policy configuration.
+    fn copy_directory(
+        source: _rt::String,
+        destination: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Compress a directory and all of its contents into a zip archive at destination-zip, \
+    /// overwriting it if it exists. Creates parent directories of the archive if needed. \
+    /// Symlinks inside the source are skipped. Requires write permission in the policy configuration.
+    fn zip_directory(
+        source: _rt::String,
+        destination_zip: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Extract a zip archive into destination, creating it if needed. Existing files are never \
+    /// overwritten. Archives with entries that would escape destination (e.g. "../x" or absolute \
+    /// paths) are rejected before anything is written. Requires write permission in the policy configuration.
+    fn unzip_to_directory(
+        zip_path: _rt::String,
+        destination: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Create a symbolic link at link-path pointing to target. The target does not need to exist. \
+    /// Requires write permission in the policy configuration.
+    fn create_symlink(
+        target: _rt::String,
+        link_path: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Create a hard link at link-path to the existing file source, so both names refer to the \
+    /// same data. Directories can't be hard linked, and both paths must be on the same \
+    /// filesystem. Requires write permission in the policy configuration.
+    fn create_hard_link(
+        source: _rt::String,
+        link_path: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Read the target of a symbolic link without following it.
+    fn read_symlink(link_path: _rt::String) -> Result<_rt::String, _rt::String>;
     /// Delete a file at the specified path. Will not delete directories. \
     /// Requires write permission in the policy configuration.
     fn delete_file(path: _rt::String) -> Result<_rt::String, _rt::String>;
     /// Delete an empty directory at the specified path. \
     /// Requires write permission in the policy configuration.
     fn delete_directory(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Delete a directory and all of its contents. Symlinks inside the tree are removed \
+    /// without following them. Refuses to delete a filesystem root, the jail directory or a \
+    /// preopened directory. \
+    /// Requires write permission in the policy configuration.
+    fn delete_directory_recursive(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Set or clear the read-only flag on a file or directory. \
+    /// Not every host supports changing permissions. Requires write permission in the policy configuration.
+    fn set_permissions(path: _rt::String, readonly: bool) -> Result<_rt::String, _rt::String>;
+    /// Set the Unix permission bits (e.g. 0o644) of a file or directory. \
+    /// Only supported on Unix hosts. Requires write permission in the policy configuration.
+    fn set_unix_permissions(path: _rt::String, mode: u32) -> Result<_rt::String, _rt::String>;
     /// Check if a file or directory exists at the specified path.
     fn file_exists(path: _rt::String) -> Result<bool, _rt::String>;
+    /// Check if a directory exists at the specified path, following symlinks.
+    fn directory_exists(path: _rt::String) -> Result<bool, _rt::String>;
+    /// Check if a symlink exists at the specified path. \
+    /// Broken symlinks, whose target is missing, still count as existing.
+    fn symlink_exists(path: _rt::String) -> Result<bool, _rt::String>;
+    /// Check if anything exists at the specified path, following symlinks, \
+    /// so broken symlinks count as missing. Same as file-exists.
+    fn any_path_exists(path: _rt::String) -> Result<bool, _rt::String>;
     /// Get a recursive tree view of a directory structure. \
-    /// Useful for understanding nested directory hierarchies.
-    fn get_directory_tree(path: _rt::String, max_depth: u32) -> Result<_rt::String, _rt::String>;
-    /// Recursively search for files and directories matching a pattern.
-    fn search_file(path: _rt::String, pattern: _rt::String) -> Result<_rt::String, _rt::String>;
-    /// Retrieve detailed metadata about a file or directory including size, permissions, and timestamps.
+    /// Useful for understanding nested directory hierarchies. \
+    /// sort-by orders the entries of each directory by "name" (the default when empty), "size", \
+    /// "modified" or "extension"; prefix it with "-" for descending order, e.g. "-size".
+    fn get_directory_tree(
+        path: _rt::String,
+        max_depth: u32,
+        sort_by: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Get a recursive tree view of a directory like get-directory-tree, sorted by name, with \
+    /// each file's size and each directory's number of immediate children, e.g. \
+    /// "├── app.wasm (42.00 KB)" and "├── [DIR] src/ (3 items)".
+    fn get_directory_tree_with_sizes(
+        path: _rt::String,
+        max_depth: u32,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Get a directory tree as nested JSON for programs to consume, e.g. \
+    /// {"name": "root", "type": "dir", "children": [{"name": "app.wasm", "type": "file", "size": 42000}]}. \
+    /// type is "dir", "file", "symlink" or "unknown"; files carry their size in bytes. \
+    /// Entries are sorted by name and symlinks are not followed. Directories deeper than \
+    /// max-depth (with the same semantics as get-directory-tree) have no "children" key.
+    fn get_directory_tree_json(
+        path: _rt::String,
+        max_depth: u32,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Get a recursive tree view of a directory showing only files whose names match filter, \
+    /// a case-insensitive glob such as "*.wasm" or "*.toml". Directories are shown only when \
+    /// they contain matching files within max-depth.
+    fn get_directory_tree_filtered(
+        path: _rt::String,
+        max_depth: u32,
+        filter: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Recursively search for files and directories whose names match a pattern. \
+    /// pattern-type is one of "substring" (default), "glob" (supports * and ?, matches the whole name) \
+    /// or "regex". Name matching is case-insensitive.
+    fn search_file(
+        path: _rt::String,
+        pattern: _rt::String,
+        pattern_type: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Search for files and directories like search-file, but report each match relative to \
+    /// path using "/" as separator (e.g. "subdir/file.txt"), so host paths are not exposed.
+    fn search_file_relative(
+        path: _rt::String,
+        pattern: _rt::String,
+        pattern_type: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Recursively find the files under root with the given extension, such as "wasm" or "toml", \
+    /// compared case-insensitively against the part of the name after the last ".". Faster than \
+    /// search-file for this common case since no pattern is compiled and no metadata is read. \
+    /// Returns sorted paths. Symlinks are not followed.
+    fn find_files_by_extension(
+        root: _rt::String,
+        extension: _rt::String,
+    ) -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Recursively search the contents of text files for lines matching a pattern. \
+    /// pattern-type is one of "substring" (default), "glob" (supports * and ?, matches the whole line) \
+    /// or "regex". Returns matches formatted as "path:line-number:line". Binary and unreadable files are skipped. \
+    /// At most max-results matches are returned (0 means unlimited); a final "[TRUNCATED]" \
+    /// entry is added when the limit is reached.
+    fn search_file_content(
+        root: _rt::String,
+        pattern: _rt::String,
+        pattern_type: _rt::String,
+        max_results: u32,
+    ) -> Result<_rt::Vec<_rt::String>, _rt::String>;
+    /// Count the lines of a text file that match a regular expression. The file is read line \
+    /// by line, so large files are never loaded whole. Binary files are rejected.
+    fn count_matching_lines(path: _rt::String, pattern: _rt::String) -> Result<u64, _rt::String>;
+    /// Count matching lines like count-matching-lines for every text file under root, \
+    /// returning (relative path, count) pairs sorted by path, with "/" as separator. Files \
+    /// without matches are included with a count of 0. Binary and unreadable files are \
+    /// skipped and symlinks are not followed.
+    fn count_matching_lines_in_directory(
+        root: _rt::String,
+        pattern: _rt::String,
+    ) -> Result<_rt::Vec<(_rt::String, u64)>, _rt::String>;
+    /// Retrieve detailed metadata about a file or directory including size, permissions, and timestamps. \
+    /// Returns a human-readable summary; prefer get-file-info-structured for programmatic use.
     fn get_file_info(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Retrieve metadata about a file or directory as a structured record.
+    fn get_file_info_structured(path: _rt::String) -> Result<FileInfo, _rt::String>;
+    /// Check that a file is a well-formed WebAssembly binary: the \0asm magic and version are \
+    /// checked first, then the whole binary is validated. Reports whether it is a core module \
+    /// or a component and how many top-level sections it has.
+    fn validate_wasm_file(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// List the top-level exports of a WebAssembly component or core module, such as the \
+    /// interfaces a component provides, without running it. The file is parsed as it streams \
+    /// in, skipping function bodies and nested modules and components.
+    fn wasm_component_exports(path: _rt::String) -> Result<_rt::Vec<ExportEntry>, _rt::String>;
+    /// List the top-level imports of a WebAssembly component or core module, i.e. what a host \
+    /// or composed component must provide to run it. Streams the file like wasm-component-exports.
+    fn wasm_component_imports(path: _rt::String) -> Result<_rt::Vec<ImportEntry>, _rt::String>;
+    /// Compose two WebAssembly components with wasm-compose, wiring the imports of \
+    /// component-a-path to the matching exports of component-b-path, and write the result to \
+    /// output-path. Imports component B doesn't provide stay imports of the composed component. \
+    /// Reports the composed component's exports and remaining imports; on failure the error \
+    /// lists the imports of component A that component B doesn't provide. Requires write \
+    /// permission in the policy configuration.
+    fn compose_components(
+        component_a_path: _rt::String,
+        component_b_path: _rt::String,
+        output_path: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Report total, available and used space for the filesystem containing a path. \
+    /// Not every host exposes this information.
+    fn get_disk_usage(path: _rt::String) -> Result<DiskUsage, _rt::String>;
+    /// Recursively add up the sizes of all regular files under a directory. Reports the total \
+    /// in bytes and human-readable form with the number of files and subdirectories. \
+    /// Symlinks are not followed, so nothing is counted twice.
+    fn get_directory_size(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Summarise a directory tree in one pass: file, subdirectory and symlink counts, total file \
+    /// size, and the largest and oldest files with paths relative to path using "/" as \
+    /// separator. Symlinks are counted but not followed; unreadable subdirectories are skipped.
+    fn get_directory_stats(path: _rt::String) -> Result<DirectoryStats, _rt::String>;
+    /// Find files with identical contents under a directory tree by hashing every regular file. \
+    /// algorithm is "sha256" or "blake3". Returns a report of each duplicate set with its paths \
+    /// and the bytes wasted by the extra copies. Unreadable files are listed and skipped. \
+    /// Symlinks are not followed.
+    fn find_duplicate_files(
+        root: _rt::String,
+        algorithm: _rt::String,
+    ) -> Result<_rt::String, _rt::String>;
+    /// Count the newline characters in a file by streaming it, like `wc -l`. \
+    /// A final line without a trailing newline is not counted.
+    fn count_lines(path: _rt::String) -> Result<u64, _rt::String>;
+    /// Get the size of a file in bytes without reading its contents.
+    fn count_bytes(path: _rt::String) -> Result<u64, _rt::String>;
+    /// Summarise a WIT file: its package, and for each world and interface the imports, exports, \
+    /// type names (records, variants, enums, flags, resources and aliases) and functions with \
+    /// their parameter counts. Uses a lightweight line scan rather than a full WIT parser.
+    fn summarise_wit_file(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Compute the digest of a file's contents as a lowercase hex string. \
+    /// Supported algorithms are "sha256" and "blake3" (case-insensitive).
+    fn get_file_hash(path: _rt::String, algorithm: _rt::String)
+        -> Result<_rt::String, _rt::String>;
+    /// Check whether two files have identical contents. Files of different sizes are rejected \
+    /// without reading them, and two paths to the same file (e.g. via a symlink) match without \
+    /// reading. Otherwise both are streamed in 64 KB blocks, stopping at the first difference.
+    fn compare_files(path_a: _rt::String, path_b: _rt::String) -> Result<bool, _rt::String>;
+    /// Get an opaque token describing a file's current state, built from its size, modification \
+    /// time and a BLAKE3 hash of its contents. A missing file has a token too, so creating it \
+    /// counts as a change. Pass the token to has-file-changed-since to poll for changes in \
+    /// environments where directory watching isn't available.
+    fn get_file_change_token(path: _rt::String) -> Result<_rt::String, _rt::String>;
+    /// Check whether a file differs from the state captured by get-file-change-token.
+    fn has_file_changed_since(path: _rt::String, token: _rt::String) -> Result<bool, _rt::String>;
 }
 #[doc(hidden)]
 
 macro_rules! __export_world_fs_cabi{
   ($ty:ident with_types_in $($path_to_types:tt)*) => (const _: () = {
 
+    #[unsafe(export_name = "set-root-directory")]
+    unsafe extern "C" fn export_set_root_directory(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_set_root_directory_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_set-root-directory")]
+    unsafe extern "C" fn _post_return_set_root_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_set_root_directory::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-root-directory")]
+    unsafe extern "C" fn export_get_root_directory() -> *mut u8 {
+      $($path_to_types)*::_export_get_root_directory_cabi::<$ty>()
+    }
+    #[unsafe(export_name = "cabi_post_get-root-directory")]
+    unsafe extern "C" fn _post_return_get_root_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_root_directory::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "set-write-size-limit")]
+    unsafe extern "C" fn export_set_write_size_limit(arg0: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_set_write_size_limit_cabi::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "cabi_post_set-write-size-limit")]
+    unsafe extern "C" fn _post_return_set_write_size_limit(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_set_write_size_limit::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "set-jail-directory")]
+    unsafe extern "C" fn export_set_jail_directory(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_set_jail_directory_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_set-jail-directory")]
+    unsafe extern "C" fn _post_return_set_jail_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_set_jail_directory::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "clear-jail")]
+    unsafe extern "C" fn export_clear_jail() -> *mut u8 {
+      $($path_to_types)*::_export_clear_jail_cabi::<$ty>()
+    }
+    #[unsafe(export_name = "cabi_post_clear-jail")]
+    unsafe extern "C" fn _post_return_clear_jail(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_clear_jail::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "normalize-path")]
+    unsafe extern "C" fn export_normalize_path(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_normalize_path_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_normalize-path")]
+    unsafe extern "C" fn _post_return_normalize_path(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_normalize_path::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-environment-variable")]
+    unsafe extern "C" fn export_get_environment_variable(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_environment_variable_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_get-environment-variable")]
+    unsafe extern "C" fn _post_return_get_environment_variable(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_environment_variable::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "list-environment-variables")]
+    unsafe extern "C" fn export_list_environment_variables() -> *mut u8 {
+      $($path_to_types)*::_export_list_environment_variables_cabi::<$ty>()
+    }
+    #[unsafe(export_name = "cabi_post_list-environment-variables")]
+    unsafe extern "C" fn _post_return_list_environment_variables(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_list_environment_variables::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-temp-directory")]
+    unsafe extern "C" fn export_get_temp_directory() -> *mut u8 {
+      $($path_to_types)*::_export_get_temp_directory_cabi::<$ty>()
+    }
+    #[unsafe(export_name = "cabi_post_get-temp-directory")]
+    unsafe extern "C" fn _post_return_get_temp_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_temp_directory::<$ty>(arg0)
+    }
     #[unsafe(export_name = "list-directory")]
     unsafe extern "C" fn export_list_directory(arg0: *mut u8,arg1: usize,) -> *mut u8 {
       $($path_to_types)*::_export_list_directory_cabi::<$ty>(arg0, arg1)
@@ -626,6 +5947,46 @@ macro_rules! __export_world_fs_cabi{
     unsafe extern "C" fn _post_return_list_directory(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_list_directory::<$ty>(arg0)
     }
+    #[unsafe(export_name = "list-directory-visible")]
+    unsafe extern "C" fn export_list_directory_visible(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_list_directory_visible_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_list-directory-visible")]
+    unsafe extern "C" fn _post_return_list_directory_visible(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_list_directory_visible::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "list-directory-all")]
+    unsafe extern "C" fn export_list_directory_all(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_list_directory_all_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_list-directory-all")]
+    unsafe extern "C" fn _post_return_list_directory_all(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_list_directory_all::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "list-directory-sorted")]
+    unsafe extern "C" fn export_list_directory_sorted(arg0: *mut u8,arg1: usize,arg2: i32,arg3: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_list_directory_sorted_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_list-directory-sorted")]
+    unsafe extern "C" fn _post_return_list_directory_sorted(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_list_directory_sorted::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "list-directory-formatted")]
+    unsafe extern "C" fn export_list_directory_formatted(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_list_directory_formatted_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_list-directory-formatted")]
+    unsafe extern "C" fn _post_return_list_directory_formatted(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_list_directory_formatted::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "list-directory-recursive")]
+    unsafe extern "C" fn export_list_directory_recursive(arg0: *mut u8,arg1: usize,arg2: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_list_directory_recursive_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[unsafe(export_name = "cabi_post_list-directory-recursive")]
+    unsafe extern "C" fn _post_return_list_directory_recursive(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_list_directory_recursive::<$ty>(arg0)
+    }
     #[unsafe(export_name = "read-file")]
     unsafe extern "C" fn export_read_file(arg0: *mut u8,arg1: usize,) -> *mut u8 {
       $($path_to_types)*::_export_read_file_cabi::<$ty>(arg0, arg1)
@@ -634,6 +5995,62 @@ macro_rules! __export_world_fs_cabi{
     unsafe extern "C" fn _post_return_read_file(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_read_file::<$ty>(arg0)
     }
+    #[unsafe(export_name = "read-file-with-encoding")]
+    unsafe extern "C" fn export_read_file_with_encoding(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_read_file_with_encoding_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_read-file-with-encoding")]
+    unsafe extern "C" fn _post_return_read_file_with_encoding(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_read_file_with_encoding::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "read-file-bytes")]
+    unsafe extern "C" fn export_read_file_bytes(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_read_file_bytes_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_read-file-bytes")]
+    unsafe extern "C" fn _post_return_read_file_bytes(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_read_file_bytes::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "read-file-range")]
+    unsafe extern "C" fn export_read_file_range(arg0: *mut u8,arg1: usize,arg2: i64,arg3: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_read_file_range_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_read-file-range")]
+    unsafe extern "C" fn _post_return_read_file_range(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_read_file_range::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "read-file-bytes-range")]
+    unsafe extern "C" fn export_read_file_bytes_range(arg0: *mut u8,arg1: usize,arg2: i64,arg3: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_read_file_bytes_range_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_read-file-bytes-range")]
+    unsafe extern "C" fn _post_return_read_file_bytes_range(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_read_file_bytes_range::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "read-lines")]
+    unsafe extern "C" fn export_read_lines(arg0: *mut u8,arg1: usize,arg2: i32,arg3: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_read_lines_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_read-lines")]
+    unsafe extern "C" fn _post_return_read_lines(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_read_lines::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "read-lines-range")]
+    unsafe extern "C" fn export_read_lines_range(arg0: *mut u8,arg1: usize,arg2: i64,arg3: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_read_lines_range_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_read-lines-range")]
+    unsafe extern "C" fn _post_return_read_lines_range(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_read_lines_range::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "read-json-value")]
+    unsafe extern "C" fn export_read_json_value(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_read_json_value_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_read-json-value")]
+    unsafe extern "C" fn _post_return_read_json_value(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_read_json_value::<$ty>(arg0)
+    }
     #[unsafe(export_name = "write-file")]
     unsafe extern "C" fn export_write_file(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
       $($path_to_types)*::_export_write_file_cabi::<$ty>(arg0, arg1, arg2, arg3)
@@ -642,6 +6059,110 @@ macro_rules! __export_world_fs_cabi{
     unsafe extern "C" fn _post_return_write_file(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_write_file::<$ty>(arg0)
     }
+    #[unsafe(export_name = "write-file-bounded")]
+    unsafe extern "C" fn export_write_file_bounded(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_write_file_bounded_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[unsafe(export_name = "cabi_post_write-file-bounded")]
+    unsafe extern "C" fn _post_return_write_file_bounded(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_write_file_bounded::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "atomic-write-file")]
+    unsafe extern "C" fn export_atomic_write_file(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_atomic_write_file_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_atomic-write-file")]
+    unsafe extern "C" fn _post_return_atomic_write_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_atomic_write_file::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "write-lines")]
+    unsafe extern "C" fn export_write_lines(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_write_lines_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[unsafe(export_name = "cabi_post_write-lines")]
+    unsafe extern "C" fn _post_return_write_lines(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_write_lines::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "write-json-value")]
+    unsafe extern "C" fn export_write_json_value(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_write_json_value_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[unsafe(export_name = "cabi_post_write-json-value")]
+    unsafe extern "C" fn _post_return_write_json_value(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_write_json_value::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "merge-json-files")]
+    unsafe extern "C" fn export_merge_json_files(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,arg6: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_merge_json_files_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
+    }
+    #[unsafe(export_name = "cabi_post_merge-json-files")]
+    unsafe extern "C" fn _post_return_merge_json_files(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_merge_json_files::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "replace-in-file")]
+    unsafe extern "C" fn export_replace_in_file(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,arg6: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_replace_in_file_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
+    }
+    #[unsafe(export_name = "cabi_post_replace-in-file")]
+    unsafe extern "C" fn _post_return_replace_in_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_replace_in_file::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "replace-regex-in-file")]
+    unsafe extern "C" fn export_replace_regex_in_file(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,arg6: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_replace_regex_in_file_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
+    }
+    #[unsafe(export_name = "cabi_post_replace-regex-in-file")]
+    unsafe extern "C" fn _post_return_replace_regex_in_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_replace_regex_in_file::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "insert-line-at")]
+    unsafe extern "C" fn export_insert_line_at(arg0: *mut u8,arg1: usize,arg2: i64,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_insert_line_at_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[unsafe(export_name = "cabi_post_insert-line-at")]
+    unsafe extern "C" fn _post_return_insert_line_at(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_insert_line_at::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "delete-line-at")]
+    unsafe extern "C" fn export_delete_line_at(arg0: *mut u8,arg1: usize,arg2: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_delete_line_at_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[unsafe(export_name = "cabi_post_delete-line-at")]
+    unsafe extern "C" fn _post_return_delete_line_at(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_delete_line_at::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "write-file-bytes")]
+    unsafe extern "C" fn export_write_file_bytes(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_write_file_bytes_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_write-file-bytes")]
+    unsafe extern "C" fn _post_return_write_file_bytes(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_write_file_bytes::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "append-to-file")]
+    unsafe extern "C" fn export_append_to_file(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_append_to_file_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_append-to-file")]
+    unsafe extern "C" fn _post_return_append_to_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_append_to_file::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "truncate-file")]
+    unsafe extern "C" fn export_truncate_file(arg0: *mut u8,arg1: usize,arg2: i64,) -> *mut u8 {
+      $($path_to_types)*::_export_truncate_file_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[unsafe(export_name = "cabi_post_truncate-file")]
+    unsafe extern "C" fn _post_return_truncate_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_truncate_file::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "rotate-file")]
+    unsafe extern "C" fn export_rotate_file(arg0: *mut u8,arg1: usize,arg2: i64,arg3: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_rotate_file_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_rotate-file")]
+    unsafe extern "C" fn _post_return_rotate_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_rotate_file::<$ty>(arg0)
+    }
     #[unsafe(export_name = "create-directory")]
     unsafe extern "C" fn export_create_directory(arg0: *mut u8,arg1: usize,) -> *mut u8 {
       $($path_to_types)*::_export_create_directory_cabi::<$ty>(arg0, arg1)
@@ -650,6 +6171,30 @@ macro_rules! __export_world_fs_cabi{
     unsafe extern "C" fn _post_return_create_directory(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_create_directory::<$ty>(arg0)
     }
+    #[unsafe(export_name = "ensure-directory")]
+    unsafe extern "C" fn export_ensure_directory(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_ensure_directory_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_ensure-directory")]
+    unsafe extern "C" fn _post_return_ensure_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_ensure_directory::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "create-temp-directory")]
+    unsafe extern "C" fn export_create_temp_directory(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_create_temp_directory_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_create-temp-directory")]
+    unsafe extern "C" fn _post_return_create_temp_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_create_temp_directory::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "delete-temp-directory")]
+    unsafe extern "C" fn export_delete_temp_directory(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_delete_temp_directory_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_delete-temp-directory")]
+    unsafe extern "C" fn _post_return_delete_temp_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_delete_temp_directory::<$ty>(arg0)
+    }
     #[unsafe(export_name = "move-path")]
     unsafe extern "C" fn export_move_path(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
       $($path_to_types)*::_export_move_path_cabi::<$ty>(arg0, arg1, arg2, arg3)
@@ -658,6 +6203,62 @@ macro_rules! __export_world_fs_cabi{
     unsafe extern "C" fn _post_return_move_path(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_move_path::<$ty>(arg0)
     }
+    #[unsafe(export_name = "copy-file")]
+    unsafe extern "C" fn export_copy_file(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_copy_file_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_copy-file")]
+    unsafe extern "C" fn _post_return_copy_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_copy_file::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "copy-directory")]
+    unsafe extern "C" fn export_copy_directory(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_copy_directory_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_copy-directory")]
+    unsafe extern "C" fn _post_return_copy_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_copy_directory::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "zip-directory")]
+    unsafe extern "C" fn export_zip_directory(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_zip_directory_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_zip-directory")]
+    unsafe extern "C" fn _post_return_zip_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_zip_directory::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "unzip-to-directory")]
+    unsafe extern "C" fn export_unzip_to_directory(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_unzip_to_directory_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_unzip-to-directory")]
+    unsafe extern "C" fn _post_return_unzip_to_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_unzip_to_directory::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "create-symlink")]
+    unsafe extern "C" fn export_create_symlink(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_create_symlink_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_create-symlink")]
+    unsafe extern "C" fn _post_return_create_symlink(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_create_symlink::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "create-hard-link")]
+    unsafe extern "C" fn export_create_hard_link(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_create_hard_link_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_create-hard-link")]
+    unsafe extern "C" fn _post_return_create_hard_link(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_create_hard_link::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "read-symlink")]
+    unsafe extern "C" fn export_read_symlink(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_read_symlink_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_read-symlink")]
+    unsafe extern "C" fn _post_return_read_symlink(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_read_symlink::<$ty>(arg0)
+    }
     #[unsafe(export_name = "delete-file")]
     unsafe extern "C" fn export_delete_file(arg0: *mut u8,arg1: usize,) -> *mut u8 {
       $($path_to_types)*::_export_delete_file_cabi::<$ty>(arg0, arg1)
@@ -674,6 +6275,30 @@ macro_rules! __export_world_fs_cabi{
     unsafe extern "C" fn _post_return_delete_directory(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_delete_directory::<$ty>(arg0)
     }
+    #[unsafe(export_name = "delete-directory-recursive")]
+    unsafe extern "C" fn export_delete_directory_recursive(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_delete_directory_recursive_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_delete-directory-recursive")]
+    unsafe extern "C" fn _post_return_delete_directory_recursive(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_delete_directory_recursive::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "set-permissions")]
+    unsafe extern "C" fn export_set_permissions(arg0: *mut u8,arg1: usize,arg2: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_set_permissions_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[unsafe(export_name = "cabi_post_set-permissions")]
+    unsafe extern "C" fn _post_return_set_permissions(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_set_permissions::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "set-unix-permissions")]
+    unsafe extern "C" fn export_set_unix_permissions(arg0: *mut u8,arg1: usize,arg2: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_set_unix_permissions_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[unsafe(export_name = "cabi_post_set-unix-permissions")]
+    unsafe extern "C" fn _post_return_set_unix_permissions(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_set_unix_permissions::<$ty>(arg0)
+    }
     #[unsafe(export_name = "file-exists")]
     unsafe extern "C" fn export_file_exists(arg0: *mut u8,arg1: usize,) -> *mut u8 {
       $($path_to_types)*::_export_file_exists_cabi::<$ty>(arg0, arg1)
@@ -682,22 +6307,110 @@ macro_rules! __export_world_fs_cabi{
     unsafe extern "C" fn _post_return_file_exists(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_file_exists::<$ty>(arg0)
     }
+    #[unsafe(export_name = "directory-exists")]
+    unsafe extern "C" fn export_directory_exists(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_directory_exists_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_directory-exists")]
+    unsafe extern "C" fn _post_return_directory_exists(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_directory_exists::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "symlink-exists")]
+    unsafe extern "C" fn export_symlink_exists(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_symlink_exists_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_symlink-exists")]
+    unsafe extern "C" fn _post_return_symlink_exists(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_symlink_exists::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "any-path-exists")]
+    unsafe extern "C" fn export_any_path_exists(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_any_path_exists_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_any-path-exists")]
+    unsafe extern "C" fn _post_return_any_path_exists(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_any_path_exists::<$ty>(arg0)
+    }
     #[unsafe(export_name = "get-directory-tree")]
-    unsafe extern "C" fn export_get_directory_tree(arg0: *mut u8,arg1: usize,arg2: i32,) -> *mut u8 {
-      $($path_to_types)*::_export_get_directory_tree_cabi::<$ty>(arg0, arg1, arg2)
+    unsafe extern "C" fn export_get_directory_tree(arg0: *mut u8,arg1: usize,arg2: i32,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_directory_tree_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4)
     }
     #[unsafe(export_name = "cabi_post_get-directory-tree")]
     unsafe extern "C" fn _post_return_get_directory_tree(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_get_directory_tree::<$ty>(arg0)
     }
+    #[unsafe(export_name = "get-directory-tree-with-sizes")]
+    unsafe extern "C" fn export_get_directory_tree_with_sizes(arg0: *mut u8,arg1: usize,arg2: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_get_directory_tree_with_sizes_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[unsafe(export_name = "cabi_post_get-directory-tree-with-sizes")]
+    unsafe extern "C" fn _post_return_get_directory_tree_with_sizes(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_directory_tree_with_sizes::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-directory-tree-json")]
+    unsafe extern "C" fn export_get_directory_tree_json(arg0: *mut u8,arg1: usize,arg2: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_get_directory_tree_json_cabi::<$ty>(arg0, arg1, arg2)
+    }
+    #[unsafe(export_name = "cabi_post_get-directory-tree-json")]
+    unsafe extern "C" fn _post_return_get_directory_tree_json(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_directory_tree_json::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-directory-tree-filtered")]
+    unsafe extern "C" fn export_get_directory_tree_filtered(arg0: *mut u8,arg1: usize,arg2: i32,arg3: *mut u8,arg4: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_directory_tree_filtered_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4)
+    }
+    #[unsafe(export_name = "cabi_post_get-directory-tree-filtered")]
+    unsafe extern "C" fn _post_return_get_directory_tree_filtered(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_directory_tree_filtered::<$ty>(arg0)
+    }
     #[unsafe(export_name = "search-file")]
-    unsafe extern "C" fn export_search_file(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
-      $($path_to_types)*::_export_search_file_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    unsafe extern "C" fn export_search_file(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_search_file_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
     }
     #[unsafe(export_name = "cabi_post_search-file")]
     unsafe extern "C" fn _post_return_search_file(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_search_file::<$ty>(arg0)
     }
+    #[unsafe(export_name = "search-file-relative")]
+    unsafe extern "C" fn export_search_file_relative(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_search_file_relative_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[unsafe(export_name = "cabi_post_search-file-relative")]
+    unsafe extern "C" fn _post_return_search_file_relative(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_search_file_relative::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "find-files-by-extension")]
+    unsafe extern "C" fn export_find_files_by_extension(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_find_files_by_extension_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_find-files-by-extension")]
+    unsafe extern "C" fn _post_return_find_files_by_extension(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_find_files_by_extension::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "search-file-content")]
+    unsafe extern "C" fn export_search_file_content(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,arg6: i32,) -> *mut u8 {
+      $($path_to_types)*::_export_search_file_content_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5, arg6)
+    }
+    #[unsafe(export_name = "cabi_post_search-file-content")]
+    unsafe extern "C" fn _post_return_search_file_content(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_search_file_content::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "count-matching-lines")]
+    unsafe extern "C" fn export_count_matching_lines(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_count_matching_lines_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_count-matching-lines")]
+    unsafe extern "C" fn _post_return_count_matching_lines(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_count_matching_lines::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "count-matching-lines-in-directory")]
+    unsafe extern "C" fn export_count_matching_lines_in_directory(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_count_matching_lines_in_directory_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_count-matching-lines-in-directory")]
+    unsafe extern "C" fn _post_return_count_matching_lines_in_directory(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_count_matching_lines_in_directory::<$ty>(arg0)
+    }
     #[unsafe(export_name = "get-file-info")]
     unsafe extern "C" fn export_get_file_info(arg0: *mut u8,arg1: usize,) -> *mut u8 {
       $($path_to_types)*::_export_get_file_info_cabi::<$ty>(arg0, arg1)
@@ -706,21 +6419,150 @@ macro_rules! __export_world_fs_cabi{
     unsafe extern "C" fn _post_return_get_file_info(arg0: *mut u8,) {
       $($path_to_types)*::__post_return_get_file_info::<$ty>(arg0)
     }
+    #[unsafe(export_name = "get-file-info-structured")]
+    unsafe extern "C" fn export_get_file_info_structured(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_file_info_structured_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_get-file-info-structured")]
+    unsafe extern "C" fn _post_return_get_file_info_structured(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_file_info_structured::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "validate-wasm-file")]
+    unsafe extern "C" fn export_validate_wasm_file(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_validate_wasm_file_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_validate-wasm-file")]
+    unsafe extern "C" fn _post_return_validate_wasm_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_validate_wasm_file::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "wasm-component-exports")]
+    unsafe extern "C" fn export_wasm_component_exports(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_wasm_component_exports_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_wasm-component-exports")]
+    unsafe extern "C" fn _post_return_wasm_component_exports(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_wasm_component_exports::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "wasm-component-imports")]
+    unsafe extern "C" fn export_wasm_component_imports(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_wasm_component_imports_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_wasm-component-imports")]
+    unsafe extern "C" fn _post_return_wasm_component_imports(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_wasm_component_imports::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "compose-components")]
+    unsafe extern "C" fn export_compose_components(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,arg4: *mut u8,arg5: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_compose_components_cabi::<$ty>(arg0, arg1, arg2, arg3, arg4, arg5)
+    }
+    #[unsafe(export_name = "cabi_post_compose-components")]
+    unsafe extern "C" fn _post_return_compose_components(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_compose_components::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-disk-usage")]
+    unsafe extern "C" fn export_get_disk_usage(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_disk_usage_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_get-disk-usage")]
+    unsafe extern "C" fn _post_return_get_disk_usage(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_disk_usage::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-directory-size")]
+    unsafe extern "C" fn export_get_directory_size(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_directory_size_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_get-directory-size")]
+    unsafe extern "C" fn _post_return_get_directory_size(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_directory_size::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-directory-stats")]
+    unsafe extern "C" fn export_get_directory_stats(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_directory_stats_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_get-directory-stats")]
+    unsafe extern "C" fn _post_return_get_directory_stats(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_directory_stats::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "find-duplicate-files")]
+    unsafe extern "C" fn export_find_duplicate_files(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_find_duplicate_files_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_find-duplicate-files")]
+    unsafe extern "C" fn _post_return_find_duplicate_files(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_find_duplicate_files::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "count-lines")]
+    unsafe extern "C" fn export_count_lines(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_count_lines_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_count-lines")]
+    unsafe extern "C" fn _post_return_count_lines(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_count_lines::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "count-bytes")]
+    unsafe extern "C" fn export_count_bytes(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_count_bytes_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_count-bytes")]
+    unsafe extern "C" fn _post_return_count_bytes(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_count_bytes::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "summarise-wit-file")]
+    unsafe extern "C" fn export_summarise_wit_file(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_summarise_wit_file_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_summarise-wit-file")]
+    unsafe extern "C" fn _post_return_summarise_wit_file(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_summarise_wit_file::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-file-hash")]
+    unsafe extern "C" fn export_get_file_hash(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_file_hash_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_get-file-hash")]
+    unsafe extern "C" fn _post_return_get_file_hash(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_file_hash::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "compare-files")]
+    unsafe extern "C" fn export_compare_files(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_compare_files_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_compare-files")]
+    unsafe extern "C" fn _post_return_compare_files(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_compare_files::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "get-file-change-token")]
+    unsafe extern "C" fn export_get_file_change_token(arg0: *mut u8,arg1: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_get_file_change_token_cabi::<$ty>(arg0, arg1)
+    }
+    #[unsafe(export_name = "cabi_post_get-file-change-token")]
+    unsafe extern "C" fn _post_return_get_file_change_token(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_get_file_change_token::<$ty>(arg0)
+    }
+    #[unsafe(export_name = "has-file-changed-since")]
+    unsafe extern "C" fn export_has_file_changed_since(arg0: *mut u8,arg1: usize,arg2: *mut u8,arg3: usize,) -> *mut u8 {
+      $($path_to_types)*::_export_has_file_changed_since_cabi::<$ty>(arg0, arg1, arg2, arg3)
+    }
+    #[unsafe(export_name = "cabi_post_has-file-changed-since")]
+    unsafe extern "C" fn _post_return_has_file_changed_since(arg0: *mut u8,) {
+      $($path_to_types)*::__post_return_has_file_changed_since::<$ty>(arg0)
+    }
   };);
 }
 #[doc(hidden)]
 pub(crate) use __export_world_fs_cabi;
-#[repr(align(4))]
-struct _RetArea([::core::mem::MaybeUninit<u8>; 12]);
-static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 12]);
+#[repr(align(8))]
+struct _RetArea([::core::mem::MaybeUninit<u8>; 112]);
+static mut _RET_AREA: _RetArea = _RetArea([::core::mem::MaybeUninit::uninit(); 112]);
 mod _rt {
     #![allow(dead_code, clippy::all)]
+    pub use alloc_crate::string::String;
+    pub use alloc_crate::vec::Vec;
 
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
     }
-    pub use alloc_crate::vec::Vec;
     pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
         if cfg!(debug_assertions) {
             String::from_utf8(bytes).unwrap()
@@ -728,7 +6570,6 @@ mod _rt {
             String::from_utf8_unchecked(bytes)
         }
     }
-    pub use alloc_crate::alloc;
     pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
         if size == 0 {
             return;
@@ -736,7 +6577,116 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
-    pub use alloc_crate::string::String;
+    pub use alloc_crate::alloc;
+
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
+
+    pub fn as_i32<T: AsI32>(t: T) -> i32 {
+        t.as_i32()
+    }
+
+    pub trait AsI32 {
+        fn as_i32(self) -> i32;
+    }
+
+    impl<'a, T: Copy + AsI32> AsI32 for &'a T {
+        fn as_i32(self) -> i32 {
+            (*self).as_i32()
+        }
+    }
+
+    impl AsI32 for i32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for u32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for i16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for u16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for i8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for u8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for char {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+
+    impl AsI32 for usize {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
     extern crate alloc as alloc_crate;
 }
 
@@ -772,18 +6722,92 @@ pub(crate) use __export_fs_impl as export;
 #[unsafe(link_section = "component-type:wit-bindgen:0.37.0:component:filesystem2:fs:encoded world")]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 491] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xf2\x02\x01A\x02\x01\
-A\x16\x01ps\x01j\x01\0\x01s\x01@\x01\x04paths\0\x01\x04\0\x0elist-directory\x01\x02\
-\x01j\x01s\x01s\x01@\x01\x04paths\0\x03\x04\0\x09read-file\x01\x04\x01@\x02\x04p\
-aths\x07contents\0\x03\x04\0\x0awrite-file\x01\x05\x04\0\x10create-directory\x01\
-\x04\x01@\x02\x06sources\x0bdestinations\0\x03\x04\0\x09move-path\x01\x06\x04\0\x0b\
-delete-file\x01\x04\x04\0\x10delete-directory\x01\x04\x01j\x01\x7f\x01s\x01@\x01\
-\x04paths\0\x07\x04\0\x0bfile-exists\x01\x08\x01@\x02\x04paths\x09max-depthy\0\x03\
-\x04\0\x12get-directory-tree\x01\x09\x01@\x02\x04paths\x07patterns\0\x03\x04\0\x0b\
-search-file\x01\x0a\x04\0\x0dget-file-info\x01\x04\x04\0\x18component:filesystem\
-2/fs\x04\0\x0b\x08\x01\0\x02fs\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0d\
-wit-component\x070.223.1\x10wit-bindgen-rust\x060.37.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 4252] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa3\x20\x01A\x02\x01\
+A\xc3\x01\x01kw\x01ks\x01ky\x01r\x0a\x04paths\x09file-types\x0asize-bytesw\x08re\
+adonly\x7f\x0dmodified-secs\0\x08modified\x01\x08accessed\x01\x07created\x01\x0a\
+mode-octal\x01\x13wasm-module-version\x02\x03\0\x09file-info\x03\0\x03\x01r\x03\x0b\
+total-bytesw\x0favailable-bytesw\x0aused-bytesw\x03\0\x0adisk-usage\x03\0\x05\x01\
+o\x02sw\x01k\x07\x01r\x06\x0btotal-filesw\x11total-directoriesw\x0etotal-symlink\
+sw\x10total-size-bytesw\x0clargest-file\x08\x0boldest-file\x08\x03\0\x0fdirector\
+y-stats\x03\0\x09\x01r\x02\x04names\x04kinds\x03\0\x0cexport-entry\x03\0\x0b\x01\
+r\x03\x06modules\x04names\x04kinds\x03\0\x0cimport-entry\x03\0\x0d\x01m\x04\x04f\
+ile\x03dir\x07symlink\x07unknown\x03\0\x0aentry-type\x03\0\x0f\x01m\x04\x04name\x04\
+size\x08modified\x09extension\x03\0\x0asort-order\x03\0\x11\x01r\x04\x04names\x0d\
+relative-paths\x0aentry-type\x10\x0asize-bytesw\x03\0\x09dir-entry\x03\0\x13\x01\
+r\x02\x04texts\x11encoding-detecteds\x03\0\x0cfile-content\x03\0\x15\x01r\x02\x04\
+texts\x0abytes-readw\x03\0\x0atext-range\x03\0\x17\x01p}\x01r\x02\x04data\x19\x0a\
+bytes-readw\x03\0\x0bbytes-range\x03\0\x1a\x01r\x02\x04paths\x0falready-existed\x7f\
+\x03\0\x0densure-result\x03\0\x1c\x01j\x01s\x01s\x01@\x01\x04paths\0\x1e\x04\0\x12\
+set-root-directory\x01\x1f\x01@\0\0s\x04\0\x12get-root-directory\x01\x20\x01@\x01\
+\x09max-bytesw\0\x1e\x04\0\x14set-write-size-limit\x01!\x04\0\x12set-jail-direct\
+ory\x01\x1f\x04\0\x0aclear-jail\x01\x20\x04\0\x0enormalize-path\x01\x1f\x01@\x01\
+\x04names\0\x1e\x04\0\x18get-environment-variable\x01\"\x01ps\x01j\x01#\x01s\x01\
+@\0\0$\x04\0\x1alist-environment-variables\x01%\x01@\0\0\x1e\x04\0\x12get-temp-d\
+irectory\x01&\x01p\x14\x01j\x01'\x01s\x01@\x01\x04paths\0(\x04\0\x0elist-directo\
+ry\x01)\x04\0\x16list-directory-visible\x01)\x04\0\x12list-directory-all\x01)\x01\
+@\x03\x04paths\x07sort-by\x12\x0adescending\x7f\0(\x04\0\x15list-directory-sorte\
+d\x01*\x01@\x01\x04paths\0$\x04\0\x18list-directory-formatted\x01+\x01@\x02\x04p\
+aths\x09max-depthy\0(\x04\0\x18list-directory-recursive\x01,\x04\0\x09read-file\x01\
+\x1f\x01j\x01\x16\x01s\x01@\x01\x04paths\0-\x04\0\x17read-file-with-encoding\x01\
+.\x01j\x01\x19\x01s\x01@\x01\x04paths\0/\x04\0\x0fread-file-bytes\x010\x01j\x01\x18\
+\x01s\x01@\x03\x04paths\x06offsetw\x06lengthw\01\x04\0\x0fread-file-range\x012\x01\
+j\x01\x1b\x01s\x01@\x03\x04paths\x06offsetw\x06lengthw\03\x04\0\x15read-file-byt\
+es-range\x014\x01@\x03\x04paths\x0askip-empty\x7f\x0ftrim-whitespace\x7f\0$\x04\0\
+\x0aread-lines\x015\x01@\x03\x04paths\x0astart-linew\x08end-linew\0$\x04\0\x10re\
+ad-lines-range\x016\x01@\x02\x04paths\x0cjson-pointers\0\x1e\x04\0\x0fread-json-\
+value\x017\x01@\x02\x04paths\x07contents\0\x1e\x04\0\x0awrite-file\x018\x01@\x03\
+\x04paths\x07contents\x09max-bytesw\0\x1e\x04\0\x12write-file-bounded\x019\x04\0\
+\x11atomic-write-file\x018\x01@\x03\x04paths\x05lines#\x0bline-endings\0\x1e\x04\
+\0\x0bwrite-lines\x01:\x01@\x03\x04paths\x0cjson-pointers\x05values\0\x1e\x04\0\x10\
+write-json-value\x01;\x01@\x04\x09base-paths\x0doverride-paths\x0boutput-paths\x0b\
+array-merge\x7f\0\x1e\x04\0\x10merge-json-files\x01<\x01@\x04\x04paths\x04finds\x07\
+replaces\x10max-replacementsy\0\x1e\x04\0\x0freplace-in-file\x01=\x01@\x04\x04pa\
+ths\x07patterns\x07replaces\x10max-replacementsy\0\x1e\x04\0\x15replace-regex-in\
+-file\x01>\x01@\x03\x04paths\x0bline-numberw\x07contents\0\x1e\x04\0\x0einsert-l\
+ine-at\x01?\x01@\x02\x04paths\x0bline-numberw\0\x1e\x04\0\x0edelete-line-at\x01@\
+\x01@\x02\x04paths\x07content\x19\0\x1e\x04\0\x10write-file-bytes\x01A\x04\0\x0e\
+append-to-file\x018\x01@\x02\x04paths\x0asize-bytesw\0\x1e\x04\0\x0dtruncate-fil\
+e\x01B\x01@\x03\x04paths\x0emax-size-bytesw\x0bmax-backupsy\0\x1e\x04\0\x0brotat\
+e-file\x01C\x04\0\x10create-directory\x01\x1f\x01j\x01\x1d\x01s\x01@\x01\x04path\
+s\0\xc4\0\x04\0\x10ensure-directory\x01E\x01@\x01\x06prefixs\0\x1e\x04\0\x15crea\
+te-temp-directory\x01F\x04\0\x15delete-temp-directory\x01\x1f\x01@\x02\x06source\
+s\x0bdestinations\0\x1e\x04\0\x09move-path\x01G\x04\0\x09copy-file\x01G\x04\0\x0e\
+copy-directory\x01G\x01@\x02\x06sources\x0fdestination-zips\0\x1e\x04\0\x0dzip-d\
+irectory\x01H\x01@\x02\x08zip-paths\x0bdestinations\0\x1e\x04\0\x12unzip-to-dire\
+ctory\x01I\x01@\x02\x06targets\x09link-paths\0\x1e\x04\0\x0ecreate-symlink\x01J\x01\
+@\x02\x06sources\x09link-paths\0\x1e\x04\0\x10create-hard-link\x01K\x01@\x01\x09\
+link-paths\0\x1e\x04\0\x0cread-symlink\x01L\x04\0\x0bdelete-file\x01\x1f\x04\0\x10\
+delete-directory\x01\x1f\x04\0\x1adelete-directory-recursive\x01\x1f\x01@\x02\x04\
+paths\x08readonly\x7f\0\x1e\x04\0\x0fset-permissions\x01M\x01@\x02\x04paths\x04m\
+odey\0\x1e\x04\0\x14set-unix-permissions\x01N\x01j\x01\x7f\x01s\x01@\x01\x04path\
+s\0\xcf\0\x04\0\x0bfile-exists\x01P\x04\0\x10directory-exists\x01P\x04\0\x0esyml\
+ink-exists\x01P\x04\0\x0fany-path-exists\x01P\x01@\x03\x04paths\x09max-depthy\x07\
+sort-bys\0\x1e\x04\0\x12get-directory-tree\x01Q\x01@\x02\x04paths\x09max-depthy\0\
+\x1e\x04\0\x1dget-directory-tree-with-sizes\x01R\x04\0\x17get-directory-tree-jso\
+n\x01R\x01@\x03\x04paths\x09max-depthy\x06filters\0\x1e\x04\0\x1bget-directory-t\
+ree-filtered\x01S\x01@\x03\x04paths\x07patterns\x0cpattern-types\0\x1e\x04\0\x0b\
+search-file\x01T\x04\0\x14search-file-relative\x01T\x01@\x02\x04roots\x09extensi\
+ons\0$\x04\0\x17find-files-by-extension\x01U\x01@\x04\x04roots\x07patterns\x0cpa\
+ttern-types\x0bmax-resultsy\0$\x04\0\x13search-file-content\x01V\x01j\x01w\x01s\x01\
+@\x02\x04paths\x07patterns\0\xd7\0\x04\0\x14count-matching-lines\x01X\x01p\x07\x01\
+j\x01\xd9\0\x01s\x01@\x02\x04roots\x07patterns\0\xda\0\x04\0!count-matching-line\
+s-in-directory\x01[\x04\0\x0dget-file-info\x01\x1f\x01j\x01\x04\x01s\x01@\x01\x04\
+paths\0\xdc\0\x04\0\x18get-file-info-structured\x01]\x04\0\x12validate-wasm-file\
+\x01\x1f\x01p\x0c\x01j\x01\xde\0\x01s\x01@\x01\x04paths\0\xdf\0\x04\0\x16wasm-co\
+mponent-exports\x01`\x01p\x0e\x01j\x01\xe1\0\x01s\x01@\x01\x04paths\0\xe2\0\x04\0\
+\x16wasm-component-imports\x01c\x01@\x03\x10component-a-paths\x10component-b-pat\
+hs\x0boutput-paths\0\x1e\x04\0\x12compose-components\x01d\x01j\x01\x06\x01s\x01@\
+\x01\x04paths\0\xe5\0\x04\0\x0eget-disk-usage\x01f\x04\0\x12get-directory-size\x01\
+\x1f\x01j\x01\x0a\x01s\x01@\x01\x04paths\0\xe7\0\x04\0\x13get-directory-stats\x01\
+h\x01@\x02\x04roots\x09algorithms\0\x1e\x04\0\x14find-duplicate-files\x01i\x01@\x01\
+\x04paths\0\xd7\0\x04\0\x0bcount-lines\x01j\x04\0\x0bcount-bytes\x01j\x04\0\x12s\
+ummarise-wit-file\x01\x1f\x01@\x02\x04paths\x09algorithms\0\x1e\x04\0\x0dget-fil\
+e-hash\x01k\x01@\x02\x06path-as\x06path-bs\0\xcf\0\x04\0\x0dcompare-files\x01l\x04\
+\0\x15get-file-change-token\x01\x1f\x01@\x02\x04paths\x05tokens\0\xcf\0\x04\0\x16\
+has-file-changed-since\x01m\x04\0\x18component:filesystem2/fs\x04\0\x0b\x08\x01\0\
+\x02fs\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.223\
+.1\x10wit-bindgen-rust\x060.37.0";
 
 #[inline(never)]
 #[doc(hidden)]
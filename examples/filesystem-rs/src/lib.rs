@@ -4,16 +4,201 @@
 #[allow(warnings)]
 mod bindings;
 
+use std::cell::{Cell, RefCell};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::{env, fs};
 
 use anyhow::{anyhow, Result};
-use bindings::Guest;
+use bindings::{
+    BytesRange, DirEntry, DirectoryStats, DiskUsage, EnsureResult, EntryType, ExportEntry,
+    FileContent, FileInfo, Guest, ImportEntry, SortOrder, TextRange,
+};
+use regex::{Regex, RegexBuilder};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
 
 struct Component;
 
 impl Guest for Component {
-    fn list_directory(path: String) -> Result<Vec<String>, String> {
+    fn set_root_directory(path: String) -> Result<String, String> {
+        if path.is_empty() {
+            ROOT_DIRECTORY.with(|root| root.borrow_mut().take());
+            return Ok("Relative paths now resolve against the working directory".to_string());
+        }
+
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.exists() {
+                    return Err(format!("Directory '{}' does not exist", path.display()));
+                }
+                if !path.is_dir() {
+                    return Err(format!("'{}' is not a directory", path.display()));
+                }
+
+                let message = format!("Relative paths now resolve against '{}'", path.display());
+                ROOT_DIRECTORY.with(|root| *root.borrow_mut() = Some(path));
+                Ok(message)
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn get_root_directory() -> String {
+        ROOT_DIRECTORY.with(|root| match &*root.borrow() {
+            Some(path) => path.display().to_string(),
+            None => ".".to_string(),
+        })
+    }
+
+    fn set_write_size_limit(max_bytes: u64) -> Result<String, String> {
+        let limit = (max_bytes > 0).then_some(max_bytes);
+        WRITE_SIZE_LIMIT.with(|current| current.set(limit));
+        Ok(match limit {
+            Some(limit) => format!("write-file is now limited to {} bytes", limit),
+            None => "write-file is no longer limited in size".to_string(),
+        })
+    }
+
+    fn set_jail_directory(path: String) -> Result<String, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        if !path.is_dir() {
+            return Err(format!("'{}' is not a directory", path.display()));
+        }
+        let jail = fs::canonicalize(&path)
+            .map_err(|e| format!("Failed to resolve '{}': {}", path.display(), e))?;
+
+        let message = format!("Paths are now restricted to '{}'", jail.display());
+        JAIL_DIRECTORY.with(|current| *current.borrow_mut() = Some(jail));
+        Ok(message)
+    }
+
+    fn clear_jail() -> String {
+        JAIL_DIRECTORY.with(|jail| jail.borrow_mut().take());
+        "Paths are no longer restricted to a directory".to_string()
+    }
+
+    fn normalize_path(path: String) -> Result<String, String> {
+        let resolved = get_path(&path).map_err(|e| e.to_string())?;
+        let normalized = normalize(&resolved)
+            .map_err(|e| format!("Failed to normalise '{}': {}", resolved.display(), e))?;
+
+        // Only relative paths are resolved against the root, so only they can escape it
+        let relative = Path::new(&path).is_relative() && !path.starts_with('~');
+        let root = ROOT_DIRECTORY.with(|root| root.borrow().clone());
+        if let Some(root) = root.filter(|_| relative) {
+            let root = normalize(&root).unwrap_or(root);
+            if !normalized.starts_with(&root) {
+                return Err(format!(
+                    "Path '{}' escapes the root directory '{}'",
+                    path,
+                    root.display()
+                ));
+            }
+        }
+        Ok(normalized.display().to_string())
+    }
+
+    fn get_environment_variable(name: String) -> Result<String, String> {
+        match env::var(&name) {
+            Ok(value) => Ok(value),
+            Err(env::VarError::NotPresent) => Err(format!(
+                "Environment variable '{}' is not set or not allowed by the policy",
+                name
+            )),
+            Err(env::VarError::NotUnicode(_)) => Err(format!(
+                "Environment variable '{}' is not valid Unicode",
+                name
+            )),
+        }
+    }
+
+    fn list_environment_variables() -> Result<Vec<String>, String> {
+        let mut vars: Vec<(String, String)> = env::vars_os()
+            .map(|(name, value)| {
+                (
+                    name.to_string_lossy().into_owned(),
+                    value.to_string_lossy().into_owned(),
+                )
+            })
+            .collect();
+        vars.sort();
+        Ok(vars
+            .into_iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect())
+    }
+
+    fn get_temp_directory() -> Result<String, String> {
+        Ok(env::temp_dir().to_string_lossy().into_owned())
+    }
+
+    fn list_directory(path: String) -> Result<Vec<DirEntry>, String> {
+        match get_path(&path) {
+            Ok(path) => read_dir_entries(&path, true).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn list_directory_visible(path: String) -> Result<Vec<DirEntry>, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        read_dir_entries(&path, false).map_err(|e| e.to_string())
+    }
+
+    fn list_directory_all(path: String) -> Result<Vec<DirEntry>, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        read_dir_entries(&path, true).map_err(|e| e.to_string())
+    }
+
+    fn list_directory_sorted(
+        path: String,
+        sort_by: SortOrder,
+        descending: bool,
+    ) -> Result<Vec<DirEntry>, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                let mut entries = fs::read_dir(&path)
+                    .and_then(|entries| entries.collect::<std::io::Result<Vec<_>>>())
+                    .map_err(|e| format!("Failed to read directory '{}': {}", path.display(), e))?;
+                sort_tree_entries(&mut entries, sort_by, descending);
+                Ok(entries
+                    .iter()
+                    .map(|entry| {
+                        to_dir_entry(entry, entry.file_name().to_string_lossy().to_string())
+                    })
+                    .collect())
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn list_directory_recursive(path: String, max_depth: u32) -> Result<Vec<DirEntry>, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.is_dir() {
+                    return Err(format!("'{}' is not a directory", path.display()));
+                }
+
+                let mut entries = Vec::new();
+                let mut visited = VisitedDirs::default();
+                visited.insert(&path);
+                match walk_dir_entries(&path, "", 0, max_depth, &mut visited, &mut entries) {
+                    Ok(_) => Ok(entries),
+                    Err(e) => Err(format!("Failed to list directory recursively: {}", e)),
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn list_directory_formatted(path: String) -> Result<Vec<String>, String> {
         match get_path(&path) {
             Ok(path) => {
                 let mut text = vec![];
@@ -63,250 +248,530 @@ impl Guest for Component {
         }
     }
 
-    fn write_file(path: String, content: String) -> Result<String, String> {
+    fn read_file_with_encoding(path: String) -> Result<FileContent, String> {
         match get_path(&path) {
             Ok(path) => {
-                // Ensure parent directory exists
-                if let Some(parent) = path.parent() {
-                    if !parent.exists() {
-                        if let Err(e) = fs::create_dir_all(parent) {
-                            return Err(format!(
-                                "Failed to create parent directory '{}': {}",
-                                parent.display(),
-                                e
-                            ));
-                        }
-                    }
-                }
-
-                match fs::write(&path, content.as_bytes()) {
-                    Ok(_) => Ok(format!("Successfully wrote to file '{}'", path.display())),
-                    Err(e) => Err(format!(
-                        "Failed to write to file '{}': {}",
-                        path.display(),
-                        e
-                    )),
-                }
+                let bytes = fs::read(&path)
+                    .map_err(|e| format!("Failed to read file '{}': {}", path.display(), e))?;
+                let (text, encoding) = decode_text(&bytes);
+                Ok(FileContent {
+                    text,
+                    encoding_detected: encoding.to_string(),
+                })
             }
             Err(e) => Err(e.to_string()),
         }
     }
 
-    fn create_directory(path: String) -> Result<String, String> {
+    fn read_file_bytes(path: String) -> Result<Vec<u8>, String> {
         match get_path(&path) {
-            Ok(path) => match fs::create_dir_all(&path) {
-                Ok(_) => Ok(format!(
-                    "Successfully created directory '{}'",
-                    path.display()
-                )),
-                Err(e) => Err(format!(
-                    "Failed to create directory '{}': {}",
-                    path.display(),
-                    e
-                )),
-            },
+            Ok(path) => fs::read(&path)
+                .map_err(|e| format!("Failed to read file '{}': {}", path.display(), e)),
             Err(e) => Err(e.to_string()),
         }
     }
 
-    fn move_path(source: String, destination: String) -> Result<String, String> {
-        let source_path = match get_path(&source) {
-            Ok(p) => p,
-            Err(e) => return Err(e.to_string()),
-        };
+    fn read_file_range(path: String, offset: u64, length: u64) -> Result<TextRange, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                let bytes = read_range(&path, offset, length).map_err(|e| e.to_string())?;
+                let bytes_read = bytes.len() as u64;
+                let text = String::from_utf8(bytes).map_err(|_| {
+                    format!(
+                        "Bytes {}..{} of '{}' are not valid UTF-8; use read-file-bytes-range instead",
+                        offset,
+                        offset.saturating_add(length),
+                        path.display()
+                    )
+                })?;
+                Ok(TextRange { text, bytes_read })
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
 
-        let dest_path = match get_path(&destination) {
-            Ok(p) => p,
-            Err(e) => return Err(e.to_string()),
-        };
+    fn read_file_bytes_range(path: String, offset: u64, length: u64) -> Result<BytesRange, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                let data = read_range(&path, offset, length).map_err(|e| e.to_string())?;
+                Ok(BytesRange {
+                    bytes_read: data.len() as u64,
+                    data,
+                })
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
 
-        if !source_path.exists() {
+    fn read_lines(
+        path: String,
+        skip_empty: bool,
+        trim_whitespace: bool,
+    ) -> Result<Vec<String>, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        let mut lines = Vec::new();
+        for_each_line(&path, |_, line| {
+            let line = if trim_whitespace {
+                line.trim().to_string()
+            } else {
+                line
+            };
+            if !(skip_empty && line.is_empty()) {
+                lines.push(line);
+            }
+            true
+        })
+        .map_err(|e| e.to_string())?;
+        Ok(lines)
+    }
+
+    fn read_lines_range(
+        path: String,
+        start_line: u64,
+        end_line: u64,
+    ) -> Result<Vec<String>, String> {
+        if start_line == 0 {
+            return Err("Line numbers start at 1".to_string());
+        }
+        if end_line < start_line {
             return Err(format!(
-                "Source path '{}' does not exist",
-                source_path.display()
+                "end-line {} is before start-line {}",
+                end_line, start_line
             ));
         }
 
-        // Ensure parent directory of destination exists
-        if let Some(parent) = dest_path.parent() {
-            if !parent.exists() {
-                if let Err(e) = fs::create_dir_all(parent) {
-                    return Err(format!(
-                        "Failed to create destination parent directory '{}': {}",
-                        parent.display(),
-                        e
-                    ));
-                }
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        let mut lines = Vec::new();
+        let mut line_count = 0;
+        for_each_line(&path, |number, line| {
+            line_count = number;
+            if number >= start_line {
+                lines.push(line);
             }
-        }
+            number < end_line
+        })
+        .map_err(|e| e.to_string())?;
 
-        match fs::rename(&source_path, &dest_path) {
-            Ok(_) => Ok(format!(
-                "Successfully moved '{}' to '{}'",
-                source_path.display(),
-                dest_path.display()
-            )),
-            Err(e) => Err(format!(
-                "Failed to move '{}' to '{}': {}",
-                source_path.display(),
-                dest_path.display(),
-                e
-            )),
+        if line_count < start_line {
+            return Err(format!(
+                "Line {} is past the end of '{}' ({} lines)",
+                start_line,
+                path.display(),
+                line_count
+            ));
         }
+        Ok(lines)
     }
 
-    fn delete_file(path: String) -> Result<String, String> {
+    fn read_json_value(path: String, json_pointer: String) -> Result<String, String> {
         match get_path(&path) {
             Ok(path) => {
-                if !path.exists() {
-                    return Err(format!("File '{}' does not exist", path.display()));
-                }
-
-                if path.is_dir() {
-                    return Err(format!(
-                        "'{}' is a directory, use delete-directory instead",
+                let document = read_json_document(&path).map_err(|e| e.to_string())?;
+                let value = document.pointer(&json_pointer).ok_or_else(|| {
+                    format!(
+                        "JSON pointer '{}' does not match any value in '{}'",
+                        json_pointer,
                         path.display()
-                    ));
+                    )
+                })?;
+                serde_json::to_string(value).map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn write_file(path: String, content: String) -> Result<String, String> {
+        if let Some(limit) = WRITE_SIZE_LIMIT.with(Cell::get) {
+            check_write_size(&content, limit)?;
+        }
+        match get_path(&path) {
+            Ok(path) => {
+                if let Err(e) = ensure_parent_dir(&path) {
+                    return Err(e.to_string());
                 }
 
-                match fs::remove_file(&path) {
-                    Ok(_) => Ok(format!("Successfully deleted file '{}'", path.display())),
-                    Err(e) => Err(format!("Failed to delete file '{}': {}", path.display(), e)),
+                match fs::write(&path, content.as_bytes()) {
+                    Ok(_) => Ok(format!("Successfully wrote to file '{}'", path.display())),
+                    Err(e) => Err(format!(
+                        "Failed to write to file '{}': {}",
+                        path.display(),
+                        e
+                    )),
                 }
             }
             Err(e) => Err(e.to_string()),
         }
     }
 
-    fn delete_directory(path: String) -> Result<String, String> {
+    fn write_file_bounded(path: String, content: String, max_bytes: u64) -> Result<String, String> {
+        check_write_size(&content, max_bytes)?;
+        Self::write_file(path, content)
+    }
+
+    fn atomic_write_file(path: String, content: String) -> Result<String, String> {
         match get_path(&path) {
             Ok(path) => {
-                if !path.exists() {
-                    return Err(format!("Directory '{}' does not exist", path.display()));
+                if path.is_dir() {
+                    return Err(format!("'{}' is a directory", path.display()));
                 }
 
-                if !path.is_dir() {
-                    return Err(format!(
-                        "'{}' is not a directory, use delete-file instead",
-                        path.display()
-                    ));
+                if let Err(e) = ensure_parent_dir(&path) {
+                    return Err(e.to_string());
                 }
 
-                match fs::remove_dir(&path) {
+                match atomic_write(&path, content.as_bytes()) {
                     Ok(_) => Ok(format!(
-                        "Successfully deleted directory '{}'",
+                        "Successfully wrote {} bytes atomically to file '{}'",
+                        content.len(),
                         path.display()
                     )),
-                    Err(e) => {
-                        let error_msg =
-                            format!("Failed to delete directory '{}': {}", path.display(), e);
-                        // Directory not empty errors often contain "not empty" in the message
-                        if e.to_string().to_lowercase().contains("not empty") {
-                            Err(format!("{} Remove all contents first.", error_msg))
-                        } else {
-                            Err(error_msg)
-                        }
-                    }
+                    Err(e) => Err(e.to_string()),
                 }
             }
             Err(e) => Err(e.to_string()),
         }
     }
 
-    fn file_exists(path: String) -> Result<bool, String> {
-        match get_path(&path) {
-            Ok(path) => Ok(path.exists()),
-            Err(e) => Err(e.to_string()),
+    fn write_lines(
+        path: String,
+        lines: Vec<String>,
+        line_ending: String,
+    ) -> Result<String, String> {
+        let line_ending = match line_ending.to_lowercase().as_str() {
+            "lf" => "\n",
+            "crlf" => "\r\n",
+            "platform" if cfg!(windows) => "\r\n",
+            "platform" => "\n",
+            other => {
+                return Err(format!(
+                    "Unknown line ending '{}', expected 'lf', 'crlf' or 'platform'",
+                    other
+                ))
+            }
+        };
+
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        if path.is_dir() {
+            return Err(format!("'{}' is a directory", path.display()));
         }
+        ensure_parent_dir(&path).map_err(|e| e.to_string())?;
+
+        let mut content = String::new();
+        for line in &lines {
+            content.push_str(line);
+            content.push_str(line_ending);
+        }
+        atomic_write(&path, content.as_bytes()).map_err(|e| e.to_string())?;
+        Ok(format!(
+            "Successfully wrote {} lines ({} bytes) to file '{}'",
+            lines.len(),
+            content.len(),
+            path.display()
+        ))
     }
 
-    fn get_directory_tree(path: String, max_depth: u32) -> Result<String, String> {
+    fn write_json_value(
+        path: String,
+        json_pointer: String,
+        value: String,
+    ) -> Result<String, String> {
         match get_path(&path) {
             Ok(path) => {
-                if !path.exists() {
-                    return Err(format!("Path '{}' does not exist", path.display()));
-                }
-
-                if !path.is_dir() {
-                    return Err(format!("'{}' is not a directory", path.display()));
-                }
+                let value: Value = serde_json::from_str(&value)
+                    .map_err(|e| format!("Value is not valid JSON: {}", e))?;
+                let mut document = read_json_document(&path).map_err(|e| e.to_string())?;
+                set_json_pointer(&mut document, &json_pointer, value)
+                    .map_err(|e| format!("Failed to update '{}': {}", path.display(), e))?;
 
-                let mut output = String::new();
-                if let Err(e) = build_tree(&path, &mut output, 0, max_depth, "") {
-                    return Err(format!("Failed to build directory tree: {}", e));
+                let mut content =
+                    serde_json::to_string_pretty(&document).map_err(|e| e.to_string())?;
+                content.push('\n');
+                match atomic_write(&path, content.as_bytes()) {
+                    Ok(_) => Ok(format!(
+                        "Successfully set '{}' in JSON file '{}'",
+                        json_pointer,
+                        path.display()
+                    )),
+                    Err(e) => Err(e.to_string()),
                 }
-                Ok(output)
             }
             Err(e) => Err(e.to_string()),
         }
     }
 
-    fn search_file(path: String, pattern: String) -> Result<String, String> {
-        let path = match get_path(&path) {
-            Ok(p) => p,
-            Err(e) => {
-                return Err(e.to_string());
+    fn merge_json_files(
+        base_path: String,
+        override_path: String,
+        output_path: String,
+        array_merge: bool,
+    ) -> Result<String, String> {
+        let base_path = get_path(&base_path).map_err(|e| e.to_string())?;
+        let override_path = get_path(&override_path).map_err(|e| e.to_string())?;
+        let output_path = get_path(&output_path).map_err(|e| e.to_string())?;
+
+        let mut merged = read_json_document(&base_path).map_err(|e| e.to_string())?;
+        let overlay = read_json_document(&override_path).map_err(|e| e.to_string())?;
+        for (path, value) in [(&base_path, &merged), (&override_path, &overlay)] {
+            if !value.is_object() {
+                return Err(format!(
+                    "'{}' must contain a JSON object at the top level",
+                    path.display()
+                ));
             }
-        };
-        let mut matches = Vec::new();
-        if let Err(e) = search_directory(&path, &pattern, &mut matches) {
-            return Err(format!("Failed to search directory: {}", e));
         }
+        merge_json(&mut merged, overlay, array_merge);
 
-        if matches.is_empty() {
-            Ok(format!(
-                "No files matching pattern '{}' found in '{}'",
-                pattern,
-                path.display()
-            ))
-        } else {
-            Ok(matches.join("\n"))
-        }
+        ensure_parent_dir(&output_path).map_err(|e| e.to_string())?;
+        let mut content = serde_json::to_string_pretty(&merged).map_err(|e| e.to_string())?;
+        content.push('\n');
+        atomic_write(&output_path, content.as_bytes()).map_err(|e| e.to_string())?;
+        Ok(format!(
+            "Successfully merged '{}' into '{}' and wrote '{}'",
+            override_path.display(),
+            base_path.display(),
+            output_path.display()
+        ))
     }
 
-    fn get_file_info(path: String) -> Result<String, String> {
-        match get_path(&path) {
-            Ok(path) => match fs::symlink_metadata(&path) {
-                Ok(metadata) => {
-                    let file_type = if metadata.is_dir() {
-                        "Directory"
-                    } else if metadata.is_file() {
-                        "File"
-                    } else if metadata.is_symlink() {
-                        "Symlink"
-                    } else {
-                        "Unknown"
-                    };
+    fn replace_in_file(
+        path: String,
+        find: String,
+        replace: String,
+        max_replacements: u32,
+    ) -> Result<String, String> {
+        if find.is_empty() {
+            return Err("The text to find must not be empty".to_string());
+        }
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        let limit = replacement_limit(max_replacements);
+        let count = rewrite_file(&path, |reader, writer| {
+            replace_literal(reader, writer, find.as_bytes(), replace.as_bytes(), limit)
+        })
+        .map_err(|e| e.to_string())?;
+        Ok(format!(
+            "Made {} replacements in file '{}'",
+            count,
+            path.display()
+        ))
+    }
 
-                    let size = metadata.len();
-                    let size_str = format_size(size);
+    fn replace_regex_in_file(
+        path: String,
+        pattern: String,
+        replace: String,
+        max_replacements: u32,
+    ) -> Result<String, String> {
+        let regex = Regex::new(&pattern)
+            .map_err(|e| format!("Invalid regex pattern '{}': {}", pattern, e))?;
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        let limit = replacement_limit(max_replacements);
+        let count = rewrite_file(&path, |reader, writer| {
+            replace_regex(reader, writer, &regex, &replace, limit)
+        })
+        .map_err(|e| e.to_string())?;
+        Ok(format!(
+            "Made {} replacements in file '{}'",
+            count,
+            path.display()
+        ))
+    }
 
-                    let permissions = metadata.permissions();
+    fn insert_line_at(path: String, line_number: u64, content: String) -> Result<String, String> {
+        if content.contains(['\n', '\r']) {
+            return Err("Content must be a single line".to_string());
+        }
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        rewrite_file(&path, |reader, writer| {
+            edit_line(reader, writer, line_number, |line, writer| {
+                let ending: &[u8] = if line.ends_with(b"\r\n") {
+                    b"\r\n"
+                } else {
+                    b"\n"
+                };
+                writer.write_all(content.as_bytes())?;
+                writer.write_all(ending)?;
+                writer.write_all(line)
+            })
+        })
+        .map_err(|e| e.to_string())?;
+        Ok(format!(
+            "Inserted line {} in file '{}'",
+            line_number,
+            path.display()
+        ))
+    }
 
-                    let modified = metadata
-                        .modified()
-                        .ok()
-                        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-                        .map(|d| {
-                            let secs = d.as_secs();
-                            format!("{} seconds since epoch", secs)
-                        })
-                        .unwrap_or_else(|| "Unknown".to_string());
+    fn delete_line_at(path: String, line_number: u64) -> Result<String, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        rewrite_file(&path, |reader, writer| {
+            edit_line(reader, writer, line_number, |_, _| Ok(()))
+        })
+        .map_err(|e| e.to_string())?;
+        Ok(format!(
+            "Deleted line {} from file '{}'",
+            line_number,
+            path.display()
+        ))
+    }
 
-                    let readonly = if permissions.readonly() { "yes" } else { "no" };
+    fn write_file_bytes(path: String, content: Vec<u8>) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if let Err(e) = ensure_parent_dir(&path) {
+                    return Err(e.to_string());
+                }
 
-                    Ok(format!(
-                        "Path: {}\nType: {}\nSize: {} ({} bytes)\nRead-only: {}\nModified: {}",
+                match fs::write(&path, &content) {
+                    Ok(_) => Ok(format!(
+                        "Successfully wrote {} bytes to file '{}'",
+                        content.len(),
+                        path.display()
+                    )),
+                    Err(e) => Err(format!(
+                        "Failed to write to file '{}': {}",
                         path.display(),
-                        file_type,
-                        size_str,
-                        size,
-                        readonly,
-                        modified
-                    ))
+                        e
+                    )),
                 }
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn append_to_file(path: String, content: String) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if let Err(e) = ensure_parent_dir(&path) {
+                    return Err(e.to_string());
+                }
+
+                let mut file = match OpenOptions::new().create(true).append(true).open(&path) {
+                    Ok(f) => f,
+                    Err(e) => {
+                        return Err(format!(
+                            "Failed to open file '{}' for appending: {}",
+                            path.display(),
+                            e
+                        ));
+                    }
+                };
+
+                if let Err(e) = file.write_all(content.as_bytes()) {
+                    return Err(format!(
+                        "Failed to append to file '{}': {}",
+                        path.display(),
+                        e
+                    ));
+                }
+
+                let total_size = file
+                    .metadata()
+                    .map(|m| m.len().to_string())
+                    .unwrap_or_else(|_| "unknown".to_string());
+
+                Ok(format!(
+                    "Successfully appended {} bytes to file '{}' (total size: {} bytes)",
+                    content.len(),
+                    path.display(),
+                    total_size
+                ))
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn truncate_file(path: String, size_bytes: u64) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.exists() {
+                    return Err(format!("File '{}' does not exist", path.display()));
+                }
+
+                if path.is_dir() {
+                    return Err(format!(
+                        "'{}' is a directory, only files can be truncated",
+                        path.display()
+                    ));
+                }
+
+                let file = match OpenOptions::new().write(true).open(&path) {
+                    Ok(f) => f,
+                    Err(e) => {
+                        return Err(format!(
+                            "Failed to open file '{}' for writing: {}",
+                            path.display(),
+                            e
+                        ));
+                    }
+                };
+
+                let current_size = match file.metadata() {
+                    Ok(m) => m.len(),
+                    Err(e) => {
+                        return Err(format!(
+                            "Failed to get metadata for '{}': {}",
+                            path.display(),
+                            e
+                        ));
+                    }
+                };
+
+                if size_bytes > current_size {
+                    return Err(format!(
+                        "Cannot truncate '{}' to {} bytes: the file is only {} bytes long",
+                        path.display(),
+                        size_bytes,
+                        current_size
+                    ));
+                }
+
+                match file.set_len(size_bytes) {
+                    Ok(_) => Ok(format!(
+                        "Successfully truncated file '{}' from {} bytes to {} bytes",
+                        path.display(),
+                        current_size,
+                        size_bytes
+                    )),
+                    Err(e) => Err(format!(
+                        "Failed to truncate file '{}': {}",
+                        path.display(),
+                        e
+                    )),
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn rotate_file(path: String, max_size_bytes: u64, max_backups: u32) -> Result<String, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        if !path.is_file() {
+            return Err(format!("'{}' is not a file", path.display()));
+        }
+
+        match rotate(&path, max_size_bytes, max_backups).map_err(|e| e.to_string())? {
+            Some(renamed) => Ok(format!(
+                "Rotated '{}', renaming {} file(s)",
+                path.display(),
+                renamed
+            )),
+            None => Ok(format!(
+                "'{}' is within {} bytes, no rotation needed",
+                path.display(),
+                max_size_bytes
+            )),
+        }
+    }
+
+    fn create_directory(path: String) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => match fs::create_dir_all(&path) {
+                Ok(_) => Ok(format!(
+                    "Successfully created directory '{}'",
+                    path.display()
+                )),
                 Err(e) => Err(format!(
-                    "Failed to get metadata for '{}': {}",
+                    "Failed to create directory '{}': {}",
                     path.display(),
                     e
                 )),
@@ -314,129 +779,4715 @@ impl Guest for Component {
             Err(e) => Err(e.to_string()),
         }
     }
+
+    fn ensure_directory(path: String) -> Result<EnsureResult, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        let already_existed = match fs::metadata(&path) {
+            Ok(metadata) if metadata.is_dir() => true,
+            Ok(_) => {
+                return Err(format!(
+                    "Path '{}' exists but is not a directory",
+                    path.display()
+                ))
+            }
+            Err(_) => {
+                fs::create_dir_all(&path).map_err(|e| {
+                    format!("Failed to create directory '{}': {}", path.display(), e)
+                })?;
+                false
+            }
+        };
+        Ok(EnsureResult {
+            path: path.display().to_string(),
+            already_existed,
+        })
+    }
+
+    fn create_temp_directory(prefix: String) -> Result<String, String> {
+        if prefix.contains(['/', '\\']) || prefix == "." || prefix == ".." {
+            return Err(format!(
+                "Prefix '{}' can't be used in a directory name",
+                prefix
+            ));
+        }
+
+        let mut last_error = None;
+        for _ in 0..8 {
+            let candidate = env::temp_dir().join(format!("{}{}", prefix, random_suffix()));
+            let path = get_path(&candidate.display().to_string()).map_err(|e| e.to_string())?;
+            match fs::create_dir(&path) {
+                Ok(()) => {
+                    TEMP_DIRECTORIES.with(|dirs| dirs.borrow_mut().push(path.clone()));
+                    return Ok(path.display().to_string());
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => last_error = Some(e),
+                Err(e) => {
+                    return Err(format!(
+                        "Failed to create temporary directory '{}': {}",
+                        path.display(),
+                        e
+                    ))
+                }
+            }
+        }
+        Err(format!(
+            "Failed to create a temporary directory: {}",
+            last_error.map(|e| e.to_string()).unwrap_or_default()
+        ))
+    }
+
+    fn delete_temp_directory(path: String) -> Result<String, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        let created = TEMP_DIRECTORIES.with(|dirs| {
+            let mut dirs = dirs.borrow_mut();
+            let index = dirs.iter().position(|dir| *dir == path)?;
+            Some(dirs.remove(index))
+        });
+        let Some(path) = created else {
+            return Err(format!(
+                "'{}' was not created by create-temp-directory",
+                path.display()
+            ));
+        };
+
+        match fs::remove_dir_all(&path) {
+            Ok(()) => Ok(format!(
+                "Successfully deleted temporary directory '{}'",
+                path.display()
+            )),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(format!(
+                "Temporary directory '{}' was already deleted",
+                path.display()
+            )),
+            Err(e) => {
+                TEMP_DIRECTORIES.with(|dirs| dirs.borrow_mut().push(path.clone()));
+                Err(format!(
+                    "Failed to delete temporary directory '{}': {}",
+                    path.display(),
+                    e
+                ))
+            }
+        }
+    }
+
+    fn move_path(source: String, destination: String) -> Result<String, String> {
+        let source_path = match get_path(&source) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let dest_path = match get_path(&destination) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if !source_path.exists() {
+            return Err(format!(
+                "Source path '{}' does not exist",
+                source_path.display()
+            ));
+        }
+
+        // Ensure parent directory of destination exists
+        if let Some(parent) = dest_path.parent() {
+            if !parent.exists() {
+                if let Err(e) = fs::create_dir_all(parent) {
+                    return Err(format!(
+                        "Failed to create destination parent directory '{}': {}",
+                        parent.display(),
+                        e
+                    ));
+                }
+            }
+        }
+
+        let moved = match fs::rename(&source_path, &dest_path) {
+            // Rename can't cross filesystem boundaries, so copy and delete instead
+            Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+                move_across_devices(&source_path, &dest_path)
+            }
+            other => other.map_err(Into::into),
+        };
+
+        match moved {
+            Ok(_) => Ok(format!(
+                "Successfully moved '{}' to '{}'",
+                source_path.display(),
+                dest_path.display()
+            )),
+            Err(e) => Err(format!(
+                "Failed to move '{}' to '{}': {}",
+                source_path.display(),
+                dest_path.display(),
+                e
+            )),
+        }
+    }
+
+    fn copy_file(source: String, destination: String) -> Result<String, String> {
+        let source_path = match get_path(&source) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let dest_path = match get_path(&destination) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if !source_path.exists() {
+            return Err(format!(
+                "Source path '{}' does not exist",
+                source_path.display()
+            ));
+        }
+
+        if source_path.is_dir() {
+            return Err(format!(
+                "'{}' is a directory, only files can be copied",
+                source_path.display()
+            ));
+        }
+
+        if dest_path.is_dir() {
+            return Err(format!(
+                "Destination '{}' is a directory, provide the full destination file path",
+                dest_path.display()
+            ));
+        }
+
+        // Ensure parent directory of destination exists
+        if let Some(parent) = dest_path.parent() {
+            if !parent.exists() {
+                if let Err(e) = fs::create_dir_all(parent) {
+                    return Err(format!(
+                        "Failed to create destination parent directory '{}': {}",
+                        parent.display(),
+                        e
+                    ));
+                }
+            }
+        }
+
+        // Unlike `fs::rename`, `fs::copy` also works across filesystem boundaries
+        match fs::copy(&source_path, &dest_path) {
+            Ok(bytes) => Ok(format!(
+                "Successfully copied '{}' to '{}' ({} bytes)",
+                source_path.display(),
+                dest_path.display(),
+                bytes
+            )),
+            Err(e) => Err(format!(
+                "Failed to copy '{}' to '{}': {}",
+                source_path.display(),
+                dest_path.display(),
+                e
+            )),
+        }
+    }
+
+    fn copy_directory(source: String, destination: String) -> Result<String, String> {
+        let source_path = match get_path(&source) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let dest_path = match get_path(&destination) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if !source_path.exists() {
+            return Err(format!(
+                "Source path '{}' does not exist",
+                source_path.display()
+            ));
+        }
+
+        if !source_path.is_dir() {
+            return Err(format!(
+                "'{}' is not a directory, use copy-file instead",
+                source_path.display()
+            ));
+        }
+
+        if dest_path.exists() {
+            return Err(format!(
+                "Destination '{}' already exists",
+                dest_path.display()
+            ));
+        }
+
+        if dest_path.starts_with(&source_path) {
+            return Err(format!(
+                "Cannot copy '{}' into itself ('{}')",
+                source_path.display(),
+                dest_path.display()
+            ));
+        }
+
+        // Ensure parent directory of destination exists
+        if let Some(parent) = dest_path.parent() {
+            if !parent.exists() {
+                if let Err(e) = fs::create_dir_all(parent) {
+                    return Err(format!(
+                        "Failed to create destination parent directory '{}': {}",
+                        parent.display(),
+                        e
+                    ));
+                }
+            }
+        }
+
+        let mut stats = CopyStats::default();
+        if let Err(e) = copy_dir_recursive(&source_path, &dest_path, false, &mut stats) {
+            // Don't leave a half-copied tree behind
+            let _ = fs::remove_dir_all(&dest_path);
+            return Err(format!(
+                "Failed to copy directory '{}' to '{}': {}",
+                source_path.display(),
+                dest_path.display(),
+                e
+            ));
+        }
+
+        let mut message = format!(
+            "Successfully copied '{}' to '{}' ({} files, {} directories)",
+            source_path.display(),
+            dest_path.display(),
+            stats.files,
+            stats.directories
+        );
+        if stats.skipped_symlinks > 0 {
+            message.push_str(&format!(", skipped {} symlinks", stats.skipped_symlinks));
+        }
+        Ok(message)
+    }
+
+    fn zip_directory(source: String, destination_zip: String) -> Result<String, String> {
+        let source_path = match get_path(&source) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let zip_path = match get_path(&destination_zip) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if !source_path.is_dir() {
+            return Err(format!("'{}' is not a directory", source_path.display()));
+        }
+        if zip_path.is_dir() {
+            return Err(format!("'{}' is a directory", zip_path.display()));
+        }
+
+        if let Err(e) = ensure_parent_dir(&zip_path) {
+            return Err(e.to_string());
+        }
+
+        match zip_dir(&source_path, &zip_path) {
+            Ok(stats) => {
+                let mut message = format!(
+                    "Successfully zipped {} files and {} directories from '{}' into '{}'",
+                    stats.files,
+                    stats.directories,
+                    source_path.display(),
+                    zip_path.display()
+                );
+                if stats.skipped_symlinks > 0 {
+                    message.push_str(&format!(" ({} symlinks skipped)", stats.skipped_symlinks));
+                }
+                Ok(message)
+            }
+            Err(e) => {
+                let _ = fs::remove_file(&zip_path);
+                Err(e.to_string())
+            }
+        }
+    }
+
+    fn unzip_to_directory(zip_path: String, destination: String) -> Result<String, String> {
+        let zip_path = match get_path(&zip_path) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let dest_path = match get_path(&destination) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if !zip_path.is_file() {
+            return Err(format!(
+                "Zip archive '{}' does not exist",
+                zip_path.display()
+            ));
+        }
+        if dest_path.exists() && !dest_path.is_dir() {
+            return Err(format!("'{}' is not a directory", dest_path.display()));
+        }
+
+        match unzip_into(&zip_path, &dest_path) {
+            Ok(stats) => {
+                let mut message = format!(
+                    "Successfully extracted {} files and {} directories from '{}' into '{}'",
+                    stats.files,
+                    stats.directories,
+                    zip_path.display(),
+                    dest_path.display()
+                );
+                if stats.skipped_symlinks > 0 {
+                    message.push_str(&format!(" ({} symlinks skipped)", stats.skipped_symlinks));
+                }
+                Ok(message)
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn create_hard_link(source: String, link_path: String) -> Result<String, String> {
+        let source = get_path(&source).map_err(|e| e.to_string())?;
+        let link = get_path(&link_path).map_err(|e| e.to_string())?;
+
+        match fs::metadata(&source) {
+            Ok(metadata) if metadata.is_dir() => {
+                return Err(format!(
+                    "'{}' is a directory, only files can be hard linked",
+                    source.display()
+                ))
+            }
+            Ok(_) => {}
+            Err(e) => return Err(format!("Failed to access '{}': {}", source.display(), e)),
+        }
+        if fs::symlink_metadata(&link).is_ok() {
+            return Err(format!("'{}' already exists", link.display()));
+        }
+        ensure_parent_dir(&link).map_err(|e| e.to_string())?;
+
+        match fs::hard_link(&source, &link) {
+            Ok(_) => Ok(format!(
+                "Successfully created hard link '{}' to '{}'",
+                link.display(),
+                source.display()
+            )),
+            Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => Err(format!(
+                "Failed to create hard link '{}' to '{}': they are on different filesystems",
+                link.display(),
+                source.display()
+            )),
+            Err(e) => Err(format!(
+                "Failed to create hard link '{}' to '{}': {}",
+                link.display(),
+                source.display(),
+                e
+            )),
+        }
+    }
+
+    fn create_symlink(target: String, link_path: String) -> Result<String, String> {
+        let target_path = match get_path(&target) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let link = match get_path(&link_path) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if fs::symlink_metadata(&link).is_ok() {
+            return Err(format!("'{}' already exists", link.display()));
+        }
+
+        if let Err(e) = ensure_parent_dir(&link) {
+            return Err(e.to_string());
+        }
+
+        match create_symlink_impl(&target_path, &link) {
+            Ok(_) => Ok(format!(
+                "Successfully created symlink '{}' pointing to '{}'",
+                link.display(),
+                target_path.display()
+            )),
+            Err(e) => {
+                let error_msg = format!(
+                    "Failed to create symlink '{}' pointing to '{}': {}",
+                    link.display(),
+                    target_path.display(),
+                    e
+                );
+                if cfg!(windows) && e.kind() == std::io::ErrorKind::PermissionDenied {
+                    Err(format!(
+                        "{} Creating symlinks on Windows requires Developer Mode or elevated privileges.",
+                        error_msg
+                    ))
+                } else {
+                    Err(error_msg)
+                }
+            }
+        }
+    }
+
+    fn read_symlink(link_path: String) -> Result<String, String> {
+        match get_path(&link_path) {
+            Ok(path) => match fs::symlink_metadata(&path) {
+                Ok(metadata) if metadata.is_symlink() => match fs::read_link(&path) {
+                    Ok(target) => Ok(target.to_string_lossy().to_string()),
+                    Err(e) => Err(format!(
+                        "Failed to read symlink '{}': {}",
+                        path.display(),
+                        e
+                    )),
+                },
+                Ok(_) => Err(format!("'{}' is not a symlink", path.display())),
+                Err(e) => Err(format!(
+                    "Failed to get metadata for '{}': {}",
+                    path.display(),
+                    e
+                )),
+            },
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn delete_file(path: String) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.exists() {
+                    return Err(format!("File '{}' does not exist", path.display()));
+                }
+
+                if path.is_dir() {
+                    return Err(format!(
+                        "'{}' is a directory, use delete-directory instead",
+                        path.display()
+                    ));
+                }
+
+                match fs::remove_file(&path) {
+                    Ok(_) => Ok(format!("Successfully deleted file '{}'", path.display())),
+                    Err(e) => Err(format!("Failed to delete file '{}': {}", path.display(), e)),
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn delete_directory(path: String) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.exists() {
+                    return Err(format!("Directory '{}' does not exist", path.display()));
+                }
+
+                if !path.is_dir() {
+                    return Err(format!(
+                        "'{}' is not a directory, use delete-file instead",
+                        path.display()
+                    ));
+                }
+
+                match fs::remove_dir(&path) {
+                    Ok(_) => Ok(format!(
+                        "Successfully deleted directory '{}'",
+                        path.display()
+                    )),
+                    Err(e) => {
+                        let error_msg =
+                            format!("Failed to delete directory '{}': {}", path.display(), e);
+                        // Directory not empty errors often contain "not empty" in the message
+                        if e.to_string().to_lowercase().contains("not empty") {
+                            Err(format!(
+                                "{} Remove all contents first or use delete-directory-recursive.",
+                                error_msg
+                            ))
+                        } else {
+                            Err(error_msg)
+                        }
+                    }
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn delete_directory_recursive(path: String) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                let metadata = match fs::symlink_metadata(&path) {
+                    Ok(m) => m,
+                    Err(_) => {
+                        return Err(format!("Directory '{}' does not exist", path.display()));
+                    }
+                };
+
+                // Never follow a symlink at the top level, otherwise the contents of the
+                // link target (possibly outside the allowed directories) would be removed
+                if metadata.is_symlink() {
+                    return Err(format!(
+                        "'{}' is a symlink, use delete-file to remove the link itself",
+                        path.display()
+                    ));
+                }
+
+                if !metadata.is_dir() {
+                    return Err(format!(
+                        "'{}' is not a directory, use delete-file instead",
+                        path.display()
+                    ));
+                }
+
+                if path.parent().is_none() || path.file_name().is_none() || is_allowed_root(&path) {
+                    return Err(format!(
+                        "Refusing to recursively delete root directory '{}'",
+                        path.display()
+                    ));
+                }
+
+                // `remove_dir_all` removes symlinks inside the tree without following them
+                fs::remove_dir_all(&path)
+                    .map(|_| {
+                        format!(
+                            "Successfully deleted directory '{}' and all of its contents",
+                            path.display()
+                        )
+                    })
+                    .map_err(|e| delete_directory_error(&path, &e))
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn set_permissions(path: String, readonly: bool) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                let mut permissions = match fs::metadata(&path) {
+                    Ok(m) => m.permissions(),
+                    Err(e) => {
+                        return Err(format!(
+                            "Failed to get metadata for '{}': {}",
+                            path.display(),
+                            e
+                        ));
+                    }
+                };
+                permissions.set_readonly(readonly);
+
+                match fs::set_permissions(&path, permissions) {
+                    Ok(_) => Ok(format!(
+                        "Successfully updated permissions of '{}' ({})",
+                        path.display(),
+                        describe_permissions(&path)
+                    )),
+                    Err(e) => Err(permission_error(&path, e)),
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn set_unix_permissions(path: String, mode: u32) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.exists() {
+                    return Err(format!("Path '{}' does not exist", path.display()));
+                }
+
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    match fs::set_permissions(&path, fs::Permissions::from_mode(mode)) {
+                        Ok(_) => Ok(format!(
+                            "Successfully updated permissions of '{}' ({})",
+                            path.display(),
+                            describe_permissions(&path)
+                        )),
+                        Err(e) => Err(permission_error(&path, e)),
+                    }
+                }
+                #[cfg(not(unix))]
+                {
+                    Err(format!(
+                        "Cannot set mode {:o} on '{}': Unix permissions are not supported on this platform",
+                        mode,
+                        path.display()
+                    ))
+                }
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn file_exists(path: String) -> Result<bool, String> {
+        match get_path(&path) {
+            Ok(path) => Ok(path.exists()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn directory_exists(path: String) -> Result<bool, String> {
+        match get_path(&path) {
+            Ok(path) => Ok(path.is_dir()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn symlink_exists(path: String) -> Result<bool, String> {
+        match get_path(&path) {
+            // symlink_metadata doesn't follow the link, so a dangling one is still found
+            Ok(path) => Ok(std::fs::symlink_metadata(&path)
+                .is_ok_and(|metadata| metadata.file_type().is_symlink())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn any_path_exists(path: String) -> Result<bool, String> {
+        Self::file_exists(path)
+    }
+
+    fn get_directory_tree(path: String, max_depth: u32, sort_by: String) -> Result<String, String> {
+        let (sort_by, descending) = SortOrder::parse(&sort_by).map_err(|e| e.to_string())?;
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.exists() {
+                    return Err(format!("Path '{}' does not exist", path.display()));
+                }
+
+                if !path.is_dir() {
+                    return Err(format!("'{}' is not a directory", path.display()));
+                }
+
+                let options = TreeOptions {
+                    sort_by,
+                    descending,
+                    show_sizes: false,
+                };
+                let mut output = String::new();
+                if let Err(e) = build_tree(&path, &mut output, 0, max_depth, "", options) {
+                    return Err(format!("Failed to build directory tree: {}", e));
+                }
+                Ok(output)
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn get_directory_tree_with_sizes(path: String, max_depth: u32) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.is_dir() {
+                    return Err(format!("'{}' is not a directory", path.display()));
+                }
+
+                let options = TreeOptions {
+                    sort_by: SortOrder::Name,
+                    descending: false,
+                    show_sizes: true,
+                };
+                let mut output = String::new();
+                if let Err(e) = build_tree(&path, &mut output, 0, max_depth, "", options) {
+                    return Err(format!("Failed to build directory tree: {}", e));
+                }
+                Ok(output)
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn get_directory_tree_json(path: String, max_depth: u32) -> Result<String, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        if !path.is_dir() {
+            return Err(format!("'{}' is not a directory", path.display()));
+        }
+
+        let children = build_tree_json(&path, 0, max_depth)
+            .map_err(|e| format!("Failed to build directory tree: {}", e))?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+        let tree = serde_json::json!({ "name": name, "type": "dir", "children": children });
+        serde_json::to_string_pretty(&tree).map_err(|e| e.to_string())
+    }
+
+    fn get_directory_tree_filtered(
+        path: String,
+        max_depth: u32,
+        filter: String,
+    ) -> Result<String, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        if !path.is_dir() {
+            return Err(format!("'{}' is not a directory", path.display()));
+        }
+        let matcher = PatternMatcher::new(&filter, "glob", true).map_err(|e| e.to_string())?;
+
+        let nodes = filtered_tree(&path, 0, max_depth, &matcher)
+            .map_err(|e| format!("Failed to build directory tree: {}", e))?;
+        if nodes.is_empty() {
+            return Ok(format!(
+                "No files matching filter '{}' found in '{}'",
+                filter,
+                path.display()
+            ));
+        }
+
+        let mut output = String::new();
+        render_tree(&nodes, &mut output, "");
+        Ok(output)
+    }
+
+    fn search_file(path: String, pattern: String, pattern_type: String) -> Result<String, String> {
+        search_file_names(&path, &pattern, &pattern_type, false)
+    }
+
+    fn search_file_relative(
+        path: String,
+        pattern: String,
+        pattern_type: String,
+    ) -> Result<String, String> {
+        search_file_names(&path, &pattern, &pattern_type, true)
+    }
+
+    fn find_files_by_extension(root: String, extension: String) -> Result<Vec<String>, String> {
+        let root = get_path(&root).map_err(|e| e.to_string())?;
+        if !root.is_dir() {
+            return Err(format!("'{}' is not a directory", root.display()));
+        }
+        // Tolerate ".wasm" as well as "wasm"
+        let extension = extension.strip_prefix('.').unwrap_or(&extension);
+        if extension.is_empty() {
+            return Err("Extension must not be empty".to_string());
+        }
+
+        let mut found = Vec::new();
+        let mut pending = vec![root];
+        while let Some(dir) = pending.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                // `DirEntry::file_type` usually comes from the directory listing, without a stat
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                let path = entry.path();
+                if file_type.is_dir() {
+                    pending.push(path);
+                } else if file_type.is_file()
+                    && path
+                        .extension()
+                        .is_some_and(|ext| ext.to_string_lossy().eq_ignore_ascii_case(extension))
+                {
+                    found.push(path.display().to_string());
+                }
+            }
+        }
+        found.sort();
+        Ok(found)
+    }
+
+    fn count_matching_lines(path: String, pattern: String) -> Result<u64, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        let regex = Regex::new(&pattern)
+            .map_err(|e| format!("Invalid regex pattern '{}': {}", pattern, e))?;
+        if path.is_dir() {
+            return Err(format!("'{}' is a directory", path.display()));
+        }
+
+        match count_matching(&path, &regex).map_err(|e| e.to_string())? {
+            Some(count) => Ok(count),
+            None => Err(format!("'{}' looks like a binary file", path.display())),
+        }
+    }
+
+    fn count_matching_lines_in_directory(
+        root: String,
+        pattern: String,
+    ) -> Result<Vec<(String, u64)>, String> {
+        let root = get_path(&root).map_err(|e| e.to_string())?;
+        let regex = Regex::new(&pattern)
+            .map_err(|e| format!("Invalid regex pattern '{}': {}", pattern, e))?;
+        if !root.is_dir() {
+            return Err(format!("'{}' is not a directory", root.display()));
+        }
+
+        let mut counts = Vec::new();
+        count_matching_in_directory(&root, "", &regex, &mut counts)
+            .map_err(|e| format!("Failed to count matching lines: {}", e))?;
+        counts.sort();
+        Ok(counts)
+    }
+
+    fn search_file_content(
+        root: String,
+        pattern: String,
+        pattern_type: String,
+        max_results: u32,
+    ) -> Result<Vec<String>, String> {
+        let root = match get_path(&root) {
+            Ok(p) => p,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let matcher = match PatternMatcher::new(&pattern, &pattern_type, false) {
+            Ok(m) => m,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if !root.exists() {
+            return Err(format!("Path '{}' does not exist", root.display()));
+        }
+
+        let limit = if max_results == 0 {
+            usize::MAX
+        } else {
+            max_results as usize
+        };
+
+        let mut matches = Vec::new();
+        let truncated = if root.is_dir() {
+            search_content_directory(&root, &matcher, limit, &mut matches)
+        } else {
+            search_content_file(&root, &matcher, limit, &mut matches)
+        };
+
+        match truncated {
+            Ok(true) => {
+                matches.push(format!(
+                    "[TRUNCATED] Stopped after {} matches, increase max-results to see more",
+                    limit
+                ));
+                Ok(matches)
+            }
+            Ok(false) => Ok(matches),
+            Err(e) => Err(format!("Failed to search file contents: {}", e)),
+        }
+    }
+
+    // Kept for backward compatibility, new callers should use `get_file_info_structured`
+    fn get_file_info(path: String) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => match read_file_info(&path) {
+                Ok(info) => {
+                    let unavailable = || "Unavailable".to_string();
+                    let readonly = if info.readonly { "yes" } else { "no" };
+                    // Components keep the version in the low half and set layer 1 in the high half
+                    let wasm_version = match info.wasm_module_version {
+                        Some(1) => "\nWASM version: 1 (core module)".to_string(),
+                        Some(version) if version >> 16 == 1 => {
+                            format!("\nWASM version: {} (component)", version & 0xffff)
+                        }
+                        Some(version) => format!("\nWASM version: {}", version),
+                        None => String::new(),
+                    };
+
+                    Ok(format!(
+                        "Path: {}\nType: {}\nSize: {} ({} bytes)\nRead-only: {}\nModified: {}\nAccessed: {}\nCreated: {}",
+                        info.path,
+                        info.file_type,
+                        format_size(info.size_bytes),
+                        info.size_bytes,
+                        readonly,
+                        info.modified.unwrap_or_else(unavailable),
+                        info.accessed.unwrap_or_else(unavailable),
+                        info.created.unwrap_or_else(unavailable)
+                    ) + &wasm_version)
+                }
+                Err(e) => Err(e.to_string()),
+            },
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn get_file_info_structured(path: String) -> Result<FileInfo, String> {
+        match get_path(&path) {
+            Ok(path) => read_file_info(&path).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn validate_wasm_file(path: String) -> Result<String, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        if !path.is_file() {
+            return Err(format!("'{}' is not a file", path.display()));
+        }
+        // The header check gives a clearer error than the validator for files that aren't wasm
+        let Some(version) = wasm_header_version(&path) else {
+            return Err(format!(
+                "'{}' is not a WebAssembly binary, it doesn't start with \\0asm",
+                path.display()
+            ));
+        };
+        let kind = match version {
+            1 => "core module",
+            0x1000d => "component",
+            other => {
+                return Err(format!(
+                    "'{}' has unsupported WebAssembly version 0x{:x}",
+                    path.display(),
+                    other
+                ))
+            }
+        };
+
+        let bytes = fs::read(&path)
+            .map_err(|e| format!("Failed to read file '{}': {}", path.display(), e))?;
+        wasmparser::Validator::new_with_features(wasmparser::WasmFeatures::all())
+            .validate_all(&bytes)
+            .map_err(|e| format!("'{}' is not valid WebAssembly: {}", path.display(), e))?;
+        let sections = count_wasm_sections(&bytes)
+            .map_err(|e| format!("Failed to parse '{}': {}", path.display(), e))?;
+
+        Ok(format!(
+            "'{}' is a valid WebAssembly {} with {} section(s)",
+            path.display(),
+            kind,
+            sections
+        ))
+    }
+
+    fn wasm_component_exports(path: String) -> Result<Vec<ExportEntry>, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        wasm_exports(&path).map_err(|e| format!("Failed to parse '{}': {}", path.display(), e))
+    }
+
+    fn wasm_component_imports(path: String) -> Result<Vec<ImportEntry>, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        wasm_imports(&path).map_err(|e| format!("Failed to parse '{}': {}", path.display(), e))
+    }
+
+    fn compose_components(
+        component_a_path: String,
+        component_b_path: String,
+        output_path: String,
+    ) -> Result<String, String> {
+        let a_path = get_path(&component_a_path).map_err(|e| e.to_string())?;
+        let b_path = get_path(&component_b_path).map_err(|e| e.to_string())?;
+        let output_path = get_path(&output_path).map_err(|e| e.to_string())?;
+        for path in [&a_path, &b_path] {
+            if wasm_header_version(path) != Some(0x1000d) {
+                return Err(format!(
+                    "'{}' is not a WebAssembly component",
+                    path.display()
+                ));
+            }
+        }
+
+        let parse_error =
+            |path: &Path, e: anyhow::Error| format!("Failed to parse '{}': {}", path.display(), e);
+        let imports = wasm_imports(&a_path).map_err(|e| parse_error(&a_path, e))?;
+        if imports.is_empty() {
+            return Err(format!("'{}' has no imports to satisfy", a_path.display()));
+        }
+        let exports = wasm_exports(&b_path).map_err(|e| parse_error(&b_path, e))?;
+        let (satisfied, unresolved): (Vec<_>, Vec<_>) = imports
+            .iter()
+            .map(|import| import.name.as_str())
+            .partition(|name| exports.iter().any(|export| export.name == *name));
+        if satisfied.is_empty() {
+            return Err(format!(
+                "'{}' provides none of the imports of '{}', unresolved imports: {}",
+                b_path.display(),
+                a_path.display(),
+                unresolved.join(", ")
+            ));
+        }
+
+        let config = wasm_compose::config::Config {
+            definitions: vec![b_path.clone()],
+            ..Default::default()
+        };
+        let bytes = wasm_compose::composer::ComponentComposer::new(&a_path, &config)
+            .compose()
+            .map_err(|e| {
+                format!(
+                    "Failed to compose '{}' with '{}': {:#}\nUnresolved imports: {}",
+                    a_path.display(),
+                    b_path.display(),
+                    e,
+                    unresolved.join(", ")
+                )
+            })?;
+        ensure_parent_dir(&output_path).map_err(|e| e.to_string())?;
+        atomic_write(&output_path, &bytes).map_err(|e| e.to_string())?;
+
+        let composed_exports =
+            wasm_exports(&output_path).map_err(|e| parse_error(&output_path, e))?;
+        let composed_imports =
+            wasm_imports(&output_path).map_err(|e| parse_error(&output_path, e))?;
+        let mut report = format!(
+            "Composed '{}' with '{}' into '{}' ({} bytes), satisfying: {}\nExports:\n",
+            a_path.display(),
+            b_path.display(),
+            output_path.display(),
+            bytes.len(),
+            satisfied.join(", ")
+        );
+        for export in &composed_exports {
+            report.push_str(&format!("  {} {}\n", export.kind, export.name));
+        }
+        report.push_str("Remaining imports:\n");
+        for import in &composed_imports {
+            report.push_str(&format!("  {} {}\n", import.kind, import.name));
+        }
+        if composed_imports.is_empty() {
+            report.push_str("  (none)\n");
+        }
+        Ok(report)
+    }
+
+    fn get_disk_usage(path: String) -> Result<DiskUsage, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.exists() {
+                    return Err(format!("Path '{}' does not exist", path.display()));
+                }
+
+                disk_usage(&path).map_err(|e| {
+                    format!("Failed to get disk usage for '{}': {}", path.display(), e)
+                })
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn get_directory_stats(path: String) -> Result<DirectoryStats, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        if !path.is_dir() {
+            return Err(format!("'{}' is not a directory", path.display()));
+        }
+
+        let mut stats = DirectoryStats {
+            total_files: 0,
+            total_directories: 0,
+            total_symlinks: 0,
+            total_size_bytes: 0,
+            largest_file: None,
+            oldest_file: None,
+        };
+        add_directory_stats(&path, "", &mut stats).map_err(|e| e.to_string())?;
+        Ok(stats)
+    }
+
+    fn get_directory_size(path: String) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if !path.is_dir() {
+                    return Err(format!("'{}' is not a directory", path.display()));
+                }
+
+                let mut size = DirectorySize::default();
+                size.add_dir(&path).map_err(|e| e.to_string())?;
+
+                let mut report = format!(
+                    "Path: {}\nTotal size: {} ({} bytes)\nFiles: {}\nDirectories: {}",
+                    path.display(),
+                    format_size(size.bytes),
+                    size.bytes,
+                    size.files,
+                    size.directories
+                );
+                if !size.unreadable.is_empty() {
+                    report.push_str(&format!(
+                        "\nSkipped unreadable directories: {}",
+                        size.unreadable.join(", ")
+                    ));
+                }
+                Ok(report)
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn get_file_hash(path: String, algorithm: String) -> Result<String, String> {
+        let algorithm = match HashAlgorithm::parse(&algorithm) {
+            Ok(a) => a,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        match get_path(&path) {
+            Ok(path) => {
+                if path.is_dir() {
+                    return Err(format!(
+                        "'{}' is a directory, only files can be hashed",
+                        path.display()
+                    ));
+                }
+
+                hash_file(&path, algorithm).map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn compare_files(path_a: String, path_b: String) -> Result<bool, String> {
+        let path_a = get_path(&path_a).map_err(|e| e.to_string())?;
+        let path_b = get_path(&path_b).map_err(|e| e.to_string())?;
+        for path in [&path_a, &path_b] {
+            if path.is_dir() {
+                return Err(format!(
+                    "'{}' is a directory, only files can be compared",
+                    path.display()
+                ));
+            }
+        }
+
+        files_identical(&path_a, &path_b).map_err(|e| e.to_string())
+    }
+
+    fn get_file_change_token(path: String) -> Result<String, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        change_token(&path).map_err(|e| e.to_string())
+    }
+
+    fn has_file_changed_since(path: String, token: String) -> Result<bool, String> {
+        let path = get_path(&path).map_err(|e| e.to_string())?;
+        Ok(change_token(&path).map_err(|e| e.to_string())? != token)
+    }
+
+    fn find_duplicate_files(root: String, algorithm: String) -> Result<String, String> {
+        let algorithm = match HashAlgorithm::parse(&algorithm) {
+            Ok(a) => a,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        match get_path(&root) {
+            Ok(root) => {
+                if !root.is_dir() {
+                    return Err(format!("'{}' is not a directory", root.display()));
+                }
+
+                let mut scan = DuplicateScan::default();
+                if let Err(e) = scan.hash_tree(&root, algorithm, &mut VisitedDirs::default()) {
+                    return Err(e.to_string());
+                }
+                Ok(scan.report(&root))
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn count_lines(path: String) -> Result<u64, String> {
+        match get_path(&path) {
+            Ok(path) => {
+                if path.is_dir() {
+                    return Err(format!("'{}' is a directory", path.display()));
+                }
+
+                count_newlines(&path).map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn count_bytes(path: String) -> Result<u64, String> {
+        match get_path(&path) {
+            Ok(path) => match fs::metadata(&path) {
+                Ok(metadata) if metadata.is_dir() => {
+                    Err(format!("'{}' is a directory", path.display()))
+                }
+                Ok(metadata) => Ok(metadata.len()),
+                Err(e) => Err(format!(
+                    "Failed to get metadata for '{}': {}",
+                    path.display(),
+                    e
+                )),
+            },
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn summarise_wit_file(path: String) -> Result<String, String> {
+        match get_path(&path) {
+            Ok(path) => match fs::read_to_string(&path) {
+                Ok(source) => Ok(WitSummary::parse(&source).render(&path)),
+                Err(e) => Err(format!("Failed to read file '{}': {}", path.display(), e)),
+            },
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+fn read_file_info(path: &Path) -> Result<FileInfo> {
+    let metadata = fs::symlink_metadata(path)
+        .map_err(|e| anyhow!("Failed to get metadata for '{}': {}", path.display(), e))?;
+
+    let file_type = if metadata.is_dir() {
+        "Directory"
+    } else if metadata.is_file() {
+        "File"
+    } else if metadata.is_symlink() {
+        "Symlink"
+    } else {
+        "Unknown"
+    };
+
+    let modified_secs = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs());
+
+    #[cfg(unix)]
+    let mode_octal = {
+        use std::os::unix::fs::PermissionsExt;
+        Some(format!("{:o}", metadata.permissions().mode() & 0o7777))
+    };
+    #[cfg(not(unix))]
+    let mode_octal = None;
+
+    Ok(FileInfo {
+        path: path.display().to_string(),
+        file_type: file_type.to_string(),
+        size_bytes: metadata.len(),
+        readonly: metadata.permissions().readonly(),
+        modified_secs,
+        modified: metadata.modified().ok().and_then(format_timestamp),
+        accessed: metadata.accessed().ok().and_then(format_timestamp),
+        created: metadata.created().ok().and_then(format_timestamp),
+        mode_octal,
+        wasm_module_version: metadata
+            .is_file()
+            .then(|| wasm_header_version(path))
+            .flatten(),
+    })
+}
+
+/// The version field of a WebAssembly binary header, read from the first 8 bytes. `None` if
+/// the file doesn't start with the `\0asm` magic.
+fn wasm_header_version(path: &Path) -> Option<u32> {
+    let mut header = [0u8; 8];
+    fs::File::open(path).ok()?.read_exact(&mut header).ok()?;
+    let (magic, version) = header.split_at(4);
+    (magic == b"\0asm").then(|| u32::from_le_bytes(version.try_into().unwrap()))
+}
+
+/// Format a timestamp as ISO-8601 (`YYYY-MM-DDTHH:MM:SSZ`) in UTC.
+///
+/// Returns `None` for times before the Unix epoch.
+fn format_timestamp(time: SystemTime) -> Option<String> {
+    let secs = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    let days = (secs / 86_400) as i64;
+    let secs_of_day = secs % 86_400;
+
+    // Convert days since the epoch to a civil date (Howard Hinnant's `civil_from_days`)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    ))
+}
+
+fn read_dir_entries(dir: &Path, include_hidden: bool) -> Result<Vec<DirEntry>> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory '{}': {}", dir.display(), e))?;
+
+    let mut result = Vec::new();
+    for entry_result in entries {
+        let entry = entry_result
+            .map_err(|e| anyhow!("Failed to read entry in '{}': {}", dir.display(), e))?;
+        if !include_hidden && is_hidden(&entry) {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        result.push(to_dir_entry(&entry, name));
+    }
+    Ok(result)
+}
+
+/// Whether an entry is conventionally hidden: its name starts with `.`, or on Windows it has
+/// the hidden attribute
+fn is_hidden(entry: &fs::DirEntry) -> bool {
+    if entry.file_name().to_string_lossy().starts_with('.') {
+        return true;
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+
+        use windows_sys::Win32::Storage::FileSystem::FILE_ATTRIBUTE_HIDDEN;
+
+        if let Ok(metadata) = entry.metadata() {
+            return metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0;
+        }
+    }
+    false
+}
+
+/// Collect entries under `dir` into a flat list, using the same depth limiting as `build_tree`.
+fn walk_dir_entries(
+    dir: &Path,
+    relative_prefix: &str,
+    current_depth: u32,
+    max_depth: u32,
+    visited: &mut VisitedDirs,
+    output: &mut Vec<DirEntry>,
+) -> Result<()> {
+    if current_depth > max_depth {
+        return Ok(());
+    }
+
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory '{}': {}", dir.display(), e))?
+        .filter_map(|e| e.ok())
+        .collect();
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        let relative_path = format!("{}{}", relative_prefix, entry.file_name().to_string_lossy());
+        let dir_entry = to_dir_entry(&entry, relative_path.clone());
+        let is_dir = dir_entry.entry_type == EntryType::Dir;
+        output.push(dir_entry);
+
+        if is_dir && visited.insert(&entry.path()) {
+            walk_dir_entries(
+                &entry.path(),
+                &format!("{}/", relative_path),
+                current_depth + 1,
+                max_depth,
+                visited,
+                output,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Tracks directories already walked so bind mounts or hard-linked directories can't cause loops.
+/// Symlinks are never followed, so on platforms without inode numbers this is a no-op.
+#[derive(Default)]
+struct VisitedDirs {
+    #[cfg(unix)]
+    seen: std::collections::HashSet<(u64, u64)>,
+}
+
+impl VisitedDirs {
+    /// Returns `false` if the directory has been visited before.
+    fn insert(&mut self, _dir: &Path) -> bool {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            if let Ok(metadata) = fs::metadata(_dir) {
+                return self.seen.insert((metadata.dev(), metadata.ino()));
+            }
+        }
+        true
+    }
+}
+
+fn to_dir_entry(entry: &fs::DirEntry, relative_path: String) -> DirEntry {
+    // `DirEntry::metadata` does not follow symlinks
+    let metadata = entry.metadata().ok();
+    let entry_type = match &metadata {
+        Some(m) if m.is_symlink() => EntryType::Symlink,
+        Some(m) if m.is_dir() => EntryType::Dir,
+        Some(m) if m.is_file() => EntryType::File,
+        _ => EntryType::Unknown,
+    };
+    let size_bytes = match (&metadata, entry_type) {
+        (Some(m), EntryType::File) => m.len(),
+        _ => 0,
+    };
+
+    DirEntry {
+        name: entry.file_name().to_string_lossy().to_string(),
+        relative_path,
+        entry_type,
+        size_bytes,
+    }
+}
+
+impl SortOrder {
+    /// Parse a sort order such as "size" or "-modified"; a leading '-' means descending.
+    /// An empty string sorts by name.
+    fn parse(sort_by: &str) -> Result<(Self, bool)> {
+        let (key, descending) = match sort_by.strip_prefix('-') {
+            Some(key) => (key, true),
+            None => (sort_by, false),
+        };
+        let order = match key.to_lowercase().as_str() {
+            "" | "name" => Self::Name,
+            "size" => Self::Size,
+            "modified" => Self::Modified,
+            "extension" => Self::Extension,
+            other => {
+                return Err(anyhow!(
+                    "Unsupported sort order '{}', expected 'name', 'size', 'modified' or 'extension', optionally prefixed with '-' for descending",
+                    other
+                ))
+            }
+        };
+        Ok((order, descending))
+    }
+}
+
+/// Sort entries by `sort_by`, breaking ties by name. Metadata is only read for the
+/// size and modification time orders.
+fn sort_tree_entries(entries: &mut [fs::DirEntry], sort_by: SortOrder, descending: bool) {
+    fn sort_by_key<K: Ord>(
+        entries: &mut [fs::DirEntry],
+        descending: bool,
+        key: impl Fn(&fs::DirEntry) -> K,
+    ) {
+        if descending {
+            entries.sort_by_cached_key(|e| Reverse(key(e)));
+        } else {
+            entries.sort_by_cached_key(key);
+        }
+    }
+
+    entries.sort_by_key(|e| e.file_name());
+    match sort_by {
+        SortOrder::Name => {
+            if descending {
+                entries.reverse();
+            }
+        }
+        // Directories and symlinks count as empty, matching `DirEntry::size_bytes`
+        SortOrder::Size => sort_by_key(entries, descending, |e| {
+            e.metadata()
+                .map(|m| if m.is_file() { m.len() } else { 0 })
+                .ok()
+        }),
+        SortOrder::Modified => sort_by_key(entries, descending, |e| {
+            e.metadata().and_then(|m| m.modified()).ok()
+        }),
+        SortOrder::Extension => sort_by_key(entries, descending, |e| {
+            Path::new(&e.file_name())
+                .extension()
+                .map(|ext| ext.to_string_lossy().to_lowercase())
+        }),
+    }
+}
+
+/// How `build_tree` orders and annotates entries
+#[derive(Clone, Copy)]
+struct TreeOptions {
+    sort_by: SortOrder,
+    descending: bool,
+    /// Append file sizes and the number of items in each directory
+    show_sizes: bool,
+}
+
+fn build_tree(
+    dir: &Path,
+    output: &mut String,
+    current_depth: u32,
+    max_depth: u32,
+    prefix: &str,
+    options: TreeOptions,
+) -> Result<()> {
+    if current_depth > max_depth {
+        return Ok(());
+    }
+
+    let entries = match fs::read_dir(dir) {
+        Ok(e) => e,
+        Err(e) => {
+            return Err(anyhow!(
+                "Failed to read directory '{}': {}",
+                dir.display(),
+                e
+            ))
+        }
+    };
+
+    let mut entries: Vec<_> = entries.filter_map(|e| e.ok()).collect();
+    sort_tree_entries(&mut entries, options.sort_by, options.descending);
+
+    let count = entries.len();
+    for (idx, entry) in entries.into_iter().enumerate() {
+        let is_last = idx == count - 1;
+        let connector = if is_last { "└── " } else { "├── " };
+        let extension = if is_last { "    " } else { "│   " };
+
+        let file_name = entry.file_name();
+        let file_type = match entry.file_type() {
+            Ok(ft) if ft.is_dir() => "[DIR] ",
+            Ok(_) => "",
+            Err(_) => "[?] ",
+        };
+
+        let annotation = if options.show_sizes {
+            tree_size_annotation(&entry)
+        } else {
+            String::new()
+        };
+
+        output.push_str(&format!(
+            "{}{}{}{}{}\n",
+            prefix,
+            connector,
+            file_type,
+            file_name.to_string_lossy(),
+            annotation
+        ));
+
+        if entry.path().is_dir() {
+            let new_prefix = format!("{}{}", prefix, extension);
+            build_tree(
+                &entry.path(),
+                output,
+                current_depth + 1,
+                max_depth,
+                &new_prefix,
+                options,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The JSON counterpart of `build_tree`: one object per entry of `dir`, sorted by name, with
+/// subdirectories expanded into `children` down to `max_depth`.
+fn build_tree_json(dir: &Path, current_depth: u32, max_depth: u32) -> Result<Vec<Value>> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory '{}': {}", dir.display(), e))?;
+    let mut entries: Vec<_> = entries.filter_map(|e| e.ok()).collect();
+    sort_tree_entries(&mut entries, SortOrder::Name, false);
+
+    let mut nodes = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let mut node = serde_json::Map::new();
+        node.insert(
+            "name".to_string(),
+            entry.file_name().to_string_lossy().into(),
+        );
+        // `DirEntry::file_type` does not follow symlinks
+        match entry.file_type() {
+            Ok(ft) if ft.is_dir() => {
+                node.insert("type".to_string(), "dir".into());
+                if current_depth < max_depth {
+                    let children = build_tree_json(&entry.path(), current_depth + 1, max_depth)?;
+                    node.insert("children".to_string(), children.into());
+                }
+            }
+            Ok(ft) if ft.is_file() => {
+                node.insert("type".to_string(), "file".into());
+                let size = entry.metadata().map(|m| m.len()).unwrap_or_default();
+                node.insert("size".to_string(), size.into());
+            }
+            Ok(ft) if ft.is_symlink() => {
+                node.insert("type".to_string(), "symlink".into());
+            }
+            _ => {
+                node.insert("type".to_string(), "unknown".into());
+            }
+        }
+        nodes.push(Value::Object(node));
+    }
+    Ok(nodes)
+}
+
+/// `/ (3 items)` for a directory or ` (42.00 KB)` for a file. Symlinks and entries whose
+/// metadata can't be read get no annotation.
+fn tree_size_annotation(entry: &fs::DirEntry) -> String {
+    match entry.file_type() {
+        Ok(ft) if ft.is_dir() => match fs::read_dir(entry.path()) {
+            Ok(children) => {
+                let count = children.count();
+                format!("/ ({} item{})", count, if count == 1 { "" } else { "s" })
+            }
+            Err(_) => "/".to_string(),
+        },
+        Ok(ft) if ft.is_file() => entry
+            .metadata()
+            .map(|m| format!(" ({})", format_size(m.len())))
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+struct TreeNode {
+    name: String,
+    children: Option<Vec<TreeNode>>,
+}
+
+/// Collect the files under `dir` whose names match `matcher`, keeping only the directories
+/// that lead to a match. Depth limiting follows `build_tree`.
+fn filtered_tree(
+    dir: &Path,
+    current_depth: u32,
+    max_depth: u32,
+    matcher: &PatternMatcher,
+) -> Result<Vec<TreeNode>> {
+    if current_depth > max_depth {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory '{}': {}", dir.display(), e))?;
+    let mut entries: Vec<_> = entries.filter_map(|e| e.ok()).collect();
+    sort_tree_entries(&mut entries, SortOrder::Name, false);
+
+    let mut nodes = Vec::new();
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if entry.path().is_dir() {
+            let children = filtered_tree(&entry.path(), current_depth + 1, max_depth, matcher)?;
+            if !children.is_empty() {
+                nodes.push(TreeNode {
+                    name,
+                    children: Some(children),
+                });
+            }
+        } else if matcher.is_match(&name) {
+            nodes.push(TreeNode {
+                name,
+                children: None,
+            });
+        }
+    }
+    Ok(nodes)
+}
+
+fn render_tree(nodes: &[TreeNode], output: &mut String, prefix: &str) {
+    for (idx, node) in nodes.iter().enumerate() {
+        let is_last = idx == nodes.len() - 1;
+        let connector = if is_last { "└── " } else { "├── " };
+        let extension = if is_last { "    " } else { "│   " };
+
+        match &node.children {
+            Some(children) => {
+                output.push_str(&format!("{}{}[DIR] {}\n", prefix, connector, node.name));
+                render_tree(children, output, &format!("{}{}", prefix, extension));
+            }
+            None => output.push_str(&format!("{}{}{}\n", prefix, connector, node.name)),
+        }
+    }
+}
+
+#[derive(Default)]
+struct CopyStats {
+    files: u64,
+    directories: u64,
+    skipped_symlinks: u64,
+}
+
+/// Copy the tree at `source` to the new directory `destination`. Symlinks inside it are
+/// recreated pointing at the same target when `copy_symlinks` is set, and skipped otherwise.
+fn copy_dir_recursive(
+    source: &Path,
+    destination: &Path,
+    copy_symlinks: bool,
+    stats: &mut CopyStats,
+) -> Result<()> {
+    fs::create_dir(destination).map_err(|e| {
+        anyhow!(
+            "Failed to create directory '{}': {}",
+            destination.display(),
+            e
+        )
+    })?;
+    stats.directories += 1;
+
+    let entries = fs::read_dir(source)
+        .map_err(|e| anyhow!("Failed to read directory '{}': {}", source.display(), e))?;
+
+    for entry_result in entries {
+        let entry = entry_result
+            .map_err(|e| anyhow!("Failed to read entry in '{}': {}", source.display(), e))?;
+        let entry_path = entry.path();
+        let target = destination.join(entry.file_name());
+
+        // `DirEntry::file_type` does not follow symlinks
+        let file_type = entry
+            .file_type()
+            .map_err(|e| anyhow!("Failed to read type of '{}': {}", entry_path.display(), e))?;
+
+        if file_type.is_symlink() && copy_symlinks {
+            let link_target = fs::read_link(&entry_path)
+                .map_err(|e| anyhow!("Failed to read symlink '{}': {}", entry_path.display(), e))?;
+            create_symlink_impl(&link_target, &target)
+                .map_err(|e| anyhow!("Failed to create symlink '{}': {}", target.display(), e))?;
+        } else if file_type.is_symlink() {
+            stats.skipped_symlinks += 1;
+        } else if file_type.is_dir() {
+            copy_dir_recursive(&entry_path, &target, copy_symlinks, stats)?;
+        } else {
+            fs::copy(&entry_path, &target).map_err(|e| {
+                anyhow!(
+                    "Failed to copy '{}' to '{}': {}",
+                    entry_path.display(),
+                    target.display(),
+                    e
+                )
+            })?;
+            stats.files += 1;
+        }
+    }
+
+    Ok(())
+}
+
+fn zip_dir(source: &Path, zip_path: &Path) -> Result<CopyStats> {
+    let file = fs::File::create(zip_path)
+        .map_err(|e| anyhow!("Failed to create '{}': {}", zip_path.display(), e))?;
+    let mut writer = ZipWriter::new(file);
+    let mut stats = CopyStats::default();
+
+    add_dir_to_zip(&mut writer, source, "", zip_path, &mut stats)?;
+    writer
+        .finish()
+        .map_err(|e| anyhow!("Failed to write '{}': {}", zip_path.display(), e))?;
+    Ok(stats)
+}
+
+fn add_dir_to_zip(
+    writer: &mut ZipWriter<fs::File>,
+    dir: &Path,
+    prefix: &str,
+    zip_path: &Path,
+    stats: &mut CopyStats,
+) -> Result<()> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory '{}': {}", dir.display(), e))?
+        .collect::<std::io::Result<_>>()
+        .map_err(|e| anyhow!("Failed to read entry in '{}': {}", dir.display(), e))?;
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        let entry_path = entry.path();
+        // Don't add the archive to itself when it is written inside the source
+        if entry_path == zip_path {
+            continue;
+        }
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+
+        // `DirEntry::file_type` does not follow symlinks
+        let file_type = entry
+            .file_type()
+            .map_err(|e| anyhow!("Failed to read type of '{}': {}", entry_path.display(), e))?;
+
+        if file_type.is_symlink() {
+            stats.skipped_symlinks += 1;
+        } else if file_type.is_dir() {
+            writer
+                .add_directory(name.as_str(), options)
+                .map_err(|e| anyhow!("Failed to add '{}' to archive: {}", name, e))?;
+            stats.directories += 1;
+            add_dir_to_zip(writer, &entry_path, &format!("{}/", name), zip_path, stats)?;
+        } else {
+            let mut file = fs::File::open(&entry_path)
+                .map_err(|e| anyhow!("Failed to open file '{}': {}", entry_path.display(), e))?;
+            writer
+                .start_file(name.as_str(), options)
+                .map_err(|e| anyhow!("Failed to add '{}' to archive: {}", name, e))?;
+            std::io::copy(&mut file, writer)
+                .map_err(|e| anyhow!("Failed to add '{}' to archive: {}", name, e))?;
+            stats.files += 1;
+        }
+    }
+
+    Ok(())
+}
+
+fn unzip_into(zip_path: &Path, destination: &Path) -> Result<CopyStats> {
+    let file = fs::File::open(zip_path)
+        .map_err(|e| anyhow!("Failed to open '{}': {}", zip_path.display(), e))?;
+    let mut archive = ZipArchive::new(file)
+        .map_err(|e| anyhow!("'{}' is not a valid zip archive: {}", zip_path.display(), e))?;
+
+    // Check every entry before writing anything, so a zip-slip archive leaves no partial output.
+    // `enclosed_name` rejects absolute paths and paths that climb out with `..`.
+    let mut targets = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let entry = archive
+            .by_index(index)
+            .map_err(|e| anyhow!("Failed to read '{}': {}", zip_path.display(), e))?;
+        let relative = entry.enclosed_name().ok_or_else(|| {
+            anyhow!(
+                "Refusing to extract '{}': entry '{}' would be written outside the destination",
+                zip_path.display(),
+                entry.name()
+            )
+        })?;
+        targets.push(destination.join(relative));
+    }
+
+    fs::create_dir_all(destination).map_err(|e| {
+        anyhow!(
+            "Failed to create directory '{}': {}",
+            destination.display(),
+            e
+        )
+    })?;
+
+    let mut stats = CopyStats::default();
+    for (index, target) in targets.into_iter().enumerate() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|e| anyhow!("Failed to read '{}': {}", zip_path.display(), e))?;
+
+        if entry.is_symlink() {
+            stats.skipped_symlinks += 1;
+        } else if entry.is_dir() {
+            fs::create_dir_all(&target)
+                .map_err(|e| anyhow!("Failed to create directory '{}': {}", target.display(), e))?;
+            stats.directories += 1;
+        } else {
+            ensure_parent_dir(&target)?;
+            let mut output = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&target)
+                .map_err(|e| anyhow!("Failed to create file '{}': {}", target.display(), e))?;
+            std::io::copy(&mut entry, &mut output)
+                .map_err(|e| anyhow!("Failed to extract '{}': {}", target.display(), e))?;
+            stats.files += 1;
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Move `source` to `destination` on a different filesystem by copying it and
+/// then removing the original. The original is left untouched if the copy fails.
+fn move_across_devices(source: &Path, destination: &Path) -> Result<()> {
+    let metadata = fs::symlink_metadata(source)?;
+
+    if metadata.file_type().is_symlink() {
+        let target = fs::read_link(source)?;
+        create_symlink_impl(&target, destination)?;
+        fs::remove_file(source)?;
+    } else if metadata.is_dir() {
+        if destination.exists() {
+            return Err(anyhow!("'{}' already exists", destination.display()));
+        }
+
+        if let Err(e) = copy_dir_recursive(source, destination, true, &mut CopyStats::default()) {
+            let _ = fs::remove_dir_all(destination);
+            return Err(e);
+        }
+        fs::remove_dir_all(source)?;
+    } else {
+        fs::copy(source, destination)?;
+        fs::remove_file(source)?;
+    }
+    Ok(())
+}
+
+// The statvfs fields are u64 on 64-bit Linux but u32 on macOS and some 32-bit targets
+#[cfg(unix)]
+#[allow(clippy::useless_conversion)]
+fn disk_usage(path: &Path) -> Result<DiskUsage> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: `c_path` is a valid NUL-terminated string and `stat` is a valid out pointer
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+
+    let block_size = u64::from(stat.f_frsize);
+    let total_bytes = u64::from(stat.f_blocks) * block_size;
+    let free_bytes = u64::from(stat.f_bfree) * block_size;
+    Ok(DiskUsage {
+        total_bytes,
+        available_bytes: u64::from(stat.f_bavail) * block_size,
+        used_bytes: total_bytes.saturating_sub(free_bytes),
+    })
+}
+
+#[cfg(windows)]
+fn disk_usage(path: &Path) -> Result<DiskUsage> {
+    use std::os::windows::ffi::OsStrExt;
+
+    use windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let wide_path: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let (mut available_bytes, mut total_bytes, mut free_bytes) = (0u64, 0u64, 0u64);
+    // SAFETY: `wide_path` is NUL-terminated and all out pointers are valid
+    let ok = unsafe {
+        GetDiskFreeSpaceExW(
+            wide_path.as_ptr(),
+            &mut available_bytes,
+            &mut total_bytes,
+            &mut free_bytes,
+        )
+    };
+    if ok == 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+
+    Ok(DiskUsage {
+        total_bytes,
+        available_bytes,
+        used_bytes: total_bytes.saturating_sub(free_bytes),
+    })
+}
+
+/// WASI doesn't expose filesystem statistics to components.
+#[cfg(not(any(unix, windows)))]
+fn disk_usage(_path: &Path) -> Result<DiskUsage> {
+    Err(anyhow!("disk usage is not available on this platform"))
+}
+
+#[derive(Clone, Copy)]
+enum HashAlgorithm {
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    fn parse(algorithm: &str) -> Result<Self> {
+        match algorithm.to_lowercase().as_str() {
+            "sha256" => Ok(Self::Sha256),
+            "blake3" => Ok(Self::Blake3),
+            other => Err(anyhow!(
+                "Unsupported hash algorithm '{}', expected 'sha256' or 'blake3'",
+                other
+            )),
+        }
+    }
+}
+
+/// A token that changes whenever the file at `path` is created, deleted, or written to
+fn change_token(path: &Path) -> Result<String> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok("missing".to_string()),
+        Err(e) => {
+            return Err(anyhow!(
+                "Failed to read metadata for '{}': {}",
+                path.display(),
+                e
+            ))
+        }
+    };
+    if metadata.is_dir() {
+        return Err(anyhow!("'{}' is a directory, not a file", path.display()));
+    }
+
+    // The hash catches writes that keep the size and land within the mtime resolution
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|since_epoch| since_epoch.as_nanos())
+        .unwrap_or_default();
+    Ok(format!(
+        "{}-{}-{}",
+        metadata.len(),
+        modified,
+        hash_file(path, HashAlgorithm::Blake3)?
+    ))
+}
+
+/// Count the top-level sections of a WebAssembly binary, not those of nested modules or
+/// components.
+fn count_wasm_sections(bytes: &[u8]) -> Result<u32> {
+    use wasmparser::Payload;
+
+    let mut depth = 0;
+    let mut sections = 0;
+    for payload in wasmparser::Parser::new(0).parse_all(bytes) {
+        match payload? {
+            Payload::Version { .. } => depth += 1,
+            Payload::End(_) => depth -= 1,
+            // Function bodies follow their section start
+            Payload::CodeSectionEntry(_) => {}
+            _ if depth == 1 => sections += 1,
+            _ => {}
+        }
+    }
+    Ok(sections)
+}
+
+/// The top-level exports of the WebAssembly binary at `path`
+fn wasm_exports(path: &Path) -> Result<Vec<ExportEntry>> {
+    use wasmparser::{ExternalKind, Payload};
+
+    let mut exports = Vec::new();
+    scan_wasm_sections(path, |payload| {
+        match payload {
+            Payload::ExportSection(reader) => {
+                for export in reader.clone() {
+                    let export = export?;
+                    let kind = match export.kind {
+                        ExternalKind::Func => "func",
+                        ExternalKind::Table => "table",
+                        ExternalKind::Memory => "memory",
+                        ExternalKind::Global => "global",
+                        ExternalKind::Tag => "tag",
+                    };
+                    exports.push(ExportEntry {
+                        name: export.name.to_string(),
+                        kind: kind.to_string(),
+                    });
+                }
+            }
+            Payload::ComponentExportSection(reader) => {
+                for export in reader.clone() {
+                    let export = export?;
+                    exports.push(ExportEntry {
+                        name: export.name.0.to_string(),
+                        kind: export.kind.desc().to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(exports)
+}
+
+/// The top-level imports of the WebAssembly binary at `path`
+fn wasm_imports(path: &Path) -> Result<Vec<ImportEntry>> {
+    use wasmparser::{Payload, TypeRef};
+
+    let mut imports = Vec::new();
+    scan_wasm_sections(path, |payload| {
+        match payload {
+            Payload::ImportSection(reader) => {
+                for import in reader.clone() {
+                    let import = import?;
+                    let kind = match import.ty {
+                        TypeRef::Func(_) => "func",
+                        TypeRef::Table(_) => "table",
+                        TypeRef::Memory(_) => "memory",
+                        TypeRef::Global(_) => "global",
+                        TypeRef::Tag(_) => "tag",
+                    };
+                    imports.push(ImportEntry {
+                        module: import.module.to_string(),
+                        name: import.name.to_string(),
+                        kind: kind.to_string(),
+                    });
+                }
+            }
+            Payload::ComponentImportSection(reader) => {
+                for import in reader.clone() {
+                    let import = import?;
+                    imports.push(ImportEntry {
+                        module: String::new(),
+                        name: import.name.0.to_string(),
+                        kind: import.ty.kind().desc().to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(imports)
+}
+
+/// Call `visit` with each top-level payload of the WebAssembly binary at `path`, reading the
+/// file as the parser asks for more. Function bodies and nested modules and components are
+/// skipped over rather than parsed.
+fn scan_wasm_sections(
+    path: &Path,
+    mut visit: impl FnMut(&wasmparser::Payload) -> Result<()>,
+) -> Result<()> {
+    use wasmparser::{Chunk, Payload};
+
+    if wasm_header_version(path).is_none() {
+        return Err(anyhow!(
+            "not a WebAssembly binary, it doesn't start with \\0asm"
+        ));
+    }
+    let mut file = fs::File::open(path)?;
+    let mut parser = wasmparser::Parser::new(0);
+    let mut buffer = Vec::new();
+    let mut eof = false;
+    let mut skip = 0u64;
+    loop {
+        if skip > 0 {
+            let skipped = skip.min(buffer.len() as u64);
+            buffer.drain(..skipped as usize);
+            skip -= skipped;
+            if skip > 0 {
+                file.seek(SeekFrom::Current(skip as i64))?;
+                skip = 0;
+            }
+        }
+
+        let (payload, consumed) = match parser.parse(&buffer, eof)? {
+            Chunk::NeedMoreData(hint) => {
+                let start = buffer.len();
+                buffer.resize(start + hint.clamp(1, 64 * 1024) as usize, 0);
+                let read = file.read(&mut buffer[start..])?;
+                buffer.truncate(start + read);
+                eof = read == 0;
+                continue;
+            }
+            Chunk::Parsed { consumed, payload } => (payload, consumed),
+        };
+
+        match &payload {
+            Payload::CodeSectionStart { size, .. } => {
+                parser.skip_section();
+                skip = *size as u64;
+            }
+            // The parser has already moved past these; their bytes just need dropping
+            Payload::ModuleSection {
+                unchecked_range, ..
+            }
+            | Payload::ComponentSection {
+                unchecked_range, ..
+            } => skip = unchecked_range.len() as u64,
+            _ => {}
+        }
+        let done = matches!(payload, Payload::End(_));
+        visit(&payload)?;
+        buffer.drain(..consumed);
+        if done {
+            return Ok(());
+        }
+    }
+}
+
+/// A `<file>.lock` file held while a file is rotated, removed again on drop
+struct RotationLock {
+    path: PathBuf,
+}
+
+impl RotationLock {
+    fn acquire(file: &Path) -> Result<Self> {
+        let path = suffixed_path(file, "lock");
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::AlreadyExists => anyhow!(
+                    "'{}' is already being rotated, remove '{}' if that rotation was interrupted",
+                    file.display(),
+                    path.display()
+                ),
+                _ => anyhow!("Failed to create lock file '{}': {}", path.display(), e),
+            })?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for RotationLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// `path` with `.suffix` appended to its file name, e.g. `app.log.1`
+fn suffixed_path(path: &Path, suffix: impl std::fmt::Display) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", suffix));
+    PathBuf::from(name)
+}
+
+/// Rotate `path` if it is larger than `max_size`, returning how many files were renamed, or
+/// `None` if the file was small enough. The size is checked again once the lock is held, since
+/// another writer may have rotated the file in the meantime.
+fn rotate(path: &Path, max_size: u64, max_backups: u32) -> Result<Option<u32>> {
+    let size = |path: &Path| {
+        fs::metadata(path)
+            .map(|metadata| metadata.len())
+            .map_err(|e| anyhow!("Failed to read metadata for '{}': {}", path.display(), e))
+    };
+    if size(path)? <= max_size {
+        return Ok(None);
+    }
+    let _lock = RotationLock::acquire(path)?;
+    if size(path)? <= max_size {
+        return Ok(None);
+    }
+
+    if max_backups == 0 {
+        fs::File::create(path)
+            .map_err(|e| anyhow!("Failed to truncate file '{}': {}", path.display(), e))?;
+        return Ok(Some(0));
+    }
+
+    let oldest = suffixed_path(path, max_backups);
+    if oldest.exists() {
+        fs::remove_file(&oldest)
+            .map_err(|e| anyhow!("Failed to remove '{}': {}", oldest.display(), e))?;
+    }
+
+    let rename = |from: &Path, to: &Path| {
+        fs::rename(from, to).map_err(|e| {
+            anyhow!(
+                "Failed to rename '{}' to '{}': {}",
+                from.display(),
+                to.display(),
+                e
+            )
+        })
+    };
+    let mut renamed = 0;
+    for n in (1..max_backups).rev() {
+        let backup = suffixed_path(path, n);
+        if backup.exists() {
+            rename(&backup, &suffixed_path(path, n + 1))?;
+            renamed += 1;
+        }
+    }
+    rename(path, &suffixed_path(path, 1))?;
+    renamed += 1;
+
+    fs::File::create(path)
+        .map_err(|e| anyhow!("Failed to create file '{}': {}", path.display(), e))?;
+    Ok(Some(renamed))
+}
+
+/// Compare two files block by block, stopping at the first difference
+fn files_identical(path_a: &Path, path_b: &Path) -> Result<bool> {
+    let canonical = |path: &Path| {
+        fs::canonicalize(path)
+            .map_err(|e| anyhow!("Failed to open file '{}': {}", path.display(), e))
+    };
+    if canonical(path_a)? == canonical(path_b)? {
+        return Ok(true);
+    }
+
+    let open = |path: &Path| {
+        let file = fs::File::open(path)
+            .map_err(|e| anyhow!("Failed to open file '{}': {}", path.display(), e))?;
+        let size = file
+            .metadata()
+            .map_err(|e| anyhow!("Failed to read metadata for '{}': {}", path.display(), e))?
+            .len();
+        Ok::<_, anyhow::Error>((file, size))
+    };
+    let (mut file_a, size_a) = open(path_a)?;
+    let (mut file_b, size_b) = open(path_b)?;
+    if size_a != size_b {
+        return Ok(false);
+    }
+
+    let mut buffer_a = vec![0u8; 64 * 1024];
+    let mut buffer_b = vec![0u8; 64 * 1024];
+    loop {
+        let read_a = read_block(&mut file_a, &mut buffer_a)
+            .map_err(|e| anyhow!("Failed to read file '{}': {}", path_a.display(), e))?;
+        let read_b = read_block(&mut file_b, &mut buffer_b)
+            .map_err(|e| anyhow!("Failed to read file '{}': {}", path_b.display(), e))?;
+        if buffer_a[..read_a] != buffer_b[..read_b] {
+            return Ok(false);
+        }
+        if read_a == 0 {
+            return Ok(true);
+        }
+    }
+}
+
+/// Fill `buffer` from `reader`, returning fewer bytes only at end of file
+fn read_block(reader: &mut impl Read, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..])? {
+            0 => break,
+            read => filled += read,
+        }
+    }
+    Ok(filled)
+}
+
+/// Hash a file's contents with a fixed-size buffer so large files are never fully loaded.
+fn hash_file(path: &Path, algorithm: HashAlgorithm) -> Result<String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| anyhow!("Failed to open file '{}': {}", path.display(), e))?;
+    let mut buffer = vec![0u8; 64 * 1024];
+
+    let mut sha256 = Sha256::new();
+    let mut blake3 = blake3::Hasher::new();
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| anyhow!("Failed to read file '{}': {}", path.display(), e))?;
+        if read == 0 {
+            break;
+        }
+        match algorithm {
+            HashAlgorithm::Sha256 => sha256.update(&buffer[..read]),
+            HashAlgorithm::Blake3 => {
+                blake3.update(&buffer[..read]);
+            }
+        }
+    }
+
+    Ok(match algorithm {
+        HashAlgorithm::Sha256 => format!("{:x}", sha256.finalize()),
+        HashAlgorithm::Blake3 => blake3.finalize().to_hex().to_string(),
+    })
+}
+
+/// Files grouped by content hash. Files are hashed one at a time, so only paths are kept.
+#[derive(Default)]
+struct DuplicateScan {
+    /// hash -> (file size, paths with that content)
+    by_hash: HashMap<String, (u64, Vec<PathBuf>)>,
+    files_scanned: u64,
+    skipped: Vec<String>,
+}
+
+impl DuplicateScan {
+    fn hash_tree(
+        &mut self,
+        dir: &Path,
+        algorithm: HashAlgorithm,
+        visited: &mut VisitedDirs,
+    ) -> Result<()> {
+        if !visited.insert(dir) {
+            return Ok(());
+        }
+        let entries = fs::read_dir(dir)
+            .map_err(|e| anyhow!("Failed to read directory '{}': {}", dir.display(), e))?;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            // `DirEntry::file_type` does not follow symlinks
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+
+            if file_type.is_dir() {
+                if let Err(e) = self.hash_tree(&path, algorithm, visited) {
+                    self.skipped.push(e.to_string());
+                }
+            } else if file_type.is_file() {
+                let hashed = entry
+                    .metadata()
+                    .map_err(|e| anyhow!("Failed to read metadata of '{}': {}", path.display(), e))
+                    .and_then(|m| Ok((m.len(), hash_file(&path, algorithm)?)));
+                match hashed {
+                    Ok((size, hash)) => {
+                        self.files_scanned += 1;
+                        self.by_hash
+                            .entry(hash)
+                            .or_insert_with(|| (size, Vec::new()))
+                            .1
+                            .push(path);
+                    }
+                    Err(e) => self.skipped.push(e.to_string()),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn report(self, root: &Path) -> String {
+        let mut sets: Vec<(String, u64, Vec<PathBuf>)> = self
+            .by_hash
+            .into_iter()
+            .filter(|(_, (_, paths))| paths.len() > 1)
+            .map(|(hash, (size, mut paths))| {
+                paths.sort();
+                (hash, size, paths)
+            })
+            .collect();
+        // Biggest savings first
+        sets.sort_by(|a, b| {
+            let wasted =
+                |(_, size, paths): &(String, u64, Vec<PathBuf>)| size * (paths.len() as u64 - 1);
+            wasted(b).cmp(&wasted(a)).then_with(|| a.2.cmp(&b.2))
+        });
+
+        let mut report = if sets.is_empty() {
+            format!(
+                "No duplicate files found in '{}' ({} files scanned)",
+                root.display(),
+                self.files_scanned
+            )
+        } else {
+            let total_wasted: u64 = sets
+                .iter()
+                .map(|(_, size, paths)| size * (paths.len() as u64 - 1))
+                .sum();
+            let mut report = format!(
+                "Found {} duplicate set(s) in '{}' ({} files scanned, {} wasted)",
+                sets.len(),
+                root.display(),
+                self.files_scanned,
+                format_size(total_wasted)
+            );
+            for (hash, size, paths) in &sets {
+                report.push_str(&format!(
+                    "\n\n{} copies of {} ({} wasted), hash {}:",
+                    paths.len(),
+                    format_size(*size),
+                    format_size(size * (paths.len() as u64 - 1)),
+                    hash
+                ));
+                for path in paths {
+                    report.push_str(&format!("\n  {}", path.display()));
+                }
+            }
+            report
+        };
+
+        if !self.skipped.is_empty() {
+            report.push_str(&format!(
+                "\n\nSkipped {} unreadable item(s):",
+                self.skipped.len()
+            ));
+            for note in &self.skipped {
+                report.push_str(&format!("\n  {}", note));
+            }
+        }
+        report
+    }
+}
+
+fn count_newlines(path: &Path) -> Result<u64> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| anyhow!("Failed to open file '{}': {}", path.display(), e))?;
+    let mut buffer = vec![0u8; 64 * 1024];
+
+    let mut lines = 0;
+    loop {
+        let read = file
+            .read(&mut buffer)
+            .map_err(|e| anyhow!("Failed to read file '{}': {}", path.display(), e))?;
+        if read == 0 {
+            break;
+        }
+        lines += buffer[..read].iter().filter(|&&b| b == b'\n').count() as u64;
+    }
+    Ok(lines)
+}
+
+/// Interfaces, worlds and their contents found by a lightweight scan of a WIT file.
+#[derive(Default)]
+struct WitSummary {
+    package: Option<String>,
+    sections: Vec<WitSection>,
+}
+
+struct WitSection {
+    kind: &'static str,
+    name: String,
+    imports: Vec<String>,
+    exports: Vec<String>,
+    types: Vec<String>,
+    functions: Vec<String>,
+}
+
+enum WitBlock {
+    Section,
+    Resource(String),
+    Other,
+}
+
+impl WitSummary {
+    /// Split the source into statements ending at ';', '{' or '}' and classify each one,
+    /// so signatures spanning several lines are handled without a full WIT grammar.
+    fn parse(source: &str) -> Self {
+        let mut summary = Self::default();
+        let mut blocks = Vec::new();
+        let mut statement = String::new();
+
+        for line in source.lines() {
+            let line = line.split("//").next().unwrap_or_default();
+            for c in line.chars().chain(std::iter::once(' ')) {
+                match c {
+                    ';' | '{' => {
+                        let text = strip_wit_annotations(statement.trim()).to_string();
+                        statement.clear();
+                        summary.statement(&text, c == '{', &mut blocks);
+                    }
+                    '}' => {
+                        statement.clear();
+                        blocks.pop();
+                    }
+                    _ => statement.push(c),
+                }
+            }
+        }
+        summary
+    }
+
+    fn statement(&mut self, text: &str, opens_block: bool, blocks: &mut Vec<WitBlock>) {
+        let (keyword, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+        let rest = rest.trim();
+        let in_section = matches!(blocks.last(), Some(WitBlock::Section));
+
+        let block = match keyword {
+            "package" if blocks.is_empty() => {
+                self.package = Some(rest.to_string());
+                WitBlock::Other
+            }
+            "world" | "interface" if blocks.is_empty() => {
+                self.sections.push(WitSection {
+                    kind: if keyword == "world" {
+                        "world"
+                    } else {
+                        "interface"
+                    },
+                    name: rest.to_string(),
+                    imports: Vec::new(),
+                    exports: Vec::new(),
+                    types: Vec::new(),
+                    functions: Vec::new(),
+                });
+                WitBlock::Section
+            }
+            "record" | "variant" | "enum" | "flags" | "resource" | "type" if in_section => {
+                let name = rest.split(['=', ' ']).next().unwrap_or_default();
+                self.current().types.push(format!("{} {}", keyword, name));
+                if keyword == "resource" {
+                    WitBlock::Resource(name.to_string())
+                } else {
+                    WitBlock::Other
+                }
+            }
+            "use" | "include" => WitBlock::Other,
+            "import" | "export" if in_section => {
+                let section = self.current();
+                match rest.split_once(':') {
+                    Some((name, signature)) if is_wit_function(signature) => {
+                        let function = describe_wit_function(name.trim(), signature);
+                        section.functions.push(format!("{} {}", keyword, function));
+                    }
+                    // An inline interface, e.g. `export handler: interface { ... }`
+                    Some((name, signature)) if signature.trim() == "interface" => {
+                        let name = name.trim().to_string();
+                        if keyword == "import" {
+                            section.imports.push(name);
+                        } else {
+                            section.exports.push(name);
+                        }
+                    }
+                    _ if keyword == "import" => section.imports.push(rest.to_string()),
+                    _ => section.exports.push(rest.to_string()),
+                }
+                WitBlock::Other
+            }
+            _ => {
+                match blocks.last() {
+                    Some(WitBlock::Section) => {
+                        if let Some((name, signature)) = text.split_once(':') {
+                            if is_wit_function(signature) {
+                                let function = describe_wit_function(name.trim(), signature);
+                                self.current().functions.push(function);
+                            }
+                        }
+                    }
+                    Some(WitBlock::Resource(resource)) => {
+                        let method = match text.split_once(':') {
+                            _ if text.starts_with("constructor") => Some(("constructor", text)),
+                            Some((name, signature)) if is_wit_function(signature) => {
+                                Some((name.trim(), signature))
+                            }
+                            _ => None,
+                        };
+                        if let Some((name, signature)) = method {
+                            let function =
+                                describe_wit_function(&format!("{}.{}", resource, name), signature);
+                            self.current().functions.push(function);
+                        }
+                    }
+                    _ => {}
+                }
+                WitBlock::Other
+            }
+        };
+
+        if opens_block {
+            blocks.push(block);
+        }
+    }
+
+    fn current(&mut self) -> &mut WitSection {
+        self.sections
+            .last_mut()
+            .expect("sections are only nested inside a world or interface")
+    }
+
+    fn render(&self, path: &Path) -> String {
+        let mut output = format!("WIT file '{}'\n", path.display());
+        if let Some(package) = &self.package {
+            output.push_str(&format!("package {}\n", package));
+        }
+        if self.sections.is_empty() {
+            output.push_str("No worlds or interfaces found\n");
+        }
+
+        for section in &self.sections {
+            output.push_str(&format!("\n{} {}\n", section.kind, section.name));
+            for (label, items) in [
+                ("imports", &section.imports),
+                ("exports", &section.exports),
+                ("types", &section.types),
+                ("functions", &section.functions),
+            ] {
+                if items.is_empty() {
+                    continue;
+                }
+                output.push_str(&format!("  {} ({}):\n", label, items.len()));
+                for item in items {
+                    output.push_str(&format!("    {}\n", item));
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Remove leading feature gates such as `@since(version = 0.2.0)` or `@unstable(...)`.
+fn strip_wit_annotations(mut text: &str) -> &str {
+    while let Some(annotated) = text.strip_prefix('@') {
+        text = match annotated.find(')') {
+            Some(end) => annotated[end + 1..].trim_start(),
+            None => return "",
+        };
+    }
+    text
+}
+
+fn is_wit_function(signature: &str) -> bool {
+    let signature = signature.trim_start();
+    let signature = signature
+        .strip_prefix("static")
+        .or_else(|| signature.strip_prefix("async"))
+        .unwrap_or(signature);
+    signature.trim_start().starts_with("func")
+}
+
+/// Describe a function as its name and parameter count, e.g. "read-file (1 param)".
+fn describe_wit_function(name: &str, signature: &str) -> String {
+    let params = signature
+        .split_once('(')
+        .map(|(_, params)| {
+            let mut depth = 0;
+            let mut count = 0;
+            let mut in_param = false;
+            for c in params.chars() {
+                match c {
+                    '(' | '<' => depth += 1,
+                    ')' if depth == 0 => break,
+                    ')' | '>' => depth -= 1,
+                    ',' if depth == 0 => in_param = false,
+                    c if !c.is_whitespace() && !in_param => {
+                        in_param = true;
+                        count += 1;
+                    }
+                    _ => {}
+                }
+            }
+            count
+        })
+        .unwrap_or(0);
+
+    format!(
+        "{} ({} param{})",
+        name,
+        params,
+        if params == 1 { "" } else { "s" }
+    )
+}
+
+/// Running totals for `get_directory_size`
+#[derive(Default)]
+struct DirectorySize {
+    bytes: u64,
+    files: u64,
+    /// Subdirectories found, not counting the root
+    directories: u64,
+    unreadable: Vec<String>,
+}
+
+impl DirectorySize {
+    /// Add the regular files under `dir`. Symlinks are skipped, so a link cycle can't be walked
+    /// forever and linked files aren't counted twice. Subdirectories that can't be read are
+    /// recorded and skipped; only failing to read `dir` itself is an error.
+    fn add_dir(&mut self, dir: &Path) -> Result<()> {
+        let entries = fs::read_dir(dir)
+            .map_err(|e| anyhow!("Failed to read directory '{}': {}", dir.display(), e))?;
+        for entry in entries.flatten() {
+            // `DirEntry::metadata` does not follow symlinks
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_file() {
+                self.bytes += metadata.len();
+                self.files += 1;
+            } else if metadata.is_dir() {
+                self.directories += 1;
+                if self.add_dir(&entry.path()).is_err() {
+                    self.unreadable.push(entry.path().display().to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Walk `dir` once, adding its entries to `stats`. Ties for the largest and oldest file go to
+/// the path that sorts first, so the result doesn't depend on directory order.
+fn add_directory_stats(
+    dir: &Path,
+    relative_prefix: &str,
+    stats: &mut DirectoryStats,
+) -> Result<()> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Failed to read directory '{}': {}", dir.display(), e))?;
+    for entry in entries.flatten() {
+        // `DirEntry::metadata` does not follow symlinks
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let name = entry.file_name().to_string_lossy().to_string();
+        let relative = if relative_prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", relative_prefix, name)
+        };
+
+        if metadata.is_symlink() {
+            stats.total_symlinks += 1;
+        } else if metadata.is_dir() {
+            stats.total_directories += 1;
+            // Unreadable subdirectories are skipped, as in `DirectorySize::add_dir`
+            let _ = add_directory_stats(&entry.path(), &relative, stats);
+        } else if metadata.is_file() {
+            let size = metadata.len();
+            stats.total_files += 1;
+            stats.total_size_bytes += size;
+
+            let is_larger = |(path, largest): &(String, u64)| {
+                size > *largest || (size == *largest && relative < *path)
+            };
+            if stats.largest_file.as_ref().is_none_or(is_larger) {
+                stats.largest_file = Some((relative.clone(), size));
+            }
+
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs());
+            if let Some(modified) = modified {
+                let is_older = |(path, oldest): &(String, u64)| {
+                    modified < *oldest || (modified == *oldest && relative < *path)
+                };
+                if stats.oldest_file.as_ref().is_none_or(is_older) {
+                    stats.oldest_file = Some((relative, modified));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn format_size(size: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+    let mut size_f = size as f64;
+    let mut unit_idx = 0;
+
+    while size_f >= 1024.0 && unit_idx < UNITS.len() - 1 {
+        size_f /= 1024.0;
+        unit_idx += 1;
+    }
+
+    if unit_idx == 0 {
+        format!("{} {}", size, UNITS[unit_idx])
+    } else {
+        format!("{:.2} {}", size_f, UNITS[unit_idx])
+    }
+}
+
+/// Matches file names or lines against a substring, glob or regex pattern.
+enum PatternMatcher {
+    Substring {
+        pattern: String,
+        case_insensitive: bool,
+    },
+    Glob {
+        pattern: Vec<char>,
+        case_insensitive: bool,
+    },
+    Regex(Regex),
+}
+
+impl PatternMatcher {
+    fn new(pattern: &str, pattern_type: &str, case_insensitive: bool) -> Result<Self> {
+        let normalize = |p: &str| {
+            if case_insensitive {
+                p.to_lowercase()
+            } else {
+                p.to_string()
+            }
+        };
+
+        match pattern_type.to_lowercase().as_str() {
+            "" | "substring" => Ok(Self::Substring {
+                pattern: normalize(pattern),
+                case_insensitive,
+            }),
+            "glob" => Ok(Self::Glob {
+                pattern: normalize(pattern).chars().collect(),
+                case_insensitive,
+            }),
+            "regex" => RegexBuilder::new(pattern)
+                .case_insensitive(case_insensitive)
+                .build()
+                .map(Self::Regex)
+                .map_err(|e| anyhow!("Invalid regex pattern '{}': {}", pattern, e)),
+            other => Err(anyhow!(
+                "Unsupported pattern type '{}', expected 'substring', 'glob' or 'regex'",
+                other
+            )),
+        }
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        match self {
+            Self::Substring {
+                pattern,
+                case_insensitive,
+            } => {
+                if *case_insensitive {
+                    text.to_lowercase().contains(pattern.as_str())
+                } else {
+                    text.contains(pattern.as_str())
+                }
+            }
+            Self::Glob {
+                pattern,
+                case_insensitive,
+            } => {
+                let text: Vec<char> = if *case_insensitive {
+                    text.to_lowercase().chars().collect()
+                } else {
+                    text.chars().collect()
+                };
+                glob_match(pattern, &text)
+            }
+            Self::Regex(regex) => regex.is_match(text),
+        }
+    }
+}
+
+/// Match `text` against a glob supporting `*` (any run of characters) and `?` (any one character).
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` seen in the pattern and the text position it currently covers
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // Let the last `*` absorb one more character and retry
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Shared implementation of `search_file` and `search_file_relative`. With `relative`, matches
+/// are reported relative to the search root using "/" as separator.
+fn search_file_names(
+    path: &str,
+    pattern: &str,
+    pattern_type: &str,
+    relative: bool,
+) -> Result<String, String> {
+    let requested = path;
+    let path = match get_path(path) {
+        Ok(p) => p,
+        Err(e) => {
+            return Err(e.to_string());
+        }
+    };
+    let matcher = match PatternMatcher::new(pattern, pattern_type, true) {
+        Ok(m) => m,
+        Err(e) => return Err(e.to_string()),
+    };
+    let mut matches = Vec::new();
+    if let Err(e) = search_directory(&path, &matcher, &mut matches) {
+        return Err(format!("Failed to search directory: {}", e));
+    }
+
+    if matches.is_empty() {
+        // Relative results echo the caller's path rather than the resolved host path
+        let searched = if relative {
+            requested.to_string()
+        } else {
+            path.display().to_string()
+        };
+        return Ok(format!(
+            "No files matching pattern '{}' found in '{}'",
+            pattern, searched
+        ));
+    }
+    if relative {
+        for found in &mut matches {
+            if let Ok(stripped) = Path::new(found.as_str()).strip_prefix(&path) {
+                *found = stripped
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+            }
+        }
+    }
+    Ok(matches.join("\n"))
+}
+
+fn search_directory(dir: &Path, matcher: &PatternMatcher, matches: &mut Vec<String>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+        if matcher.is_match(&name) {
+            matches.push(path.to_string_lossy().to_string());
+        }
+        if path.is_dir() {
+            search_directory(&path, matcher, matches)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn create_symlink_impl(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+/// `std` has no stable symlink API on WASI, so go through the preopened directory
+/// that contains the link instead.
+#[cfg(target_os = "wasi")]
+fn create_symlink_impl(target: &Path, link: &Path) -> std::io::Result<()> {
+    use std::io::{Error, ErrorKind};
+
+    use wasi::filesystem::types::ErrorCode;
+
+    let (dir, link_in_dir) = wasi::filesystem::preopens::get_directories()
+        .into_iter()
+        .filter_map(|(dir, preopen)| {
+            let rest = link.strip_prefix(&preopen).ok()?.to_path_buf();
+            Some((dir, preopen.len(), rest))
+        })
+        .max_by_key(|(_, len, _)| *len)
+        .map(|(dir, _, rest)| (dir, rest))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::PermissionDenied,
+                format!("'{}' is not inside an allowed directory", link.display()),
+            )
+        })?;
+
+    // WASI hosts reject absolute symlink targets, so store the target relative to the link
+    let target = match link.parent() {
+        Some(parent) if target.is_absolute() => relative_path(parent, target),
+        _ => target.to_path_buf(),
+    };
+
+    dir.symlink_at(&target.to_string_lossy(), &link_in_dir.to_string_lossy())
+        .map_err(|e| match e {
+            ErrorCode::Access | ErrorCode::NotPermitted => {
+                Error::new(ErrorKind::PermissionDenied, format!("{:?}", e))
+            }
+            ErrorCode::Exist => Error::new(ErrorKind::AlreadyExists, format!("{:?}", e)),
+            _ => Error::other(format!("{:?}", e)),
+        })
+}
+
+/// Express `target` relative to the directory `base`, e.g. `/a/b` + `/a/c/d` -> `../c/d`.
+#[cfg(target_os = "wasi")]
+fn relative_path(base: &Path, target: &Path) -> PathBuf {
+    let base: Vec<_> = base.components().collect();
+    let target: Vec<_> = target.components().collect();
+    let common = base
+        .iter()
+        .zip(target.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut result = PathBuf::new();
+    for _ in common..base.len() {
+        result.push("..");
+    }
+    for component in &target[common..] {
+        result.push(component);
+    }
+    result
+}
+
+#[cfg(windows)]
+fn create_symlink_impl(target: &Path, link: &Path) -> std::io::Result<()> {
+    if target.is_dir() {
+        std::os::windows::fs::symlink_dir(target, link)
+    } else {
+        std::os::windows::fs::symlink_file(target, link)
+    }
+}
+
+/// Summarise the current permissions of `path` for success messages.
+fn describe_permissions(path: &Path) -> String {
+    match read_file_info(path) {
+        Ok(info) => {
+            let readonly = if info.readonly { "yes" } else { "no" };
+            match info.mode_octal {
+                Some(mode) => format!("read-only: {}, mode: {}", readonly, mode),
+                None => format!("read-only: {}", readonly),
+            }
+        }
+        Err(e) => e.to_string(),
+    }
+}
+
+fn permission_error(path: &Path, e: std::io::Error) -> String {
+    let error_msg = format!("Failed to set permissions of '{}': {}", path.display(), e);
+    if e.kind() == std::io::ErrorKind::Unsupported {
+        format!(
+            "{} The host does not support changing permissions.",
+            error_msg
+        )
+    } else {
+        error_msg
+    }
+}
+
+/// Call `visit` with the 1-based number and text of each line of `path`, without its line
+/// ending, until it returns false.
+fn for_each_line(path: &Path, mut visit: impl FnMut(u64, String) -> bool) -> Result<()> {
+    if path.is_dir() {
+        return Err(anyhow!("'{}' is a directory", path.display()));
+    }
+    let file = fs::File::open(path)
+        .map_err(|e| anyhow!("Failed to open file '{}': {}", path.display(), e))?;
+    let mut reader = BufReader::new(file);
+    let mut line = Vec::new();
+    let mut number = 0;
+    loop {
+        line.clear();
+        let read = reader
+            .read_until(b'\n', &mut line)
+            .map_err(|e| anyhow!("Failed to read file '{}': {}", path.display(), e))?;
+        if read == 0 {
+            return Ok(());
+        }
+        number += 1;
+
+        if line.ends_with(b"\n") {
+            line.pop();
+            if line.ends_with(b"\r") {
+                line.pop();
+            }
+        }
+        let text = String::from_utf8(std::mem::take(&mut line))
+            .map_err(|_| anyhow!("Line {} of '{}' is not valid UTF-8", number, path.display()))?;
+        if !visit(number, text) {
+            return Ok(());
+        }
+    }
+}
+
+/// Read `length` bytes starting at `offset`, shortened if the range passes the end of the file.
+fn read_range(path: &Path, offset: u64, length: u64) -> Result<Vec<u8>> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| anyhow!("Failed to read file '{}': {}", path.display(), e))?;
+    let size = file
+        .metadata()
+        .map_err(|e| anyhow!("Failed to read metadata of '{}': {}", path.display(), e))?
+        .len();
+
+    if offset > size {
+        return Err(anyhow!(
+            "Offset {} is past the end of '{}' ({} bytes)",
+            offset,
+            path.display(),
+            size
+        ));
+    }
+
+    let available = length.min(size - offset);
+    let mut buffer = vec![0; available as usize];
+    file.seek(SeekFrom::Start(offset))
+        .and_then(|_| file.read_exact(&mut buffer))
+        .map_err(|e| anyhow!("Failed to read file '{}': {}", path.display(), e))?;
+    Ok(buffer)
+}
+
+fn read_json_document(path: &Path) -> Result<Value> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read file '{}': {}", path.display(), e))?;
+    serde_json::from_str(&text)
+        .map_err(|e| anyhow!("File '{}' is not valid JSON: {}", path.display(), e))
+}
+
+/// Merge `overlay` into `base`: objects are merged key by key, arrays are appended when
+/// `append_arrays` is set, and any other value from `overlay` replaces the one in `base`.
+fn merge_json(base: &mut Value, overlay: Value, append_arrays: bool) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value, append_arrays),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(overlay)) if append_arrays => base.extend(overlay),
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Set the value at `pointer` (RFC 6901), adding a missing object key or appending to an
+/// array with `-` in the last segment, like a JSON Patch "add" that replaces existing values.
+fn set_json_pointer(document: &mut Value, pointer: &str, value: Value) -> Result<()> {
+    if pointer.is_empty() {
+        *document = value;
+        return Ok(());
+    }
+    if !pointer.starts_with('/') {
+        return Err(anyhow!(
+            "JSON pointer '{}' must be empty or start with '/'",
+            pointer
+        ));
+    }
+
+    let (parent_pointer, last) = pointer.rsplit_once('/').unwrap_or_default();
+    let key = last.replace("~1", "/").replace("~0", "~");
+    let parent = document
+        .pointer_mut(parent_pointer)
+        .ok_or_else(|| anyhow!("JSON pointer '{}' does not match any value", parent_pointer))?;
+
+    match parent {
+        Value::Object(map) => {
+            map.insert(key, value);
+        }
+        Value::Array(items) if key == "-" => items.push(value),
+        Value::Array(items) => {
+            let index = key
+                .parse::<usize>()
+                .ok()
+                .filter(|&i| i < items.len() && (key == "0" || !key.starts_with('0')))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "'{}' is not a valid index into an array of {} items; use '-' to append",
+                        key,
+                        items.len()
+                    )
+                })?;
+            items[index] = value;
+        }
+        _ => {
+            return Err(anyhow!(
+                "JSON pointer '{}' does not refer to an object or array",
+                parent_pointer
+            ))
+        }
+    }
+    Ok(())
+}
+
+/// Write `content` to a temporary sibling of `path` and rename it into place.
+///
+/// Falls back to copy-then-delete when the rename fails, e.g. when the host maps the
+/// directory across devices.
+fn atomic_write(path: &Path, content: &[u8]) -> Result<()> {
+    let path = &write_target(path);
+    let (temp_path, mut file) = create_temp_sibling(path)?;
+
+    let written = file.write_all(content).and_then(|_| file.sync_all());
+    drop(file);
+    if let Err(e) = written {
+        let _ = fs::remove_file(&temp_path);
+        return Err(anyhow!(
+            "Failed to write temporary file '{}': {}",
+            temp_path.display(),
+            e
+        ));
+    }
+
+    persist_temp_file(&temp_path, path)
+}
+
+/// Rename `temp_path` over `path`, falling back to copy-then-delete when the rename fails.
+/// An existing file at `path` keeps its permissions.
+fn persist_temp_file(temp_path: &Path, path: &Path) -> Result<()> {
+    if let Ok(metadata) = fs::metadata(path) {
+        if let Err(e) = fs::set_permissions(temp_path, metadata.permissions()) {
+            let _ = fs::remove_file(temp_path);
+            return Err(anyhow!(
+                "Failed to copy permissions of '{}': {}",
+                path.display(),
+                e
+            ));
+        }
+    }
+    if fs::rename(temp_path, path).is_err() {
+        let copied = fs::copy(temp_path, path);
+        let _ = fs::remove_file(temp_path);
+        copied.map_err(|e| anyhow!("Failed to write to file '{}': {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// `max_replacements` as a limit, where 0 means unlimited
+fn replacement_limit(max_replacements: u32) -> u64 {
+    match max_replacements {
+        0 => u64::MAX,
+        n => n as u64,
+    }
+}
+
+/// Stream `path` through `transform` into a temporary sibling and rename it into place.
+/// `transform` returns the number of changes it made; with none, `path` is left untouched.
+fn rewrite_file(
+    path: &Path,
+    transform: impl FnOnce(&mut BufReader<fs::File>, &mut BufWriter<&fs::File>) -> Result<u64>,
+) -> Result<u64> {
+    let path = &write_target(path);
+    if path.is_dir() {
+        return Err(anyhow!("'{}' is a directory", path.display()));
+    }
+    let source = fs::File::open(path)
+        .map_err(|e| anyhow!("Failed to open file '{}': {}", path.display(), e))?;
+    let mut reader = BufReader::new(source);
+    let (temp_path, file) = create_temp_sibling(path)?;
+
+    let result = (|| {
+        let mut writer = BufWriter::new(&file);
+        let changes = transform(&mut reader, &mut writer)?;
+        writer.flush().and_then(|_| file.sync_all()).map_err(|e| {
+            anyhow!(
+                "Failed to write temporary file '{}': {}",
+                temp_path.display(),
+                e
+            )
+        })?;
+        Ok(changes)
+    })();
+    drop(file);
+
+    match result {
+        Ok(changes) if changes > 0 => {
+            persist_temp_file(&temp_path, path)?;
+            Ok(changes)
+        }
+        result => {
+            let _ = fs::remove_file(&temp_path);
+            result
+        }
+    }
+}
+
+/// Copy `reader` to `writer`, replacing up to `limit` occurrences of `find`. Occurrences may
+/// span read boundaries, so the last `find.len() - 1` bytes of each chunk are held back.
+fn replace_literal(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+    find: &[u8],
+    replace: &[u8],
+    limit: u64,
+) -> Result<u64> {
+    let mut pending = Vec::new();
+    let mut chunk = vec![0u8; 64 * 1024];
+    let mut count = 0;
+    loop {
+        let read = reader.read(&mut chunk)?;
+        pending.extend_from_slice(&chunk[..read]);
+
+        let mut start = 0;
+        while count < limit {
+            let Some(offset) = pending[start..]
+                .windows(find.len())
+                .position(|window| window == find)
+            else {
+                break;
+            };
+            writer.write_all(&pending[start..start + offset])?;
+            writer.write_all(replace)?;
+            start += offset + find.len();
+            count += 1;
+        }
+
+        if read == 0 || count == limit {
+            writer.write_all(&pending[start..])?;
+            std::io::copy(reader, writer)?;
+            return Ok(count);
+        }
+        let keep_from = pending.len().saturating_sub(find.len() - 1).max(start);
+        writer.write_all(&pending[start..keep_from])?;
+        pending.drain(..keep_from);
+    }
+}
+
+/// Copy `reader` to `writer`, passing line `line_number` (1-based, with its line ending) to
+/// `edit` to write in its place. Fails if the file has fewer lines.
+fn edit_line(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    line_number: u64,
+    edit: impl FnOnce(&[u8], &mut dyn Write) -> std::io::Result<()>,
+) -> Result<u64> {
+    if line_number == 0 {
+        return Err(anyhow!("Line numbers start at 1"));
+    }
+    let mut line = Vec::new();
+    for number in 1..=line_number {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Err(anyhow!(
+                "Line {} is past the end of the file ({} lines)",
+                line_number,
+                number - 1
+            ));
+        }
+        if number < line_number {
+            writer.write_all(&line)?;
+        }
+    }
+    edit(&line, writer)?;
+    std::io::copy(reader, writer)?;
+    Ok(1)
+}
+
+/// Copy `reader` to `writer` line by line, replacing up to `limit` matches of `regex`
+fn replace_regex(
+    reader: &mut impl BufRead,
+    writer: &mut impl Write,
+    regex: &Regex,
+    replace: &str,
+    limit: u64,
+) -> Result<u64> {
+    let mut line = Vec::new();
+    let mut number = 0;
+    let mut count = 0;
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(count);
+        }
+        number += 1;
+        if count == limit {
+            writer.write_all(&line)?;
+            continue;
+        }
+
+        let text = std::str::from_utf8(&line)
+            .map_err(|_| anyhow!("Line {} is not valid UTF-8", number))?;
+        let content = text.trim_end_matches(['\n', '\r']);
+        let matches = regex
+            .find_iter(content)
+            .take((limit - count).min(usize::MAX as u64) as usize)
+            .count();
+        if matches == 0 {
+            writer.write_all(&line)?;
+            continue;
+        }
+        writer.write_all(regex.replacen(content, matches, replace).as_bytes())?;
+        writer.write_all(&text.as_bytes()[content.len()..])?;
+        count += matches as u64;
+    }
+}
+
+/// The file a rewrite of `path` replaces: the target of a symlink at `path`, so the link
+/// survives, or else `path` itself
+fn write_target(path: &Path) -> PathBuf {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_symlink() => {
+            normalize(path).unwrap_or_else(|_| path.to_path_buf())
+        }
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Create a new, uniquely named file next to `path`.
+fn create_temp_sibling(path: &Path) -> Result<(PathBuf, fs::File)> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("'{}' is not a valid file path", path.display()))?
+        .to_string_lossy();
+
+    let mut last_error = None;
+    for _ in 0..8 {
+        let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, random_suffix()));
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&temp_path)
+        {
+            Ok(file) => return Ok((temp_path, file)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => last_error = Some(e),
+            Err(e) => {
+                return Err(anyhow!(
+                    "Failed to create temporary file '{}': {}",
+                    temp_path.display(),
+                    e
+                ))
+            }
+        }
+    }
+    Err(anyhow!(
+        "Failed to create a temporary file next to '{}': {}",
+        path.display(),
+        last_error.map(|e| e.to_string()).unwrap_or_default()
+    ))
+}
+
+/// A best-effort unique suffix for temporary file names.
+fn random_suffix() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{:x}", nanos ^ count.rotate_left(32))
+}
+
+/// Create the parent directory of `path` if it doesn't exist yet.
+fn ensure_parent_dir(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.exists() {
+            fs::create_dir_all(parent).map_err(|e| {
+                anyhow!(
+                    "Failed to create parent directory '{}': {}",
+                    parent.display(),
+                    e
+                )
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Search the text files under `dir` for lines matching `matcher`.
+///
+/// Returns `true` once `limit` matches have been collected so callers can report truncation.
+fn search_content_directory(
+    dir: &Path,
+    matcher: &PatternMatcher,
+    limit: usize,
+    matches: &mut Vec<String>,
+) -> Result<bool> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+
+        let truncated = if file_type.is_dir() {
+            search_content_directory(&path, matcher, limit, matches)?
+        } else if file_type.is_file() {
+            search_content_file(&path, matcher, limit, matches)?
+        } else {
+            false
+        };
+        if truncated {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn search_content_file(
+    path: &Path,
+    matcher: &PatternMatcher,
+    limit: usize,
+    matches: &mut Vec<String>,
+) -> Result<bool> {
+    // Unreadable, binary and non-UTF-8 files are skipped rather than failing the search
+    let Ok(bytes) = fs::read(path) else {
+        return Ok(false);
+    };
+    if bytes.contains(&0) {
+        return Ok(false);
+    }
+    let Ok(text) = String::from_utf8(bytes) else {
+        return Ok(false);
+    };
+
+    for (idx, line) in text.lines().enumerate() {
+        if matcher.is_match(line) {
+            if matches.len() >= limit {
+                return Ok(true);
+            }
+            matches.push(format!("{}:{}:{}", path.display(), idx + 1, line));
+        }
+    }
+    Ok(false)
+}
+
+/// Count the lines of `path` matching `regex`, reading one line at a time. Returns `None` for
+/// binary files, recognised by a NUL byte.
+fn count_matching(path: &Path, regex: &Regex) -> Result<Option<u64>> {
+    let file = fs::File::open(path)
+        .map_err(|e| anyhow!("Failed to open file '{}': {}", path.display(), e))?;
+    let mut reader = BufReader::new(file);
+    let mut line = Vec::new();
+    let mut count = 0;
+    loop {
+        line.clear();
+        let read = reader
+            .read_until(b'\n', &mut line)
+            .map_err(|e| anyhow!("Failed to read file '{}': {}", path.display(), e))?;
+        if read == 0 {
+            return Ok(Some(count));
+        }
+        if line.contains(&0) {
+            return Ok(None);
+        }
+        let text = String::from_utf8_lossy(&line);
+        if regex.is_match(text.trim_end_matches(['\n', '\r'])) {
+            count += 1;
+        }
+    }
+}
+
+/// Collect `(relative path, matching line count)` for the text files under `dir`
+fn count_matching_in_directory(
+    dir: &Path,
+    relative_prefix: &str,
+    regex: &Regex,
+    counts: &mut Vec<(String, u64)>,
+) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let relative = if relative_prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", relative_prefix, name)
+        };
+
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            count_matching_in_directory(&entry.path(), &relative, regex, counts)?;
+        } else if file_type.is_file() {
+            // Binary and unreadable files are skipped rather than failing the count
+            if let Ok(Some(count)) = count_matching(&entry.path(), regex) {
+                counts.push((relative, count));
+            }
+        }
+    }
+    Ok(())
 }
 
-fn build_tree(
-    dir: &Path,
-    output: &mut String,
-    current_depth: u32,
-    max_depth: u32,
-    prefix: &str,
-) -> Result<()> {
-    if current_depth > max_depth {
-        return Ok(());
+thread_local! {
+    /// Directory that relative paths are joined to, set by `set_root_directory`
+    static ROOT_DIRECTORY: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+
+    /// Canonical directory every path must stay inside, set by `set_jail_directory`
+    static JAIL_DIRECTORY: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+
+    /// Most bytes a `write_file` call may write, set by `set_write_size_limit`
+    static WRITE_SIZE_LIMIT: Cell<Option<u64>> = const { Cell::new(None) };
+
+    /// Directories made by `create_temp_directory` that `delete_temp_directory` may remove
+    static TEMP_DIRECTORIES: RefCell<Vec<PathBuf>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Reject content longer than `max_bytes`
+fn check_write_size(content: &str, max_bytes: u64) -> Result<(), String> {
+    let size = content.len() as u64;
+    if size > max_bytes {
+        return Err(format!(
+            "Content exceeds limit of {} bytes ({} bytes given)",
+            max_bytes, size
+        ));
     }
+    Ok(())
+}
 
-    let entries = match fs::read_dir(dir) {
-        Ok(e) => e,
-        Err(e) => {
-            return Err(anyhow!(
-                "Failed to read directory '{}': {}",
-                dir.display(),
-                e
-            ))
+/// How many dangling symlinks `normalize` follows by hand before giving up on a cycle
+const MAX_SYMLINK_HOPS: u32 = 40;
+
+/// Resolve a user-supplied path, rejecting it if a jail is set and the path, with `..` and
+/// symlinks resolved, lies outside the jail.
+fn get_path(path_str: &str) -> Result<PathBuf> {
+    let path = resolve_path(path_str)?;
+    if let Some(jail) = JAIL_DIRECTORY.with(|jail| jail.borrow().clone()) {
+        match normalize(&path) {
+            Ok(normalized) if normalized.starts_with(&jail) => {}
+            _ => return Err(anyhow!("Path escapes the allowed root directory")),
         }
-    };
+    }
+    Ok(path)
+}
 
-    let mut entries: Vec<_> = entries.collect();
-    entries.sort_by_key(|e| {
-        e.as_ref()
-            .ok()
-            .and_then(|e| e.file_name().into_string().ok())
-    });
+/// Whether `path` is the jail directory or a preopened directory, which must never be removed
+/// as a whole
+fn is_allowed_root(path: &Path) -> bool {
+    let normalized = normalize(path).unwrap_or_else(|_| lexically_normalize(path));
+    let is_jail = JAIL_DIRECTORY.with(|jail| jail.borrow().as_deref() == Some(&normalized));
+    is_jail
+        || preopened_directories()
+            .iter()
+            .map(|dir| lexically_normalize(dir))
+            .any(|dir| dir == normalized || dir == lexically_normalize(path))
+}
 
-    let count = entries.len();
-    for (idx, entry_result) in entries.into_iter().enumerate() {
-        let entry = match entry_result {
-            Ok(e) => e,
-            Err(_) => continue,
-        };
+/// The directories the host preopened for this component
+#[cfg(target_os = "wasi")]
+fn preopened_directories() -> Vec<PathBuf> {
+    wasi::filesystem::preopens::get_directories()
+        .into_iter()
+        .map(|(_, preopen)| PathBuf::from(preopen))
+        .collect()
+}
 
-        let is_last = idx == count - 1;
-        let connector = if is_last { "└── " } else { "├── " };
-        let extension = if is_last { "    " } else { "│   " };
+#[cfg(not(target_os = "wasi"))]
+fn preopened_directories() -> Vec<PathBuf> {
+    Vec::new()
+}
 
-        let file_name = entry.file_name();
-        let file_type = match entry.file_type() {
-            Ok(ft) if ft.is_dir() => "[DIR] ",
-            Ok(_) => "",
-            Err(_) => "[?] ",
-        };
+/// Describe a failed `delete_directory_recursive`, calling out read-only entries separately
+fn delete_directory_error(path: &Path, e: &std::io::Error) -> String {
+    if e.kind() == std::io::ErrorKind::PermissionDenied {
+        format!(
+            "Failed to delete directory '{}': {}. The directory or one of its entries is \
+             read-only or write permission has not been granted.",
+            path.display(),
+            e
+        )
+    } else {
+        format!("Failed to delete directory '{}': {}", path.display(), e)
+    }
+}
 
-        output.push_str(&format!(
-            "{}{}{}{}\n",
-            prefix,
-            connector,
-            file_type,
-            file_name.to_string_lossy()
-        ));
+fn resolve_path(path_str: &str) -> Result<PathBuf> {
+    if let Some(path) = expand_xdg_base_dir(path_str)? {
+        return Ok(path);
+    }
 
-        if entry.path().is_dir() {
-            let new_prefix = format!("{}{}", prefix, extension);
-            build_tree(
-                &entry.path(),
-                output,
-                current_depth + 1,
-                max_depth,
-                &new_prefix,
-            )?;
+    // Windows users also write `~\dir`
+    let home_suffix = match path_str.strip_prefix("~/") {
+        Some(suffix) => Some(suffix),
+        None if cfg!(windows) => path_str.strip_prefix("~\\"),
+        None => None,
+    };
+
+    if path_str == "~" || home_suffix.is_some() {
+        let home_dir = home_dir()?;
+
+        if path_str == "~" {
+            return Ok(PathBuf::from(home_dir));
         }
+        let combined = Path::new(&home_dir).join(home_suffix.unwrap_or_default());
+        return Ok(combined);
     }
 
-    Ok(())
+    let path = PathBuf::from(path_str);
+    if path.is_absolute() {
+        return Ok(path);
+    }
+    Ok(ROOT_DIRECTORY.with(|root| match &*root.borrow() {
+        Some(root) => root.join(&path),
+        None => path,
+    }))
 }
 
-fn format_size(size: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-    let mut size_f = size as f64;
-    let mut unit_idx = 0;
-
-    while size_f >= 1024.0 && unit_idx < UNITS.len() - 1 {
-        size_f /= 1024.0;
-        unit_idx += 1;
+/// Decode file contents, returning the text and the name of the encoding used. A byte order
+/// mark decides between UTF-8 and UTF-16; without one the bytes are UTF-8 if valid, else Latin-1.
+fn decode_text(bytes: &[u8]) -> (String, &'static str) {
+    if let Some(rest) = bytes.strip_prefix(b"\xEF\xBB\xBF") {
+        return (String::from_utf8_lossy(rest).into_owned(), "utf-8-bom");
+    }
+    if let Some(rest) = bytes.strip_prefix(b"\xFF\xFE") {
+        return (decode_utf16_lossy(rest, u16::from_le_bytes), "utf-16le");
     }
+    if let Some(rest) = bytes.strip_prefix(b"\xFE\xFF") {
+        return (decode_utf16_lossy(rest, u16::from_be_bytes), "utf-16be");
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => (text.to_string(), "utf-8"),
+        // Latin-1 code points are the byte values, so this never fails or loses data
+        Err(_) => (bytes.iter().map(|&b| char::from(b)).collect(), "latin-1"),
+    }
+}
 
-    if unit_idx == 0 {
-        format!("{} {}", size, UNITS[unit_idx])
-    } else {
-        format!("{:.2} {}", size_f, UNITS[unit_idx])
+/// Decode UTF-16 with the given byte order, replacing unpaired surrogates and a trailing odd
+/// byte with U+FFFD
+fn decode_utf16_lossy(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> String {
+    let chunks = bytes.chunks_exact(2);
+    let odd_byte = !chunks.remainder().is_empty();
+    let mut text: String = char::decode_utf16(chunks.map(|c| unit([c[0], c[1]])))
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect();
+    if odd_byte {
+        text.push(char::REPLACEMENT_CHARACTER);
     }
+    text
 }
 
-fn search_directory(dir: &Path, pattern: &str, matches: &mut Vec<String>) -> Result<()> {
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        let name = path
-            .file_name()
-            .unwrap_or_default()
-            .to_string_lossy()
-            .to_lowercase();
+/// Canonicalise `path` if it exists. Otherwise canonicalise its deepest existing ancestor and
+/// append the rest, after removing `.` and `..` components without touching the filesystem.
+/// Dangling symlinks along the way are followed to where their target would be.
+fn normalize(path: &Path) -> std::io::Result<PathBuf> {
+    normalize_following(path, 0)
+}
 
-        if name.contains(&pattern.to_lowercase()) {
-            matches.push(path.to_string_lossy().to_string());
+fn normalize_following(path: &Path, hops: u32) -> std::io::Result<PathBuf> {
+    if path.exists() {
+        return fs::canonicalize(path);
+    }
+
+    let path = lexically_normalize(path);
+    let mut missing = Vec::new();
+    let mut ancestor = path.as_path();
+    // An empty ancestor is the working directory a relative path starts from. symlink_metadata
+    // doesn't follow links, so a dangling symlink stops the walk and is resolved below.
+    while !ancestor.as_os_str().is_empty() && fs::symlink_metadata(ancestor).is_err() {
+        let (Some(parent), Some(name)) = (ancestor.parent(), ancestor.file_name()) else {
+            return Ok(path.clone());
+        };
+        missing.push(name);
+        ancestor = parent;
+    }
+
+    let mut normalized = if ancestor.as_os_str().is_empty() {
+        fs::canonicalize(".")?
+    } else if ancestor.exists() {
+        fs::canonicalize(ancestor)?
+    } else {
+        // canonicalize fails on a dangling symlink, so follow it by hand
+        if hops >= MAX_SYMLINK_HOPS {
+            return Err(std::io::Error::other("Too many levels of symbolic links"));
         }
-        if path.is_dir() {
-            search_directory(&path, pattern, matches)?;
+        let target = fs::read_link(ancestor)?;
+        let parent = ancestor.parent().unwrap_or(Path::new(""));
+        normalize_following(&parent.join(target), hops + 1)?
+    };
+    normalized.extend(missing.iter().rev());
+    Ok(normalized)
+}
+
+/// Remove `.` components and resolve `..` against the preceding component. Leading `..` of a
+/// relative path are kept, and `..` at the root stays at the root.
+fn lexically_normalize(path: &Path) -> PathBuf {
+    use std::path::Component as PathComponent;
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            PathComponent::CurDir => {}
+            PathComponent::ParentDir => match normalized.components().next_back() {
+                Some(PathComponent::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(PathComponent::RootDir | PathComponent::Prefix(_)) => {}
+                _ => normalized.push(".."),
+            },
+            other => normalized.push(other),
         }
     }
-    Ok(())
+    normalized
 }
 
-fn get_path(path_str: &str) -> Result<PathBuf> {
-    if path_str == "~" || path_str.starts_with("~/") {
-        let home_dir =
-            env::var("HOME").map_err(|_| anyhow!("Cannot determine home directory from $HOME"))?;
+/// XDG base directory variables `get_path` expands, with the fallback under the home directory
+/// used when a variable is unset. `XDG_RUNTIME_DIR` has no fallback.
+const XDG_BASE_DIRS: &[(&str, Option<&str>)] = &[
+    ("XDG_DATA_HOME", Some(".local/share")),
+    ("XDG_CONFIG_HOME", Some(".config")),
+    ("XDG_CACHE_HOME", Some(".cache")),
+    ("XDG_STATE_HOME", Some(".local/state")),
+    ("XDG_RUNTIME_DIR", None),
+];
 
-        if path_str == "~" {
-            return Ok(PathBuf::from(home_dir));
-        }
-        let suffix = &path_str[2..];
-        let combined = Path::new(&home_dir).join(suffix);
-        return Ok(combined);
+/// Expand a leading `$XDG_*_HOME` or `$XDG_RUNTIME_DIR`, e.g. `$XDG_DATA_HOME/myapp`. Returns
+/// `None` if the path doesn't start with one. As the XDG spec requires, empty and relative
+/// values count as unset.
+fn expand_xdg_base_dir(path_str: &str) -> Result<Option<PathBuf>> {
+    let Some(rest) = path_str.strip_prefix('$') else {
+        return Ok(None);
+    };
+    for (name, fallback) in XDG_BASE_DIRS {
+        let Some(suffix) = rest.strip_prefix(name) else {
+            continue;
+        };
+        let suffix = match suffix.strip_prefix('/') {
+            Some(suffix) => suffix,
+            None if cfg!(windows) && suffix.starts_with('\\') => &suffix[1..],
+            None if suffix.is_empty() => suffix,
+            // e.g. `$XDG_DATA_HOME_OLD`, which isn't ours to expand
+            None => return Ok(None),
+        };
+
+        let base = match env::var(name) {
+            Ok(value) if Path::new(&value).is_absolute() => PathBuf::from(value),
+            _ => match fallback {
+                Some(fallback) => Path::new(&home_dir()?).join(fallback),
+                None => return Err(anyhow!("Cannot expand ${} because it is not set", name)),
+            },
+        };
+        return Ok(Some(if suffix.is_empty() {
+            base
+        } else {
+            base.join(suffix)
+        }));
+    }
+    Ok(None)
+}
+
+/// `$HOME`, falling back to `%USERPROFILE%` on Windows where `$HOME` is often unset.
+fn home_dir() -> Result<String> {
+    if let Ok(home_dir) = env::var("HOME") {
+        return Ok(home_dir);
+    }
+
+    #[cfg(windows)]
+    if let Ok(profile_dir) = env::var("USERPROFILE") {
+        return Ok(profile_dir);
     }
 
-    Ok(PathBuf::from(path_str))
+    Err(anyhow!("Cannot determine home directory from $HOME"))
 }
 
 bindings::export!(Component with_types_in bindings);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh directory under the system temp directory, unique to this test and process
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("filesystem-rs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_jail_rejects_parent_escape() {
+        let dir = test_dir("jail-parent");
+        let jail = dir.join("jail");
+        fs::create_dir_all(&jail).unwrap();
+        fs::write(jail.join("inside.txt"), "inside").unwrap();
+        fs::write(dir.join("outside.txt"), "outside").unwrap();
+
+        Component::set_jail_directory(jail.display().to_string()).unwrap();
+        assert!(get_path(&jail.join("inside.txt").display().to_string()).is_ok());
+        assert!(get_path(&jail.join("new/file.txt").display().to_string()).is_ok());
+        for escape in [
+            jail.join("../outside.txt"),
+            jail.join("sub/../../outside.txt"),
+            dir.join("outside.txt"),
+        ] {
+            assert_eq!(
+                Component::read_file(escape.display().to_string()).unwrap_err(),
+                "Path escapes the allowed root directory"
+            );
+        }
+
+        Component::clear_jail();
+        assert!(get_path(&jail.join("../outside.txt").display().to_string()).is_ok());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_jail_rejects_symlink_escape() {
+        use std::os::unix::fs::symlink;
+
+        let dir = test_dir("jail-symlink");
+        let jail = dir.join("jail");
+        let outside = dir.join("outside");
+        fs::create_dir_all(jail.join("data")).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("secret.txt"), "secret").unwrap();
+
+        symlink(&outside, jail.join("escape")).unwrap();
+        symlink(outside.join("missing.txt"), jail.join("dangling")).unwrap();
+        symlink("../outside/new", jail.join("relative")).unwrap();
+        symlink("data", jail.join("internal")).unwrap();
+
+        Component::set_jail_directory(jail.display().to_string()).unwrap();
+        for escape in [
+            jail.join("escape/secret.txt"),
+            jail.join("escape"),
+            jail.join("dangling"),
+            jail.join("relative/file.txt"),
+        ] {
+            assert_eq!(
+                get_path(&escape.display().to_string())
+                    .unwrap_err()
+                    .to_string(),
+                "Path escapes the allowed root directory",
+                "{} should be rejected",
+                escape.display()
+            );
+        }
+        assert!(get_path(&jail.join("internal/file.txt").display().to_string()).is_ok());
+
+        Component::clear_jail();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_delete_directory_recursive_refuses_jail_root() {
+        let dir = test_dir("delete-jail-root");
+        fs::create_dir_all(dir.join("sub")).unwrap();
+        fs::write(dir.join("sub/file.txt"), "data").unwrap();
+
+        Component::set_jail_directory(dir.display().to_string()).unwrap();
+        for root in [dir.clone(), dir.join("sub/..")] {
+            assert!(
+                Component::delete_directory_recursive(root.display().to_string())
+                    .unwrap_err()
+                    .contains("Refusing to recursively delete root directory"),
+                "{} should be refused",
+                root.display()
+            );
+        }
+        assert!(dir.join("sub/file.txt").exists());
+        Component::delete_directory_recursive(dir.join("sub").display().to_string()).unwrap();
+        assert!(!dir.join("sub").exists());
+
+        Component::clear_jail();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_delete_directory_recursive_keeps_symlink_targets() {
+        let dir = test_dir("delete-symlink");
+        let tree = dir.join("tree");
+        let outside = dir.join("outside");
+        fs::create_dir_all(&tree).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("keep.txt"), "keep").unwrap();
+        std::os::unix::fs::symlink(&outside, tree.join("link")).unwrap();
+
+        Component::delete_directory_recursive(tree.display().to_string()).unwrap();
+        assert!(!tree.exists());
+        assert_eq!(
+            fs::read_to_string(outside.join("keep.txt")).unwrap(),
+            "keep"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_delete_directory_error_mentions_read_only() {
+        let path = Path::new("/data/tree");
+        let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert!(delete_directory_error(path, &denied).contains("is read-only"));
+
+        let missing = std::io::Error::from(std::io::ErrorKind::NotFound);
+        let message = delete_directory_error(path, &missing);
+        assert!(message.starts_with("Failed to delete directory '/data/tree'"));
+        assert!(!message.contains("read-only"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_atomic_write_keeps_permissions_and_symlinks() {
+        use std::os::unix::fs::{symlink, PermissionsExt};
+
+        let dir = test_dir("atomic-write");
+        let target = dir.join("target.txt");
+        fs::write(&target, "old").unwrap();
+        fs::set_permissions(&target, fs::Permissions::from_mode(0o640)).unwrap();
+        symlink("target.txt", dir.join("link.txt")).unwrap();
+
+        atomic_write(&target, b"new").unwrap();
+        let mode = fs::metadata(&target).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o640);
+
+        atomic_write(&dir.join("link.txt"), b"through link").unwrap();
+        assert!(fs::symlink_metadata(dir.join("link.txt"))
+            .unwrap()
+            .is_symlink());
+        assert_eq!(fs::read_to_string(&target).unwrap(), "through link");
+
+        let changes = rewrite_file(&dir.join("link.txt"), |reader, writer| {
+            replace_literal(reader, writer, b"link", b"symlink", u64::MAX)
+        })
+        .unwrap();
+        assert_eq!(changes, 1);
+        assert!(fs::symlink_metadata(dir.join("link.txt"))
+            .unwrap()
+            .is_symlink());
+        assert_eq!(fs::read_to_string(&target).unwrap(), "through symlink");
+        let mode = fs::metadata(&target).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o640);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_copy_directory_counts_and_creates_parents() {
+        let dir = test_dir("copy-directory");
+        let source = dir.join("source");
+        fs::create_dir_all(source.join("a/b")).unwrap();
+        fs::write(source.join("one.txt"), "1").unwrap();
+        fs::write(source.join("a/two.txt"), "2").unwrap();
+        fs::write(source.join("a/b/three.txt"), "3").unwrap();
+
+        let destination = dir.join("missing/parent/copy");
+        let message = Component::copy_directory(
+            source.display().to_string(),
+            destination.display().to_string(),
+        )
+        .unwrap();
+        assert!(message.ends_with("(3 files, 3 directories)"), "{message}");
+        assert_eq!(
+            fs::read_to_string(destination.join("a/b/three.txt")).unwrap(),
+            "3"
+        );
+
+        let err = Component::copy_directory(
+            source.display().to_string(),
+            destination.display().to_string(),
+        )
+        .unwrap_err();
+        assert!(err.contains("already exists"), "{err}");
+        let err = Component::copy_directory(
+            source.display().to_string(),
+            source.join("a/inside").display().to_string(),
+        )
+        .unwrap_err();
+        assert!(err.contains("into itself"), "{err}");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_directory_skips_symlinks() {
+        let dir = test_dir("copy-directory-symlinks");
+        let source = dir.join("source");
+        fs::create_dir_all(&source).unwrap();
+        fs::write(source.join("file.txt"), "data").unwrap();
+        std::os::unix::fs::symlink("file.txt", source.join("link.txt")).unwrap();
+        std::os::unix::fs::symlink(&dir, source.join("parent")).unwrap();
+
+        let destination = dir.join("copy");
+        let message = Component::copy_directory(
+            source.display().to_string(),
+            destination.display().to_string(),
+        )
+        .unwrap();
+        assert!(message.ends_with(", skipped 2 symlinks"), "{message}");
+        assert!(destination.join("file.txt").is_file());
+        assert!(fs::symlink_metadata(destination.join("link.txt")).is_err());
+        assert!(fs::symlink_metadata(destination.join("parent")).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_and_write_json_value() {
+        let dir = test_dir("json-pointer");
+        let path = dir.join("config.json");
+        fs::write(
+            &path,
+            r#"{"servers": [{"name": "a"}], "a/b": {"~key": 1}, "debug": false}"#,
+        )
+        .unwrap();
+        let file = path.display().to_string();
+
+        let read = |pointer: &str| Component::read_json_value(file.clone(), pointer.to_string());
+        assert_eq!(read("/servers/0/name").unwrap(), r#""a""#);
+        assert_eq!(read("/a~1b/~0key").unwrap(), "1");
+        assert!(read("/servers/1")
+            .unwrap_err()
+            .contains("does not match any value"));
+
+        let write = |pointer: &str, value: &str| {
+            Component::write_json_value(file.clone(), pointer.to_string(), value.to_string())
+        };
+        write("/servers/0/name", r#""b""#).unwrap();
+        write("/servers/-", r#"{"name": "c"}"#).unwrap();
+        write("/a~1b/~0key", "2").unwrap();
+        write("/new", "[]").unwrap();
+        assert_eq!(read("/servers").unwrap(), r#"[{"name":"b"},{"name":"c"}]"#);
+        assert_eq!(read("/a~1b/~0key").unwrap(), "2");
+        assert_eq!(read("/new").unwrap(), "[]");
+
+        let before = fs::read_to_string(&path).unwrap();
+        assert!(write("/servers/5", "1")
+            .unwrap_err()
+            .contains("not a valid index"));
+        assert!(write("/servers/01", "1")
+            .unwrap_err()
+            .contains("not a valid index"));
+        assert!(write("/missing/key", "1")
+            .unwrap_err()
+            .contains("does not match any value"));
+        assert!(write("/debug/x", "1")
+            .unwrap_err()
+            .contains("does not refer to an object or array"));
+        assert!(write("/debug", "not json")
+            .unwrap_err()
+            .starts_with("Value is not valid JSON"));
+        assert!(write("debug", "true")
+            .unwrap_err()
+            .contains("must be empty or start with '/'"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), before);
+
+        write("", r#"{"replaced": true}"#).unwrap();
+        assert_eq!(read("").unwrap(), r#"{"replaced":true}"#);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_merge_json_files() {
+        let dir = test_dir("merge-json");
+        let base = dir.join("base.json");
+        let overlay = dir.join("override.json");
+        fs::write(
+            &base,
+            r#"{"name": "app", "server": {"host": "localhost", "port": 80}, "plugins": ["a"], "level": {"x": 1}}"#,
+        )
+        .unwrap();
+        fs::write(
+            &overlay,
+            r#"{"server": {"port": 8080, "tls": true}, "plugins": ["b"], "level": 3}"#,
+        )
+        .unwrap();
+
+        let merge = |output: &Path, array_merge: bool| {
+            Component::merge_json_files(
+                base.display().to_string(),
+                overlay.display().to_string(),
+                output.display().to_string(),
+                array_merge,
+            )
+        };
+        let read = |path: &Path| -> Value {
+            serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+        };
+
+        let replaced = dir.join("out/replaced.json");
+        merge(&replaced, false).unwrap();
+        assert_eq!(
+            read(&replaced),
+            serde_json::json!({
+                "name": "app",
+                "server": {"host": "localhost", "port": 8080, "tls": true},
+                "plugins": ["b"],
+                "level": 3
+            })
+        );
+
+        let appended = dir.join("appended.json");
+        merge(&appended, true).unwrap();
+        assert_eq!(read(&appended)["plugins"], serde_json::json!(["a", "b"]));
+
+        fs::write(&overlay, "[1, 2]").unwrap();
+        assert!(merge(&appended, false)
+            .unwrap_err()
+            .contains("must contain a JSON object at the top level"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_move_across_devices_recreates_symlinks() {
+        let dir = test_dir("move-across-devices");
+        let source = dir.join("source");
+        fs::create_dir_all(source.join("sub")).unwrap();
+        fs::write(source.join("sub/file.txt"), "data").unwrap();
+        fs::write(dir.join("outside.txt"), "outside").unwrap();
+        std::os::unix::fs::symlink("sub/file.txt", source.join("relative")).unwrap();
+        std::os::unix::fs::symlink(dir.join("outside.txt"), source.join("sub/absolute")).unwrap();
+        std::os::unix::fs::symlink("missing", source.join("dangling")).unwrap();
+
+        let destination = dir.join("moved");
+        move_across_devices(&source, &destination).unwrap();
+        assert!(!source.exists());
+        assert_eq!(
+            fs::read_to_string(destination.join("sub/file.txt")).unwrap(),
+            "data"
+        );
+        assert_eq!(
+            fs::read_link(destination.join("relative")).unwrap(),
+            Path::new("sub/file.txt")
+        );
+        assert_eq!(
+            fs::read_to_string(destination.join("relative")).unwrap(),
+            "data"
+        );
+        assert_eq!(
+            fs::read_to_string(destination.join("sub/absolute")).unwrap(),
+            "outside"
+        );
+        assert_eq!(
+            fs::read_link(destination.join("dangling")).unwrap(),
+            Path::new("missing")
+        );
+
+        fs::create_dir_all(&source).unwrap();
+        let err = move_across_devices(&source, &destination).unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{err}");
+        assert!(source.exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_zip_and_unzip_round_trip() {
+        let dir = test_dir("zip-round-trip");
+        let source = dir.join("source");
+        fs::create_dir_all(source.join("nested/empty")).unwrap();
+        fs::write(source.join("top.txt"), "top").unwrap();
+        fs::write(source.join("nested/inner.bin"), [0u8, 159, 146, 150]).unwrap();
+
+        let archive = dir.join("out/archive.zip");
+        Component::zip_directory(source.display().to_string(), archive.display().to_string())
+            .unwrap();
+        let message = Component::unzip_to_directory(
+            archive.display().to_string(),
+            dir.join("extracted").display().to_string(),
+        )
+        .unwrap();
+        assert!(message.contains("2 files"), "{message}");
+
+        let extracted = dir.join("extracted");
+        assert_eq!(
+            fs::read_to_string(extracted.join("top.txt")).unwrap(),
+            "top"
+        );
+        assert_eq!(
+            fs::read(extracted.join("nested/inner.bin")).unwrap(),
+            [0u8, 159, 146, 150]
+        );
+        assert!(extracted.join("nested/empty").is_dir());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_unzip_rejects_entries_escaping_destination() {
+        let dir = test_dir("zip-slip");
+        let archive = dir.join("evil.zip");
+        let mut writer = ZipWriter::new(fs::File::create(&archive).unwrap());
+        writer
+            .start_file("safe.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"safe").unwrap();
+        writer
+            .start_file("../evil.txt", SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(b"evil").unwrap();
+        writer.finish().unwrap();
+
+        let destination = dir.join("extracted");
+        let err = Component::unzip_to_directory(
+            archive.display().to_string(),
+            destination.display().to_string(),
+        )
+        .unwrap_err();
+        assert!(
+            err.contains("entry '../evil.txt' would be written outside the destination"),
+            "{err}"
+        );
+        assert!(!dir.join("evil.txt").exists());
+        assert!(!destination.join("safe.txt").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rotate_shifts_backups_and_drops_the_oldest() {
+        let dir = test_dir("rotate");
+        let log = dir.join("app.log");
+        for generation in ["first", "second", "third"] {
+            fs::write(&log, generation).unwrap();
+            assert!(rotate(&log, 2, 2).unwrap().is_some());
+        }
+
+        assert_eq!(fs::read_to_string(&log).unwrap(), "");
+        assert_eq!(fs::read_to_string(dir.join("app.log.1")).unwrap(), "third");
+        assert_eq!(fs::read_to_string(dir.join("app.log.2")).unwrap(), "second");
+        assert!(!dir.join("app.log.3").exists());
+        assert!(!dir.join("app.log.lock").exists());
+        assert_eq!(rotate(&log, 2, 2).unwrap(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_create_hard_link_shares_the_file() {
+        use std::os::unix::fs::MetadataExt;
+
+        let dir = test_dir("hard-link");
+        let source = dir.join("source.txt");
+        fs::write(&source, "shared").unwrap();
+        assert_eq!(fs::metadata(&source).unwrap().nlink(), 1);
+
+        let link = dir.join("nested/link.txt");
+        Component::create_hard_link(source.display().to_string(), link.display().to_string())
+            .unwrap();
+        assert_eq!(fs::metadata(&source).unwrap().nlink(), 2);
+        assert_eq!(fs::read_to_string(&link).unwrap(), "shared");
+
+        assert!(Component::create_hard_link(
+            source.display().to_string(),
+            link.display().to_string()
+        )
+        .is_err());
+        assert!(Component::create_hard_link(
+            dir.display().to_string(),
+            dir.join("dir-link").display().to_string()
+        )
+        .unwrap_err()
+        .contains("is a directory"));
+    }
+
+    #[test]
+    fn test_read_lines() {
+        let dir = test_dir("read_lines");
+        let path = dir.join("lines.txt");
+        fs::write(&path, "  one  \r\n\n   \ntwo\nthree").unwrap();
+        let path = path.display().to_string();
+
+        assert_eq!(
+            Component::read_lines(path.clone(), false, false).unwrap(),
+            vec!["  one  ", "", "   ", "two", "three"]
+        );
+        assert_eq!(
+            Component::read_lines(path.clone(), true, true).unwrap(),
+            vec!["one", "two", "three"]
+        );
+
+        assert_eq!(
+            Component::read_lines_range(path.clone(), 4, 10).unwrap(),
+            vec!["two", "three"]
+        );
+        assert_eq!(
+            Component::read_lines_range(path.clone(), 2, 2).unwrap(),
+            vec![""]
+        );
+        assert!(Component::read_lines_range(path.clone(), 6, 6).is_err());
+        assert!(Component::read_lines_range(path, 0, 1).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_lines() {
+        let dir = test_dir("write_lines");
+        let path = dir.join("nested").join("lines.txt");
+        let lines = vec!["one".to_string(), String::new(), "three".to_string()];
+
+        let message = Component::write_lines(
+            path.display().to_string(),
+            lines.clone(),
+            "crlf".to_string(),
+        )
+        .unwrap();
+        assert!(message.contains("3 lines (14 bytes)"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "one\r\n\r\nthree\r\n");
+        assert_eq!(
+            Component::read_lines(path.display().to_string(), false, false).unwrap(),
+            lines
+        );
+
+        Component::write_lines(path.display().to_string(), lines, "LF".to_string()).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "one\n\nthree\n");
+        assert!(
+            Component::write_lines(path.display().to_string(), vec![], "cr".to_string()).is_err()
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_replace_literal_across_chunks() {
+        // Occurrences straddle the 64 KiB read boundary
+        let mut input = "a".repeat(64 * 1024 - 2).into_bytes();
+        input.extend_from_slice(b"needle needle needle");
+        let mut output = Vec::new();
+        let count = replace_literal(&mut &input[..], &mut output, b"needle", b"pin", 2).unwrap();
+
+        assert_eq!(count, 2);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with("apin pin needle"));
+    }
+
+    #[test]
+    fn test_replace_in_file() {
+        let dir = test_dir("replace_in_file");
+        let path = dir.join("config.toml");
+        fs::write(&path, "port = 80\nhost = \"a\"\nbackup_port = 80\n").unwrap();
+        let path_str = path.display().to_string();
+
+        let message =
+            Component::replace_in_file(path_str.clone(), "80".into(), "8080".into(), 0).unwrap();
+        assert!(message.starts_with("Made 2 replacements"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "port = 8080\nhost = \"a\"\nbackup_port = 8080\n"
+        );
+
+        let message = Component::replace_regex_in_file(
+            path_str.clone(),
+            r"(\w+) = (\d+)".into(),
+            "$1 = [$2]".into(),
+            1,
+        )
+        .unwrap();
+        assert!(message.starts_with("Made 1 replacements"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "port = [8080]\nhost = \"a\"\nbackup_port = 8080\n"
+        );
+
+        let message =
+            Component::replace_in_file(path_str.clone(), "missing".into(), "x".into(), 0).unwrap();
+        assert!(message.starts_with("Made 0 replacements"));
+        assert!(Component::replace_in_file(path_str, String::new(), "x".into(), 0).is_err());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_insert_and_delete_line_at() {
+        let dir = test_dir("insert_delete_line");
+        let path = dir.join("lines.txt");
+        fs::write(&path, "one\r\ntwo\r\nthree").unwrap();
+        let path_str = path.display().to_string();
+
+        Component::insert_line_at(path_str.clone(), 2, "inserted".into()).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "one\r\ninserted\r\ntwo\r\nthree"
+        );
+        Component::insert_line_at(path_str.clone(), 4, "before last".into()).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "one\r\ninserted\r\ntwo\r\nbefore last\nthree"
+        );
+
+        Component::delete_line_at(path_str.clone(), 1).unwrap();
+        Component::delete_line_at(path_str.clone(), 4).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "inserted\r\ntwo\r\nbefore last\n"
+        );
+
+        assert!(Component::delete_line_at(path_str.clone(), 4).is_err());
+        assert!(Component::delete_line_at(path_str.clone(), 0).is_err());
+        assert!(Component::insert_line_at(path_str.clone(), 4, "x".into()).is_err());
+        assert!(Component::insert_line_at(path_str, 1, "a\nb".into()).is_err());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// A core module with a type, a function import from "env", a memory and a memory export
+    const TEST_MODULE: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section: () -> ()
+        0x02, 0x09, 0x01, 0x03, b'e', b'n', b'v', 0x01, b'f', 0x00, 0x00, // import env.f
+        0x05, 0x03, 0x01, 0x00, 0x01, // memory section: one page
+        0x07, 0x07, 0x01, 0x03, b'm', b'e', b'm', 0x02, 0x00, // export memory as "mem"
+    ];
+
+    #[test]
+    fn test_wasm_component_exports() {
+        let dir = test_dir("wasm_exports");
+        let path = dir.join("module.wasm");
+        fs::write(&path, TEST_MODULE).unwrap();
+
+        let exports = Component::wasm_component_exports(path.display().to_string()).unwrap();
+        assert_eq!(exports.len(), 1);
+        assert_eq!(exports[0].name, "mem");
+        assert_eq!(exports[0].kind, "memory");
+
+        fs::write(&path, b"not wasm").unwrap();
+        assert!(Component::wasm_component_exports(path.display().to_string()).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Imports `example:calc/math`, which [`CALCULATOR_WAT`] provides, and `example:log/sink`,
+    /// which nothing here provides
+    const CALCULATOR_CLIENT_WAT: &str = r#"
+        (component
+          (import "example:calc/math" (instance $math
+            (export "add" (func (param "a" u32) (param "b" u32) (result u32)))))
+          (import "example:log/sink" (instance $sink
+            (export "log" (func (param "message" string)))))
+          (alias export $math "add" (func $add))
+          (alias export $sink "log" (func $log))
+          (export "sum" (func $add))
+          (export "log" (func $log)))
+    "#;
+
+    const CALCULATOR_WAT: &str = r#"
+        (component
+          (core module $m
+            (func (export "add") (param i32 i32) (result i32)
+              local.get 0
+              local.get 1
+              i32.add))
+          (core instance $i (instantiate $m))
+          (func $add (param "a" u32) (param "b" u32) (result u32)
+            (canon lift (core func $i "add")))
+          (instance $math (export "add" (func $add)))
+          (export "example:calc/math" (instance $math)))
+    "#;
+
+    #[test]
+    fn test_compose_components() {
+        let dir = test_dir("compose");
+        let client = dir.join("client.wasm");
+        let calculator = dir.join("calculator.wasm");
+        let module = dir.join("module.wasm");
+        fs::write(&client, wat::parse_str(CALCULATOR_CLIENT_WAT).unwrap()).unwrap();
+        fs::write(&calculator, wat::parse_str(CALCULATOR_WAT).unwrap()).unwrap();
+        fs::write(&module, TEST_MODULE).unwrap();
+
+        let compose = |a: &Path, b: &Path, output: &Path| {
+            Component::compose_components(
+                a.display().to_string(),
+                b.display().to_string(),
+                output.display().to_string(),
+            )
+        };
+
+        let output = dir.join("out/composed.wasm");
+        let report = compose(&client, &calculator, &output).unwrap();
+        assert!(
+            report.contains("satisfying: example:calc/math\n"),
+            "{report}"
+        );
+        assert!(
+            report.contains("Remaining imports:\n  instance example:log/sink\n"),
+            "{report}"
+        );
+        let imports = wasm_imports(&output).unwrap();
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].name, "example:log/sink");
+        let exports = wasm_exports(&output).unwrap();
+        assert!(exports.iter().any(|export| export.name == "sum"));
+
+        let err = compose(&calculator, &client, &output).unwrap_err();
+        assert!(err.contains("has no imports to satisfy"), "{err}");
+        let err = compose(&client, &client, &output).unwrap_err();
+        assert!(
+            err.contains("unresolved imports: example:calc/math, example:log/sink"),
+            "{err}"
+        );
+        let err = compose(&client, &module, &output).unwrap_err();
+        assert!(err.contains("is not a WebAssembly component"), "{err}");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_wasm_component_imports() {
+        let dir = test_dir("wasm_imports");
+        let path = dir.join("module.wasm");
+        fs::write(&path, TEST_MODULE).unwrap();
+
+        let imports = Component::wasm_component_imports(path.display().to_string()).unwrap();
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].module, "env");
+        assert_eq!(imports[0].name, "f");
+        assert_eq!(imports[0].kind, "func");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_path_expands_xdg_base_dirs() {
+        let unset: Vec<(&str, Option<&str>)> = XDG_BASE_DIRS
+            .iter()
+            .map(|(name, _)| (*name, None))
+            .chain([("HOME", Some("/home/test"))])
+            .collect();
+        temp_env::with_vars(unset, || {
+            assert_eq!(
+                get_path("$XDG_DATA_HOME/myapp").unwrap(),
+                PathBuf::from("/home/test/.local/share/myapp")
+            );
+            assert_eq!(
+                get_path("$XDG_CONFIG_HOME/myapp/config.toml").unwrap(),
+                PathBuf::from("/home/test/.config/myapp/config.toml")
+            );
+            assert_eq!(
+                get_path("$XDG_CACHE_HOME").unwrap(),
+                PathBuf::from("/home/test/.cache")
+            );
+            assert_eq!(
+                get_path("$XDG_STATE_HOME/log").unwrap(),
+                PathBuf::from("/home/test/.local/state/log")
+            );
+            assert!(get_path("$XDG_RUNTIME_DIR/app.sock")
+                .unwrap_err()
+                .to_string()
+                .contains("not set"));
+        });
+
+        temp_env::with_vars(
+            [
+                ("HOME", Some("/home/test")),
+                ("XDG_DATA_HOME", Some("/data")),
+                ("XDG_CONFIG_HOME", Some("relative/config")),
+                ("XDG_RUNTIME_DIR", Some("/run/user/1000")),
+            ],
+            || {
+                assert_eq!(
+                    get_path("$XDG_DATA_HOME/myapp").unwrap(),
+                    PathBuf::from("/data/myapp")
+                );
+                // Relative values are ignored, as the XDG spec requires
+                assert_eq!(
+                    get_path("$XDG_CONFIG_HOME/myapp").unwrap(),
+                    PathBuf::from("/home/test/.config/myapp")
+                );
+                assert_eq!(
+                    get_path("$XDG_RUNTIME_DIR/app.sock").unwrap(),
+                    PathBuf::from("/run/user/1000/app.sock")
+                );
+                assert_eq!(
+                    get_path("$XDG_DATA_HOMEX/file").unwrap(),
+                    PathBuf::from("$XDG_DATA_HOMEX/file")
+                );
+            },
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_get_path_expands_userprofile() {
+        temp_env::with_vars(
+            [("HOME", None), ("USERPROFILE", Some(r"C:\Users\test"))],
+            || {
+                assert_eq!(get_path("~").unwrap(), PathBuf::from(r"C:\Users\test"));
+                assert_eq!(
+                    get_path(r"~\Documents\notes.txt").unwrap(),
+                    PathBuf::from(r"C:\Users\test\Documents\notes.txt")
+                );
+                assert_eq!(
+                    get_path("~/Documents").unwrap(),
+                    PathBuf::from(r"C:\Users\test\Documents")
+                );
+                assert_eq!(get_path(r"C:\data").unwrap(), PathBuf::from(r"C:\data"));
+            },
+        );
+    }
+}
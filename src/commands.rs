@@ -9,6 +9,7 @@ use clap::{Args, Parser, Subcommand, ValueEnum};
 use serde::{Deserialize, Serialize};
 
 use crate::format::OutputFormat;
+use crate::formatting::OutputStyle;
 
 /// Supported shell types for completion generation
 #[derive(ValueEnum, Clone, Debug)]
@@ -460,8 +461,20 @@ pub enum ToolCommands {
 pub enum RegistryCommands {
     /// Search for components in the registry.
     Search {
-        /// Search query (matches against component name and description)
+        /// Search query (matches against component name, description, URI and tags)
         query: Option<String>,
+        /// Only include components with this tag (case-insensitive)
+        #[arg(long)]
+        tag: Option<String>,
+        /// Include components that have been marked deprecated
+        #[arg(long)]
+        include_deprecated: bool,
+        /// Number of results to skip, for paging
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+        /// Maximum number of results to return
+        #[arg(long)]
+        limit: Option<usize>,
         /// Output format
         #[arg(short = 'o', long = "output-format", default_value = "json")]
         output_format: OutputFormat,
@@ -474,4 +487,40 @@ pub enum RegistryCommands {
         #[arg(long)]
         plugin_dir: Option<PathBuf>,
     },
+    /// List registry components grouped by tag.
+    Tags {
+        /// Output format
+        #[arg(short = 'o', long = "output-format", default_value = "json")]
+        output_format: OutputFormat,
+    },
+    /// Print the registry as a Markdown catalog, or as a list in another style.
+    Export {
+        /// Print a plain list instead of the Markdown catalog
+        #[arg(long)]
+        style: Option<OutputStyle>,
+    },
+    /// Show details about a component in the registry.
+    Info {
+        /// Component name or URI from the registry
+        component: String,
+    },
+    /// Validate a registry file and report every problem found.
+    Validate {
+        /// Path to the registry file, read as TOML or CSV for `.toml` and `.csv` files and as
+        /// JSON otherwise
+        path: PathBuf,
+        /// Also report an error if entries aren't sorted by name, then URI
+        #[arg(long)]
+        require_sorted: bool,
+        /// Output format
+        #[arg(short = 'o', long = "output-format", default_value = "json")]
+        output_format: OutputFormat,
+    },
+    /// Show the components added, removed or changed between two registry JSON files.
+    Diff {
+        /// Path to the old registry JSON file
+        old: PathBuf,
+        /// Path to the new registry JSON file
+        new: PathBuf,
+    },
 }
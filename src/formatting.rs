@@ -0,0 +1,162 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+//! Rendering lists of registry components in the styles the CLI offers
+
+use clap::ValueEnum;
+
+use crate::registry::RegistryComponent;
+
+/// How [`format_components`] renders a component list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputStyle {
+    /// Aligned columns with a header row
+    Table,
+    /// Compact JSON array
+    Json,
+    /// Indented JSON array
+    JsonPretty,
+    /// One `Name (uri)` line per component
+    Plain,
+    /// Tab-separated values with a header row
+    Tsv,
+    /// Comma-separated registry fields, readable by `parse_registry_from_csv`
+    Csv,
+}
+
+const COLUMNS: [&str; 4] = ["NAME", "VERSION", "DESCRIPTION", "URI"];
+
+/// Render `components` in the given style. Every style except the JSON ones ends each line,
+/// including the last, with a newline. CSV uses the registry's own columns, see
+/// [`export_registry_to_csv`](crate::registry::export_registry_to_csv).
+pub fn format_components(components: &[RegistryComponent], style: OutputStyle) -> String {
+    match style {
+        OutputStyle::Json => {
+            serde_json::to_string(components).expect("registry components serialize to JSON")
+        }
+        OutputStyle::JsonPretty => {
+            serde_json::to_string_pretty(components).expect("registry components serialize to JSON")
+        }
+        OutputStyle::Csv => crate::registry::export_registry_to_csv(components),
+        OutputStyle::Plain => components
+            .iter()
+            .map(|c| format!("{} ({})\n", c.name, c.uri))
+            .collect(),
+        OutputStyle::Tsv => {
+            let mut output = COLUMNS.join("\t");
+            output.push('\n');
+            for row in components.iter().map(row) {
+                output.push_str(&row.join("\t"));
+                output.push('\n');
+            }
+            output
+        }
+        OutputStyle::Table => {
+            let rows: Vec<[String; 4]> = components.iter().map(row).collect();
+            let header = COLUMNS.map(str::to_string);
+            let mut widths = COLUMNS.map(|header| header.chars().count());
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+
+            let mut output = String::new();
+            for row in std::iter::once(header).chain(rows) {
+                let line: Vec<String> = row
+                    .iter()
+                    .zip(widths)
+                    .map(|(cell, width)| format!("{cell:<width$}"))
+                    .collect();
+                // Padding the last column would only add trailing spaces
+                output.push_str(line.join("  ").trim_end());
+                output.push('\n');
+            }
+            output
+        }
+    }
+}
+
+/// The cells of a table or TSV row. Tabs and line breaks become spaces, since they would break
+/// the row apart.
+fn row(component: &RegistryComponent) -> [String; 4] {
+    [
+        component.name.as_str(),
+        component.version.as_deref().unwrap_or("-"),
+        component.description.as_str(),
+        component.uri.as_str(),
+    ]
+    .map(|cell| cell.replace(['\t', '\n', '\r'], " "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::parse_registry;
+
+    fn components() -> Vec<RegistryComponent> {
+        parse_registry(
+            r#"[
+                {"name": "Fetch", "description": "Fetch URLs", "uri": "oci://ghcr.io/microsoft/fetch-rs:0.1.0"},
+                {"name": "Weather Server", "description": "Get\tthe weather", "uri": "oci://ghcr.io/microsoft/get-weather-js:latest"}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_format_table_aligns_columns() {
+        assert_eq!(
+            format_components(&components(), OutputStyle::Table),
+            "NAME            VERSION  DESCRIPTION      URI\n\
+             Fetch           0.1.0    Fetch URLs       oci://ghcr.io/microsoft/fetch-rs:0.1.0\n\
+             Weather Server  -        Get the weather  oci://ghcr.io/microsoft/get-weather-js:latest\n"
+        );
+    }
+
+    #[test]
+    fn test_format_tsv_and_plain() {
+        assert_eq!(
+            format_components(&components(), OutputStyle::Tsv),
+            "NAME\tVERSION\tDESCRIPTION\tURI\n\
+             Fetch\t0.1.0\tFetch URLs\toci://ghcr.io/microsoft/fetch-rs:0.1.0\n\
+             Weather Server\t-\tGet the weather\toci://ghcr.io/microsoft/get-weather-js:latest\n"
+        );
+        assert_eq!(
+            format_components(&components(), OutputStyle::Plain),
+            "Fetch (oci://ghcr.io/microsoft/fetch-rs:0.1.0)\n\
+             Weather Server (oci://ghcr.io/microsoft/get-weather-js:latest)\n"
+        );
+    }
+
+    #[test]
+    fn test_format_csv_uses_registry_columns() {
+        assert_eq!(
+            format_components(&components(), OutputStyle::Csv),
+            "name,description,uri,tags,license,homepage,deprecated\n\
+             Fetch,Fetch URLs,oci://ghcr.io/microsoft/fetch-rs:0.1.0,,,,\n\
+             Weather Server,Get\tthe weather,oci://ghcr.io/microsoft/get-weather-js:latest,,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_format_json_round_trips() {
+        for style in [OutputStyle::Json, OutputStyle::JsonPretty] {
+            let json = format_components(&components(), style);
+            let parsed = parse_registry(&json).unwrap();
+            assert_eq!(parsed.len(), 2);
+            assert_eq!(parsed[1].name, "Weather Server");
+        }
+        assert!(!format_components(&components(), OutputStyle::Json).contains('\n'));
+    }
+
+    #[test]
+    fn test_format_empty_list() {
+        assert_eq!(
+            format_components(&[], OutputStyle::Table),
+            "NAME  VERSION  DESCRIPTION  URI\n"
+        );
+        assert_eq!(format_components(&[], OutputStyle::Plain), "");
+        assert_eq!(format_components(&[], OutputStyle::Json), "[]");
+    }
+}
@@ -0,0 +1,1133 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+//! Installing registry components into a local cache of `.wasm` files
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use reqwest::header::{ACCEPT, AUTHORIZATION, WWW_AUTHENTICATE};
+use reqwest::{Client, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{debug, info};
+
+use crate::oci_uri::OciUri;
+use crate::registry::{
+    parse_checksum, parse_version, verify_component_checksum, ChecksumAlgorithm, RegistryComponent,
+};
+
+/// Manifest media types we ask the registry for
+const MANIFEST_MEDIA_TYPES: &str =
+    "application/vnd.oci.image.manifest.v1+json, application/vnd.docker.distribution.manifest.v2+json";
+
+/// Layer media types that hold the component binary
+const WASM_MEDIA_TYPES: &[&str] = &[
+    "application/wasm",
+    "application/vnd.wasm.component.v1",
+    "application/vnd.bytecodealliance.wasm.component.layer.v0+wasm",
+];
+
+/// How many downloaded bytes pass between progress callbacks
+const PROGRESS_INTERVAL: u64 = 64 * 1024;
+
+/// Upper bound on the buffer reserved up front from an untrusted `Content-Length`
+const MAX_PREALLOC: u64 = 16 * 1024 * 1024;
+
+/// A `.wasm` binary found in the component cache
+#[derive(Debug, Clone, Serialize)]
+pub struct InstalledComponent {
+    pub path: PathBuf,
+    pub name: String,
+    pub version: String,
+    /// The registry entry with the same name, or `None` if the registry no longer lists it
+    pub registry_entry: Option<RegistryComponent>,
+}
+
+impl InstalledComponent {
+    /// Whether the registry lists a newer semver version than the one installed
+    #[allow(dead_code)] // Not wired into the CLI yet, for tools that report upgradable components
+    pub fn update_available(&self) -> bool {
+        let installed = parse_version(&self.version);
+        let latest = self
+            .registry_entry
+            .as_ref()
+            .and_then(|entry| entry.semver());
+        matches!((installed, latest), (Some(installed), Some(latest)) if latest > installed)
+    }
+}
+
+/// Install details stored next to each cached binary as `<version>.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheMetadata {
+    /// The URI the binary was installed from
+    pub uri: String,
+    /// When the binary was installed, in seconds since the Unix epoch
+    pub installed_at: u64,
+    /// The registry checksum the binary was verified against, if the registry had one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+}
+
+impl CacheMetadata {
+    /// The sidecar file holding the metadata of the binary at `wasm_path`
+    fn path_for(wasm_path: &Path) -> PathBuf {
+        wasm_path.with_extension("json")
+    }
+
+    /// Read the sidecar of `wasm_path`, or `None` if it is missing or unreadable
+    fn read(wasm_path: &Path) -> Option<Self> {
+        let contents = std::fs::read(Self::path_for(wasm_path)).ok()?;
+        serde_json::from_slice(&contents).ok()
+    }
+}
+
+/// A component cache directory laid out as `<root>/<name>/<version>.wasm`, as populated by
+/// [`install_component`]
+#[derive(Debug, Clone)]
+pub struct ComponentCache {
+    root: PathBuf,
+}
+
+#[allow(dead_code)] // Not wired into the CLI yet, the server still takes a bare plugin directory
+impl ComponentCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Every cached binary, sorted as by [`list_installed_components`]. Registry entries are
+    /// left empty since the cache doesn't know which registry the components came from.
+    pub fn installed(&self) -> Result<Vec<InstalledComponent>> {
+        list_installed_components(&self.root, &[])
+    }
+
+    /// The path of an installed component. The name is compared case-insensitively. With no
+    /// `version` the highest installed version is returned.
+    pub fn get(&self, name: &str, version: Option<&str>) -> Result<Option<PathBuf>> {
+        let mut matching = self
+            .installed()?
+            .into_iter()
+            .filter(|installed| installed.name.eq_ignore_ascii_case(name));
+        let found = match version {
+            Some(version) => matching.find(|installed| installed.version == version),
+            None => matching.next_back(),
+        };
+        Ok(found.map(|installed| installed.path))
+    }
+
+    /// Remove every binary installed more than `max_age` ago, along with its metadata sidecar,
+    /// and return the number of bytes freed. Binaries without a sidecar are aged by their
+    /// modification time. Component directories left empty are removed.
+    pub fn evict_older_than(&self, max_age: Duration) -> Result<u64> {
+        let now = SystemTime::now();
+        let mut freed = 0;
+        for installed in self.installed()? {
+            let metadata = std::fs::metadata(&installed.path)
+                .with_context(|| format!("Failed to read '{}'", installed.path.display()))?;
+            let installed_at = match CacheMetadata::read(&installed.path) {
+                Some(sidecar) => UNIX_EPOCH + Duration::from_secs(sidecar.installed_at),
+                None => metadata.modified()?,
+            };
+            // Install times in the future count as fresh
+            if now.duration_since(installed_at).unwrap_or_default() <= max_age {
+                continue;
+            }
+
+            std::fs::remove_file(&installed.path)
+                .with_context(|| format!("Failed to remove '{}'", installed.path.display()))?;
+            let _ = std::fs::remove_file(CacheMetadata::path_for(&installed.path));
+            freed += metadata.len();
+            info!(
+                component = %installed.name,
+                version = %installed.version,
+                "Evicted component from cache"
+            );
+
+            let name_dir = self.root.join(&installed.name);
+            remove_if_empty(&name_dir)?;
+        }
+        Ok(freed)
+    }
+
+    /// The combined size of every cached binary, in bytes
+    pub fn total_size(&self) -> Result<u64> {
+        self.installed()?
+            .iter()
+            .map(|installed| {
+                std::fs::metadata(&installed.path)
+                    .map(|metadata| metadata.len())
+                    .with_context(|| format!("Failed to read '{}'", installed.path.display()))
+            })
+            .sum()
+    }
+}
+
+/// The integrity of an installed binary, from [`check_component_health`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComponentHealth {
+    /// Whether the file starts with the `\0asm` WebAssembly magic bytes
+    pub valid_magic: bool,
+    /// The registry checksum recorded when the component was installed
+    pub expected_checksum: Option<String>,
+    /// The file's digest as `<algorithm>:<hex>`, using the algorithm of the expected checksum
+    /// or SHA-256 when there is none
+    pub actual_checksum: String,
+    /// Whether the digest matches the expected checksum. With no expected checksum there is
+    /// nothing to contradict, so this is `true`.
+    pub checksum_matches: bool,
+    pub file_size: u64,
+}
+
+/// Check that an installed binary isn't corrupt.
+///
+/// The file must start with the WebAssembly magic bytes. If the registry entry had a
+/// `checksum` when the component was installed, as recorded in the cache metadata sidecar, the
+/// file is hashed and compared against it; otherwise the SHA-256 digest is returned for the
+/// user to record.
+#[allow(dead_code)] // Not wired into the CLI yet, for tools that verify cached binaries
+pub fn check_component_health(path: &Path) -> Result<ComponentHealth> {
+    let bytes =
+        std::fs::read(path).with_context(|| format!("Failed to read '{}'", path.display()))?;
+    let expected_checksum = CacheMetadata::read(path).and_then(|metadata| metadata.checksum);
+
+    let (algorithm, expected_digest) = match &expected_checksum {
+        Some(checksum) => {
+            let (algorithm, digest) = parse_checksum(checksum)
+                .with_context(|| format!("Invalid checksum recorded for '{}'", path.display()))?;
+            (algorithm, Some(digest))
+        }
+        None => (ChecksumAlgorithm::Sha256, None),
+    };
+    let actual_digest = algorithm.hex_digest(&bytes);
+
+    Ok(ComponentHealth {
+        valid_magic: bytes.starts_with(b"\0asm"),
+        checksum_matches: expected_digest
+            .is_none_or(|expected| expected.eq_ignore_ascii_case(&actual_digest)),
+        actual_checksum: format!("{}:{}", algorithm.name(), actual_digest),
+        expected_checksum,
+        file_size: bytes.len() as u64,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImageManifest {
+    #[serde(default)]
+    layers: Vec<Descriptor>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Descriptor {
+    media_type: String,
+    digest: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+/// Download a registry component over the OCI distribution API and store it in the cache.
+///
+/// The binary is written to `cache_dir/<name>/<version>.wasm`, where the version is the
+/// component's `version`, falling back to the URI tag. The layer digest is always checked,
+/// as is the registry `checksum` when one is set. Returns the path of the cached file.
+///
+/// A `file://` URI, such as `file:///abs/component.wasm` or `file://./relative.wasm`, installs a
+/// local build instead by copying it into the cache. Relative paths are resolved against the
+/// current directory, and the version falls back to `latest`.
+///
+/// While the layer downloads, `progress` receives `(bytes_downloaded, total_bytes)` every 64 KB
+/// and once more when the download completes. `total_bytes` is `None` when the registry sent no
+/// `Content-Length`. Local files report their size once.
+#[allow(dead_code)] // Not wired into the CLI yet, there is no `registry install` command
+pub async fn install_component(
+    component: &RegistryComponent,
+    cache_dir: &Path,
+    progress: impl Fn(u64, Option<u64>),
+) -> Result<PathBuf> {
+    if let Some(path) = component.uri.strip_prefix("file://") {
+        return install_local_component(component, Path::new(path), cache_dir, progress).await;
+    }
+
+    let uri = OciUri::parse(&component.uri)?;
+    let destination = install_path(component, Some(&uri), cache_dir)?;
+
+    let registry = uri.registry_host();
+    let scheme = if is_loopback_registry(registry) {
+        "http"
+    } else {
+        "https"
+    };
+    let base_url = format!("{scheme}://{registry}/v2/{}", uri.repository);
+    let client = Client::new();
+
+    info!(component = %component.name, uri = %component.uri, "Fetching component manifest");
+    let (manifest, token) = get_with_anonymous_auth(
+        &client,
+        &format!("{base_url}/manifests/{}", uri.manifest_reference()),
+        Some(MANIFEST_MEDIA_TYPES),
+        None,
+    )
+    .await
+    .context("Failed to fetch OCI manifest")?;
+    let manifest: ImageManifest =
+        serde_json::from_slice(&manifest).context("Failed to parse OCI image manifest")?;
+
+    let layer = manifest
+        .layers
+        .iter()
+        .find(|layer| WASM_MEDIA_TYPES.contains(&layer.media_type.as_str()))
+        .with_context(|| {
+            format!(
+                "OCI manifest for '{}' has no WebAssembly layer",
+                component.uri
+            )
+        })?;
+
+    info!(component = %component.name, digest = %layer.digest, "Downloading component layer");
+    let (response, _) = send_with_anonymous_auth(
+        &client,
+        &format!("{base_url}/blobs/{}", layer.digest),
+        None,
+        token,
+    )
+    .await
+    .context("Failed to download component layer")?;
+    let bytes = read_with_progress(response, progress)
+        .await
+        .context("Failed to download component layer")?;
+
+    let actual = format!("sha256:{:x}", Sha256::digest(&bytes));
+    if actual != layer.digest {
+        bail!(
+            "Layer digest mismatch for '{}': expected {}, got {}",
+            component.uri,
+            layer.digest,
+            actual
+        );
+    }
+    store_in_cache(component, &destination, &bytes).await?;
+    Ok(destination)
+}
+
+/// Copy a local `.wasm` build into the cache, see [`install_component`]
+async fn install_local_component(
+    component: &RegistryComponent,
+    path: &Path,
+    cache_dir: &Path,
+    progress: impl Fn(u64, Option<u64>),
+) -> Result<PathBuf> {
+    if path.as_os_str().is_empty() {
+        bail!("'{}' has no file path", component.uri);
+    }
+    let destination = install_path(component, None, cache_dir)?;
+
+    info!(component = %component.name, path = %path.display(), "Copying local component");
+    let bytes = tokio::fs::read(path)
+        .await
+        .with_context(|| format!("Failed to read component '{}'", path.display()))?;
+    let size = bytes.len() as u64;
+    progress(size, Some(size));
+
+    store_in_cache(component, &destination, &bytes).await?;
+    Ok(destination)
+}
+
+/// Check the registry checksum, if any, then write the binary and its metadata sidecar
+async fn store_in_cache(
+    component: &RegistryComponent,
+    destination: &Path,
+    bytes: &[u8],
+) -> Result<()> {
+    if component.checksum.is_some() {
+        if !verify_component_checksum(component, bytes)? {
+            bail!(
+                "Checksum mismatch for component '{}': the downloaded binary doesn't match the registry",
+                component.name
+            );
+        }
+        debug!(component = %component.name, "Registry checksum verified");
+    }
+
+    write_atomically(destination, bytes).await?;
+    let metadata = CacheMetadata {
+        uri: component.uri.clone(),
+        installed_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        checksum: component.checksum.clone(),
+    };
+    tokio::fs::write(
+        CacheMetadata::path_for(destination),
+        serde_json::to_vec(&metadata)?,
+    )
+    .await
+    .context("Failed to write component cache metadata")?;
+    info!(
+        component = %component.name,
+        path = %destination.display(),
+        bytes = bytes.len(),
+        "Installed component"
+    );
+    Ok(())
+}
+
+/// List the components in a cache populated by [`install_component`].
+///
+/// Each `cache_dir/<name>/<version>.wasm` file is matched to the registry entry of the same
+/// name, compared case-insensitively. Results are sorted by name, then by version with semver
+/// versions in ascending order. A missing cache directory yields an empty list.
+#[allow(dead_code)] // Not wired into the CLI yet, there is no `registry list` command
+pub fn list_installed_components(
+    cache_dir: &Path,
+    registry: &[RegistryComponent],
+) -> Result<Vec<InstalledComponent>> {
+    if !cache_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let read_dir = |dir: &Path| {
+        std::fs::read_dir(dir)
+            .with_context(|| format!("Failed to read cache directory '{}'", dir.display()))
+    };
+
+    let mut installed = Vec::new();
+    for name_entry in read_dir(cache_dir)? {
+        let name_dir = name_entry?.path();
+        if !name_dir.is_dir() {
+            continue;
+        }
+        let Some(name) = name_dir.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let registry_entry = registry
+            .iter()
+            .find(|component| component.name.eq_ignore_ascii_case(name))
+            .cloned();
+
+        for version_entry in read_dir(&name_dir)? {
+            let path = version_entry?.path();
+            if !path.is_file() || path.extension().is_none_or(|ext| ext != "wasm") {
+                continue;
+            }
+            let Some(version) = path.file_stem().and_then(|v| v.to_str()) else {
+                continue;
+            };
+            installed.push(InstalledComponent {
+                name: name.to_string(),
+                version: version.to_string(),
+                registry_entry: registry_entry.clone(),
+                path,
+            });
+        }
+    }
+
+    installed.sort_by(|a, b| {
+        a.name.cmp(&b.name).then_with(|| {
+            match (parse_version(&a.version), parse_version(&b.version)) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                _ => a.version.cmp(&b.version),
+            }
+        })
+    });
+    Ok(installed)
+}
+
+/// Remove a component installed by [`install_component`] from the cache.
+///
+/// With no `version`, every installed version of `name` is removed. A `version` matches an
+/// installed version exactly or as a prefix ending at a `.`, so `1.2` matches `1.2.3`; a
+/// prefix matching several versions is an error listing them. The component's directory is
+/// removed once it holds no more versions.
+#[allow(dead_code)] // Not wired into the CLI yet, there is no `registry uninstall` command
+pub fn uninstall_component(name: &str, version: Option<&str>, cache_dir: &Path) -> Result<()> {
+    let name_dir = cache_dir.join(name);
+    if name.is_empty()
+        || name == "."
+        || name == ".."
+        || name.contains(['/', '\\'])
+        || !name_dir.is_dir()
+    {
+        bail!("Component '{name}' is not installed");
+    }
+
+    let mut installed = Vec::new();
+    for entry in std::fs::read_dir(&name_dir)
+        .with_context(|| format!("Failed to read cache directory '{}'", name_dir.display()))?
+    {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().is_none_or(|ext| ext != "wasm") {
+            continue;
+        }
+        if let Some(installed_version) = path.file_stem().and_then(|v| v.to_str()) {
+            installed.push((installed_version.to_string(), path));
+        }
+    }
+    installed.sort();
+
+    let to_remove: Vec<_> = match version {
+        None => installed.iter().collect(),
+        Some(version) => match installed.iter().find(|(v, _)| v == version) {
+            Some(exact) => vec![exact],
+            None => installed
+                .iter()
+                .filter(|(v, _)| {
+                    v.strip_prefix(version)
+                        .is_some_and(|rest| rest.starts_with('.'))
+                })
+                .collect(),
+        },
+    };
+
+    match (version, to_remove.as_slice()) {
+        (_, []) => {
+            let versions: Vec<_> = installed.iter().map(|(v, _)| v.as_str()).collect();
+            bail!(
+                "Version '{}' of component '{name}' is not installed (installed: {})",
+                version.unwrap_or_default(),
+                if versions.is_empty() {
+                    "none".to_string()
+                } else {
+                    versions.join(", ")
+                }
+            );
+        }
+        (Some(version), matches) if matches.len() > 1 => {
+            let versions: Vec<_> = matches.iter().map(|(v, _)| v.as_str()).collect();
+            bail!(
+                "Version '{version}' of component '{name}' is ambiguous, it matches: {}",
+                versions.join(", ")
+            );
+        }
+        _ => {}
+    }
+
+    for (installed_version, path) in to_remove {
+        std::fs::remove_file(path)
+            .with_context(|| format!("Failed to remove '{}'", path.display()))?;
+        let _ = std::fs::remove_file(CacheMetadata::path_for(path));
+        info!(component = name, version = %installed_version, "Uninstalled component");
+    }
+
+    remove_if_empty(&name_dir)
+}
+
+/// Remove a component's cache directory once it holds no more versions
+fn remove_if_empty(name_dir: &Path) -> Result<()> {
+    let is_empty = std::fs::read_dir(name_dir)
+        .map(|mut entries| entries.next().is_none())
+        .unwrap_or(false);
+    if is_empty {
+        std::fs::remove_dir(name_dir).with_context(|| {
+            format!("Failed to remove cache directory '{}'", name_dir.display())
+        })?;
+        debug!(dir = %name_dir.display(), "Removed empty component cache directory");
+    }
+    Ok(())
+}
+
+/// Where a component is cached: `cache_dir/<name>/<version>.wasm`. Local components have no
+/// OCI URI to take a tag or digest from.
+fn install_path(
+    component: &RegistryComponent,
+    uri: Option<&OciUri>,
+    cache_dir: &Path,
+) -> Result<PathBuf> {
+    let version = component
+        .version
+        .clone()
+        .or_else(|| uri.and_then(|uri| uri.tag.clone()))
+        .or_else(|| uri.and_then(|uri| uri.digest.as_ref().map(|d| d.replace(':', "-"))))
+        .unwrap_or_else(|| "latest".to_string());
+
+    for (label, value) in [("name", &component.name), ("version", &version)] {
+        if value.is_empty() || value == "." || value == ".." || value.contains(['/', '\\']) {
+            bail!("Component {label} '{value}' can't be used as a cache path");
+        }
+    }
+
+    Ok(cache_dir
+        .join(&component.name)
+        .join(format!("{version}.wasm")))
+}
+
+/// GET `url`, answering a bearer challenge with an anonymous token when the registry asks for
+/// one. Returns the body and the token so later requests to the same repository can reuse it.
+async fn get_with_anonymous_auth(
+    client: &Client,
+    url: &str,
+    accept: Option<&str>,
+    token: Option<String>,
+) -> Result<(Vec<u8>, Option<String>)> {
+    let (response, token) = send_with_anonymous_auth(client, url, accept, token).await?;
+    Ok((response.bytes().await?.to_vec(), token))
+}
+
+/// Send a GET request, retrying once with an anonymous bearer token if the registry asks for one.
+/// Returns the successful response along with the token that was used.
+async fn send_with_anonymous_auth(
+    client: &Client,
+    url: &str,
+    accept: Option<&str>,
+    token: Option<String>,
+) -> Result<(Response, Option<String>)> {
+    let send = |token: Option<&str>| {
+        let mut request = client.get(url);
+        if let Some(accept) = accept {
+            request = request.header(ACCEPT, accept);
+        }
+        if let Some(token) = token {
+            request = request.header(AUTHORIZATION, format!("Bearer {token}"));
+        }
+        request.send()
+    };
+
+    let mut token = token;
+    let mut response = send(token.as_deref()).await?;
+    if response.status() == StatusCode::UNAUTHORIZED {
+        let challenge = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|value| value.to_str().ok())
+            .context("Registry requires authentication but sent no bearer challenge")?;
+        debug!(url, "Requesting anonymous registry token");
+        token = Some(fetch_anonymous_token(client, challenge).await?);
+        response = send(token.as_deref()).await?;
+    }
+
+    let response = response
+        .error_for_status()
+        .with_context(|| format!("Request to '{url}' failed"))?;
+    Ok((response, token))
+}
+
+/// Whether `registry` (a host with an optional port) is the local machine, which is reached over
+/// plain HTTP
+fn is_loopback_registry(registry: &str) -> bool {
+    let host = match registry.strip_prefix('[') {
+        Some(rest) => rest.split_once(']').map_or(registry, |(host, _)| host),
+        None => registry.split(':').next().unwrap_or(registry),
+    };
+    matches!(host, "localhost" | "127.0.0.1" | "::1")
+}
+
+/// Read a response body chunk by chunk, reporting progress every [`PROGRESS_INTERVAL`] bytes
+async fn read_with_progress(
+    mut response: Response,
+    progress: impl Fn(u64, Option<u64>),
+) -> Result<Vec<u8>> {
+    let total = response.content_length();
+    let mut bytes = Vec::with_capacity(total.unwrap_or_default().min(MAX_PREALLOC) as usize);
+    let mut reported = 0;
+    while let Some(chunk) = response.chunk().await? {
+        bytes.extend_from_slice(&chunk);
+        let downloaded = bytes.len() as u64;
+        if downloaded - reported >= PROGRESS_INTERVAL {
+            progress(downloaded, total);
+            reported = downloaded;
+        }
+    }
+
+    let downloaded = bytes.len() as u64;
+    if downloaded != reported || downloaded == 0 {
+        progress(downloaded, total);
+    }
+    Ok(bytes)
+}
+
+async fn fetch_anonymous_token(client: &Client, challenge: &str) -> Result<String> {
+    let params = parse_bearer_challenge(challenge)
+        .with_context(|| format!("Unsupported registry auth challenge '{challenge}'"))?;
+    let realm = params
+        .iter()
+        .find(|(key, _)| key == "realm")
+        .map(|(_, value)| value.clone())
+        .context("Registry auth challenge has no realm")?;
+    let query: Vec<_> = params.iter().filter(|(key, _)| key != "realm").collect();
+
+    let body = client
+        .get(&realm)
+        .query(&query)
+        .send()
+        .await?
+        .error_for_status()
+        .context("Failed to fetch anonymous registry token")?
+        .bytes()
+        .await?;
+    let response: TokenResponse =
+        serde_json::from_slice(&body).context("Failed to parse registry token response")?;
+    response
+        .token
+        .or(response.access_token)
+        .context("Registry token response has no token")
+}
+
+/// Parse `Bearer realm="...",service="...",scope="..."` into its key/value pairs
+fn parse_bearer_challenge(challenge: &str) -> Option<Vec<(String, String)>> {
+    let (scheme, params) = challenge.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("bearer") {
+        return None;
+    }
+
+    let mut pairs = Vec::new();
+    let mut rest = params.trim();
+    while !rest.is_empty() {
+        let (key, after_key) = rest.split_once('=')?;
+        let (value, after_value) = match after_key.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"')?;
+                (&quoted[..end], &quoted[end + 1..])
+            }
+            None => after_key.split_once(',').unwrap_or((after_key, "")),
+        };
+        pairs.push((key.trim().to_string(), value.to_string()));
+        rest = after_value.trim_start_matches([',', ' ']);
+    }
+    Some(pairs)
+}
+
+/// Write to a temporary sibling and rename it into place so a failed download never leaves
+/// a truncated binary in the cache
+async fn write_atomically(destination: &Path, bytes: &[u8]) -> Result<()> {
+    let parent = destination
+        .parent()
+        .context("Cache path has no parent directory")?;
+    tokio::fs::create_dir_all(parent)
+        .await
+        .with_context(|| format!("Failed to create cache directory '{}'", parent.display()))?;
+
+    let temp_path = destination.with_extension("wasm.partial");
+    tokio::fs::write(&temp_path, bytes)
+        .await
+        .with_context(|| format!("Failed to write '{}'", temp_path.display()))?;
+    if let Err(e) = tokio::fs::rename(&temp_path, destination).await {
+        let _ = tokio::fs::remove_file(&temp_path).await;
+        return Err(e).with_context(|| format!("Failed to write '{}'", destination.display()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(name: &str, uri: &str, version: Option<&str>) -> RegistryComponent {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "description": "test component",
+            "uri": uri,
+            "version": version,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_is_loopback_registry() {
+        for registry in [
+            "localhost",
+            "localhost:5000",
+            "127.0.0.1:5000",
+            "[::1]:5000",
+        ] {
+            assert!(is_loopback_registry(registry), "{registry}");
+        }
+        for registry in [
+            "ghcr.io",
+            "localhost.attacker.com",
+            "localhost.attacker.com:5000",
+            "127.0.0.1.nip.io",
+            "[::2]:5000",
+        ] {
+            assert!(!is_loopback_registry(registry), "{registry}");
+        }
+    }
+
+    #[test]
+    fn test_install_path_prefers_component_version() {
+        let component = component(
+            "weather",
+            "oci://ghcr.io/microsoft/get-weather-js:latest",
+            Some("1.2.3"),
+        );
+        let uri = OciUri::parse("oci://ghcr.io/microsoft/get-weather-js:latest").unwrap();
+        let path = install_path(&component, Some(&uri), Path::new("/cache")).unwrap();
+        assert_eq!(path, Path::new("/cache/weather/1.2.3.wasm"));
+    }
+
+    #[test]
+    fn test_install_path_falls_back_to_tag() {
+        let component = component(
+            "weather",
+            "oci://ghcr.io/microsoft/get-weather-js:0.4.0",
+            None,
+        );
+        let uri = OciUri::parse("oci://ghcr.io/microsoft/get-weather-js:0.4.0").unwrap();
+        let path = install_path(&component, Some(&uri), Path::new("/cache")).unwrap();
+        assert_eq!(path, Path::new("/cache/weather/0.4.0.wasm"));
+    }
+
+    #[test]
+    fn test_install_path_rejects_path_separators() {
+        let component = component("../escape", "oci://ghcr.io/a/b:1.0.0", None);
+        let uri = OciUri::parse("oci://ghcr.io/a/b:1.0.0").unwrap();
+        assert!(install_path(&component, Some(&uri), Path::new("/cache")).is_err());
+    }
+
+    #[test]
+    fn test_parse_bearer_challenge() {
+        let pairs = parse_bearer_challenge(
+            r#"Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:microsoft/fetch-rs:pull""#,
+        )
+        .unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("realm".to_string(), "https://ghcr.io/token".to_string()),
+                ("service".to_string(), "ghcr.io".to_string()),
+                (
+                    "scope".to_string(),
+                    "repository:microsoft/fetch-rs:pull".to_string()
+                ),
+            ]
+        );
+        assert!(parse_bearer_challenge(r#"Basic realm="registry""#).is_none());
+    }
+
+    #[test]
+    fn test_list_installed_components() {
+        let cache = tempfile::tempdir().unwrap();
+        populate_cache(
+            cache.path(),
+            &[
+                "weather/1.10.0.wasm",
+                "weather/1.2.0.wasm",
+                "weather/1.3.0.wasm.partial",
+                "orphan/0.1.0.wasm",
+            ],
+        );
+        let registry = vec![component(
+            "Weather",
+            "oci://ghcr.io/microsoft/get-weather-js:1.10.0",
+            Some("1.10.0"),
+        )];
+
+        let installed = list_installed_components(cache.path(), &registry).unwrap();
+        let listed: Vec<_> = installed
+            .iter()
+            .map(|c| (c.name.as_str(), c.version.as_str()))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![
+                ("orphan", "0.1.0"),
+                ("weather", "1.2.0"),
+                ("weather", "1.10.0")
+            ]
+        );
+        assert!(installed[0].registry_entry.is_none());
+        assert_eq!(installed[1].path, cache.path().join("weather/1.2.0.wasm"));
+        assert!(installed[1].update_available());
+        assert!(!installed[2].update_available());
+    }
+
+    #[test]
+    fn test_list_installed_components_without_cache() {
+        let cache = tempfile::tempdir().unwrap();
+        let installed = list_installed_components(&cache.path().join("missing"), &[]).unwrap();
+        assert!(installed.is_empty());
+    }
+
+    fn populate_cache(cache: &Path, files: &[&str]) {
+        for file in files {
+            let path = cache.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"\0asm").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_uninstall_component_single_version() {
+        let cache = tempfile::tempdir().unwrap();
+        populate_cache(cache.path(), &["weather/1.2.0.wasm", "weather/1.3.0.wasm"]);
+
+        uninstall_component("weather", Some("1.2.0"), cache.path()).unwrap();
+        assert!(!cache.path().join("weather/1.2.0.wasm").exists());
+        assert!(cache.path().join("weather/1.3.0.wasm").exists());
+
+        uninstall_component("weather", Some("1.3"), cache.path()).unwrap();
+        assert!(!cache.path().join("weather").exists());
+    }
+
+    #[test]
+    fn test_uninstall_component_all_versions() {
+        let cache = tempfile::tempdir().unwrap();
+        populate_cache(
+            cache.path(),
+            &[
+                "weather/1.2.0.wasm",
+                "weather/1.3.0.wasm",
+                "fetch/0.1.0.wasm",
+            ],
+        );
+
+        uninstall_component("weather", None, cache.path()).unwrap();
+        assert!(!cache.path().join("weather").exists());
+        assert!(cache.path().join("fetch/0.1.0.wasm").exists());
+    }
+
+    #[test]
+    fn test_uninstall_component_ambiguous_version() {
+        let cache = tempfile::tempdir().unwrap();
+        populate_cache(cache.path(), &["weather/1.2.0.wasm", "weather/1.2.1.wasm"]);
+
+        let err = uninstall_component("weather", Some("1.2"), cache.path()).unwrap_err();
+        assert!(err.to_string().contains("1.2.0, 1.2.1"), "{err}");
+        assert!(cache.path().join("weather/1.2.0.wasm").exists());
+        assert!(cache.path().join("weather/1.2.1.wasm").exists());
+    }
+
+    #[test]
+    fn test_uninstall_component_not_installed() {
+        let cache = tempfile::tempdir().unwrap();
+        populate_cache(cache.path(), &["weather/1.2.0.wasm"]);
+
+        assert!(uninstall_component("fetch", None, cache.path()).is_err());
+        let err = uninstall_component("weather", Some("2.0.0"), cache.path()).unwrap_err();
+        assert!(err.to_string().contains("installed: 1.2.0"), "{err}");
+    }
+
+    fn write_sidecar(cache: &Path, file: &str, installed_at: u64) {
+        let metadata = CacheMetadata {
+            uri: "oci://ghcr.io/example/component:1.0.0".to_string(),
+            installed_at,
+            checksum: None,
+        };
+        std::fs::write(
+            CacheMetadata::path_for(&cache.join(file)),
+            serde_json::to_vec(&metadata).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_component_cache_get() {
+        let dir = tempfile::tempdir().unwrap();
+        populate_cache(
+            dir.path(),
+            &[
+                "weather/1.2.0.wasm",
+                "weather/1.10.0.wasm",
+                "fetch/0.1.0.wasm",
+            ],
+        );
+        let cache = ComponentCache::new(dir.path());
+
+        assert_eq!(
+            cache.get("Weather", None).unwrap(),
+            Some(dir.path().join("weather/1.10.0.wasm"))
+        );
+        assert_eq!(
+            cache.get("weather", Some("1.2.0")).unwrap(),
+            Some(dir.path().join("weather/1.2.0.wasm"))
+        );
+        assert_eq!(cache.get("weather", Some("2.0.0")).unwrap(), None);
+        assert_eq!(cache.get("time", None).unwrap(), None);
+        assert_eq!(cache.installed().unwrap().len(), 3);
+        assert_eq!(cache.total_size().unwrap(), 12);
+    }
+
+    #[test]
+    fn test_component_cache_evict_older_than() {
+        let dir = tempfile::tempdir().unwrap();
+        populate_cache(
+            dir.path(),
+            &[
+                "weather/1.2.0.wasm",
+                "weather/1.3.0.wasm",
+                "fetch/0.1.0.wasm",
+            ],
+        );
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let day = 24 * 60 * 60;
+        write_sidecar(dir.path(), "weather/1.2.0.wasm", now - 10 * day);
+        write_sidecar(dir.path(), "weather/1.3.0.wasm", now - day);
+        write_sidecar(dir.path(), "fetch/0.1.0.wasm", now - 30 * day);
+        let cache = ComponentCache::new(dir.path());
+
+        let freed = cache
+            .evict_older_than(Duration::from_secs(7 * day))
+            .unwrap();
+        assert_eq!(freed, 8);
+        assert!(!dir.path().join("weather/1.2.0.wasm").exists());
+        assert!(!dir.path().join("weather/1.2.0.json").exists());
+        assert!(dir.path().join("weather/1.3.0.wasm").exists());
+        assert!(!dir.path().join("fetch").exists());
+
+        // Without a sidecar the file's modification time, i.e. now, is used
+        populate_cache(dir.path(), &["fetch/0.2.0.wasm"]);
+        assert_eq!(
+            cache
+                .evict_older_than(Duration::from_secs(2 * day))
+                .unwrap(),
+            0
+        );
+        assert!(dir.path().join("fetch/0.2.0.wasm").exists());
+        assert_eq!(cache.total_size().unwrap(), 8);
+    }
+
+    #[test]
+    fn test_uninstall_component_removes_sidecar() {
+        let cache = tempfile::tempdir().unwrap();
+        populate_cache(cache.path(), &["weather/1.2.0.wasm"]);
+        write_sidecar(cache.path(), "weather/1.2.0.wasm", 0);
+
+        uninstall_component("weather", None, cache.path()).unwrap();
+        assert!(!cache.path().join("weather").exists());
+    }
+
+    #[tokio::test]
+    async fn test_install_component_rejects_non_oci_uri() {
+        let component = component("remote", "https://example.com/component.wasm", None);
+        let cache = tempfile::tempdir().unwrap();
+        let err = install_component(&component, cache.path(), |_, _| {})
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("is not an oci:// URI"));
+    }
+
+    #[tokio::test]
+    async fn test_install_component_from_file_uri() {
+        let source = tempfile::tempdir().unwrap();
+        let wasm = source.path().join("component.wasm");
+        std::fs::write(&wasm, b"\0asm\x01\0\0\0").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut local = component("local", &format!("file://{}", wasm.display()), None);
+        let calls = std::sync::Mutex::new(Vec::new());
+        let installed = install_component(&local, dir.path(), |downloaded, total| {
+            calls.lock().unwrap().push((downloaded, total))
+        })
+        .await
+        .unwrap();
+        assert_eq!(calls.into_inner().unwrap(), [(8, Some(8))]);
+
+        let cache = ComponentCache::new(dir.path());
+        assert_eq!(cache.get("local", None).unwrap(), Some(installed.clone()));
+        assert_eq!(installed, dir.path().join("local/latest.wasm"));
+        assert_eq!(std::fs::read(&installed).unwrap(), b"\0asm\x01\0\0\0");
+        assert_eq!(
+            CacheMetadata::read(&installed).unwrap().uri,
+            local.uri.as_str()
+        );
+
+        // The registry checksum still applies to local files
+        local.version = Some("0.2.0".to_string());
+        local.checksum = Some(format!("sha256:{}", "0".repeat(64)));
+        let err = install_component(&local, dir.path(), |_, _| {})
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Checksum mismatch"), "{err}");
+        assert_eq!(cache.get("local", Some("0.2.0")).unwrap(), None);
+
+        let missing = component("missing", "file://./does-not-exist.wasm", None);
+        assert!(install_component(&missing, dir.path(), |_, _| {})
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_check_component_health() {
+        let source = tempfile::tempdir().unwrap();
+        let wasm = source.path().join("component.wasm");
+        let bytes = b"\0asm\x01\0\0\0";
+        std::fs::write(&wasm, bytes).unwrap();
+        let sha256 = format!("{:x}", Sha256::digest(bytes));
+        let dir = tempfile::tempdir().unwrap();
+
+        // Without a registry checksum the digest is just reported
+        let unchecked = component("unchecked", &format!("file://{}", wasm.display()), None);
+        let installed = install_component(&unchecked, dir.path(), |_, _| {})
+            .await
+            .unwrap();
+        let health = check_component_health(&installed).unwrap();
+        assert!(health.valid_magic);
+        assert_eq!(health.expected_checksum, None);
+        assert_eq!(health.actual_checksum, format!("sha256:{sha256}"));
+        assert!(health.checksum_matches);
+        assert_eq!(health.file_size, 8);
+
+        let mut checked = component("checked", &format!("file://{}", wasm.display()), None);
+        checked.checksum = Some(format!("sha256:{sha256}"));
+        let installed = install_component(&checked, dir.path(), |_, _| {})
+            .await
+            .unwrap();
+        assert!(check_component_health(&installed).unwrap().checksum_matches);
+
+        // Corrupt the cached copy after installation
+        std::fs::write(&installed, b"garbage!").unwrap();
+        let health = check_component_health(&installed).unwrap();
+        assert!(!health.valid_magic);
+        assert!(!health.checksum_matches);
+        assert_eq!(health.expected_checksum, checked.checksum);
+    }
+
+    /// Serve a single HTTP response with `body`, optionally without a `Content-Length` header
+    async fn serve_once(body: Vec<u8>, content_length: bool) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/blob", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0; 1024];
+            let _ = socket.read(&mut request).await.unwrap();
+            let mut head = "HTTP/1.1 200 OK\r\nConnection: close\r\n".to_string();
+            if content_length {
+                head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+            }
+            head.push_str("\r\n");
+            socket.write_all(head.as_bytes()).await.unwrap();
+            socket.write_all(&body).await.unwrap();
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn test_read_with_progress_reports_every_interval() {
+        let size = 3 * PROGRESS_INTERVAL as usize + 100;
+        for content_length in [true, false] {
+            let url = serve_once(vec![7; size], content_length).await;
+            let response = Client::new().get(&url).send().await.unwrap();
+
+            let calls = std::sync::Mutex::new(Vec::new());
+            let bytes = read_with_progress(response, |downloaded, total| {
+                calls.lock().unwrap().push((downloaded, total))
+            })
+            .await
+            .unwrap();
+            assert_eq!(bytes.len(), size);
+
+            let calls = calls.into_inner().unwrap();
+            let total = content_length.then_some(size as u64);
+            assert!(calls.len() >= 2, "{calls:?}");
+            assert_eq!(calls.last(), Some(&(size as u64, total)));
+            for pair in calls.windows(2) {
+                assert!(pair[1].0 > pair[0].0, "{calls:?}");
+            }
+            assert!(calls.iter().all(|&(_, t)| t == total), "{calls:?}");
+        }
+    }
+}
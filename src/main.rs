@@ -21,13 +21,18 @@ mod cli_handlers;
 mod commands;
 mod config;
 mod format;
+mod formatting;
+mod installer;
 mod manifest;
+mod oci_uri;
 mod permission_synthesis;
 mod provisioning_controller;
 mod registry;
+mod registry_fetch;
 mod server;
 mod tools;
 mod utils;
+mod wit_import;
 
 use cli_handlers::{create_lifecycle_manager, handle_tool_cli_command};
 use commands::{
@@ -862,15 +867,106 @@ async fn main() -> Result<()> {
             Commands::Registry { command } => match command {
                 RegistryCommands::Search {
                     query,
+                    tag,
+                    include_deprecated,
+                    offset,
+                    limit,
                     output_format,
                 } => {
+                    let mut components = load_component_registry()?;
+                    if let Some(tag) = tag.as_deref() {
+                        components = registry::search_components_by_tag(&components, tag);
+                    }
+                    let (compatible, incompatible) = registry::filter_compatible_components(
+                        &components,
+                        env!("CARGO_PKG_VERSION"),
+                    );
+                    components = compatible;
+
+                    let hidden = registry::search_components(&incompatible, query.as_deref())?;
+                    if !hidden.is_empty() {
+                        eprintln!(
+                            "Note: {} matching component(s) require a newer version of wassette and were hidden. Upgrade wassette to use them.",
+                            hidden.len()
+                        );
+                    }
+
+                    let mut results = if *include_deprecated {
+                        registry::search_components_including_deprecated(
+                            &components,
+                            query.as_deref(),
+                        )?
+                    } else {
+                        registry::search_components(&components, query.as_deref())?
+                    };
+
+                    // Fall back to typo-tolerant name matching when nothing matched exactly,
+                    // unless operators already narrowed the search to specific fields
+                    if results.is_empty() {
+                        if let Some(q) = query
+                            .as_deref()
+                            .filter(|q| !registry::has_search_operators(q))
+                        {
+                            let fuzzy = if *include_deprecated {
+                                registry::search_components_fuzzy_including_deprecated(
+                                    &components,
+                                    q,
+                                    2,
+                                )
+                            } else {
+                                registry::search_components_fuzzy(&components, q, 2)
+                            };
+                            results = fuzzy
+                                .into_iter()
+                                .map(|component| registry::SearchResult {
+                                    component,
+                                    score: 0,
+                                })
+                                .collect();
+                        }
+                    }
+
+                    let page = registry::paginate(results, *offset, limit.unwrap_or(usize::MAX));
+                    let result = json!({
+                        "status": "success",
+                        "count": page.items.len(),
+                        "total": page.total,
+                        "offset": page.offset,
+                        "has_more": page.has_more,
+                        "components": page.items
+                    });
+
+                    print_result(
+                        &rmcp::model::CallToolResult {
+                            content: Some(vec![rmcp::model::Content::text(
+                                serde_json::to_string_pretty(&result)?,
+                            )]),
+                            structured_content: None,
+                            is_error: None,
+                        },
+                        *output_format,
+                    )?;
+                }
+                RegistryCommands::Tags { output_format } => {
                     let components = load_component_registry()?;
-                    let results = registry::search_components(&components, query.as_deref());
+                    let active: Vec<_> = components
+                        .into_iter()
+                        .filter(|c| !c.is_deprecated())
+                        .collect();
+                    let groups: Map<String, serde_json::Value> =
+                        registry::group_components_by_tag(&active)
+                            .into_iter()
+                            .map(|(tag, components)| {
+                                let names: Vec<String> =
+                                    components.into_iter().map(|c| c.name).collect();
+                                (tag, json!(names))
+                            })
+                            .collect();
 
                     let result = json!({
                         "status": "success",
-                        "count": results.len(),
-                        "components": results
+                        "count": groups.len(),
+                        "tags": groups
                     });
 
                     print_result(
@@ -884,6 +980,90 @@ async fn main() -> Result<()> {
                         *output_format,
                     )?;
                 }
+                RegistryCommands::Export { style } => {
+                    let components = load_component_registry()?;
+                    match style {
+                        Some(style) => {
+                            let mut output = formatting::format_components(&components, *style);
+                            // The JSON styles don't end in a newline
+                            if !output.ends_with('\n') {
+                                output.push('\n');
+                            }
+                            print!("{output}");
+                        }
+                        None => print!("{}", registry::export_registry_to_markdown(&components)),
+                    }
+                }
+                RegistryCommands::Info { component } => {
+                    let components = load_component_registry()?;
+                    let registry_component =
+                        registry::find_component_by_name_or_uri(&components, component)?
+                            .ok_or_else(|| {
+                                anyhow::anyhow!(
+                                    "Component '{}' not found in registry. Use 'wassette registry search' to list available components.",
+                                    component
+                                )
+                            })?;
+                    println!("{}", registry::format_component_detail(&registry_component));
+                }
+                RegistryCommands::Validate {
+                    path,
+                    require_sorted,
+                    output_format,
+                } => {
+                    let mut errors = registry::validate_registry_file(path)?;
+
+                    if *require_sorted {
+                        if let Ok(components) = registry::parse_registry_from_file(path) {
+                            if !registry::is_registry_sorted(&components) {
+                                let mut sorted = components.clone();
+                                registry::sort_registry(&mut sorted);
+                                let index = components
+                                    .iter()
+                                    .zip(&sorted)
+                                    .position(|(a, b)| a.name != b.name || a.uri != b.uri)
+                                    .unwrap_or_default();
+                                errors.push(registry::RegistryValidationError {
+                                    component_index: index,
+                                    field: "name".to_string(),
+                                    message: format!(
+                                        "registry is not sorted by name, expected '{}' here",
+                                        sorted[index].name
+                                    ),
+                                });
+                            }
+                        }
+                    }
+
+                    let result = json!({
+                        "status": if errors.is_empty() { "success" } else { "error" },
+                        "count": errors.len(),
+                        "errors": errors
+                    });
+
+                    print_result(
+                        &rmcp::model::CallToolResult {
+                            content: Some(vec![rmcp::model::Content::text(
+                                serde_json::to_string_pretty(&result)?,
+                            )]),
+                            structured_content: None,
+                            is_error: Some(!errors.is_empty()),
+                        },
+                        *output_format,
+                    )?;
+
+                    if !errors.is_empty() {
+                        std::process::exit(1);
+                    }
+                }
+                RegistryCommands::Diff { old, new } => {
+                    let old = registry::parse_registry_from_file(old)?;
+                    let new = registry::parse_registry_from_file(new)?;
+                    print!(
+                        "{}",
+                        registry::format_diff(&registry::diff_registries(&old, &new))
+                    );
+                }
                 RegistryCommands::Get {
                     component,
                     plugin_dir,
@@ -892,7 +1072,7 @@ async fn main() -> Result<()> {
 
                     // Find the component by name or URI
                     let registry_component =
-                        registry::find_component_by_name_or_uri(&components, component)
+                        registry::find_component_by_name_or_uri(&components, component)?
                             .ok_or_else(|| {
                                 anyhow::anyhow!(
                                     "Component '{}' not found in registry. Use 'wassette registry search' to list available components.",
@@ -900,6 +1080,28 @@ async fn main() -> Result<()> {
                                 )
                             })?;
 
+                    if let Some(min_version) = &registry_component.min_wassette_version {
+                        let (_, incompatible) = registry::filter_compatible_components(
+                            std::slice::from_ref(&registry_component),
+                            env!("CARGO_PKG_VERSION"),
+                        );
+                        if !incompatible.is_empty() {
+                            eprintln!(
+                                "Warning: component '{}' requires wassette {} or newer, but this is {}.",
+                                registry_component.name,
+                                min_version,
+                                env!("CARGO_PKG_VERSION")
+                            );
+                        }
+                    }
+
+                    if registry_component.is_deprecated() {
+                        eprintln!(
+                            "Warning: component '{}' is deprecated and may be removed from the registry.",
+                            registry_component.name
+                        );
+                    }
+
                     // Use the existing load-component functionality
                     let plugin_dir = plugin_dir.clone().or_else(|| cli.component_dir.clone());
                     let lifecycle_manager = create_lifecycle_manager(plugin_dir).await?;
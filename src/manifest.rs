@@ -1,12 +1,17 @@
 // Copyright (c) Microsoft Corporation.
 // Licensed under the MIT license.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
 use std::path::Path;
 
 use anyhow::{bail, Context, Result};
+use figment::providers::{Format, Toml};
+use figment::Figment;
 use serde::{Deserialize, Serialize};
 
+use crate::registry::parse_checksum;
+
 /// Provisioning manifest for headless deployment mode
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProvisioningManifest {
@@ -325,6 +330,249 @@ impl InlinePermissions {
     }
 }
 
+/// Per-project component manifest, usually kept in `wassette.toml` next to the project
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentManifest {
+    /// Components the project uses
+    #[serde(default)]
+    pub components: Vec<ManifestEntry>,
+}
+
+/// A component used by a project, declared as a `[[components]]` table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Local name the project refers to the component by
+    pub alias: String,
+
+    /// Component URI (file://, oci://, https://)
+    pub uri: String,
+
+    /// Optional `sha256:<hex>` or `sha512:<hex>` checksum of the component binary
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+
+    /// Environment variables to set for the component
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+
+    /// Whether `uri` is locked to an `@sha256:` digest by [`pin_component`]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned: bool,
+}
+
+/// Parse a component manifest from TOML
+#[allow(dead_code)] // Not used by the CLI yet, provided for tools that read project manifests
+pub fn parse_component_manifest(toml_str: &str) -> Result<ComponentManifest> {
+    Figment::new()
+        .merge(Toml::string(toml_str))
+        .extract()
+        .context("Failed to parse component manifest TOML")
+}
+
+/// Lock a manifest entry to a digest, like `cargo update --precise`.
+///
+/// Finds the entry whose alias matches `component_name`, ignoring case, appends
+/// `@sha256:<digest>` to its `oci://` URI, replacing any digest already there, sets `pinned`
+/// and writes the manifest back. `digest` is the 64-character hex SHA-256, optionally with its
+/// `sha256:` prefix.
+#[allow(dead_code)] // Not used by the CLI yet, provided for tools that maintain project manifests
+pub fn pin_component(manifest_path: &Path, component_name: &str, digest: &str) -> Result<()> {
+    let digest = digest.strip_prefix("sha256:").unwrap_or(digest);
+    parse_checksum(&format!("sha256:{digest}")).context("Invalid digest")?;
+
+    update_manifest_entry(manifest_path, component_name, |entry| {
+        if !entry.uri.starts_with("oci://") {
+            bail!(
+                "Only oci:// components can be pinned, '{}' uses '{}'",
+                entry.alias,
+                entry.uri
+            );
+        }
+        entry.uri = format!(
+            "{}@sha256:{}",
+            strip_digest(&entry.uri),
+            digest.to_ascii_lowercase()
+        );
+        entry.pinned = true;
+        Ok(())
+    })
+}
+
+/// Undo [`pin_component`], removing the digest from the entry's URI and clearing `pinned`
+#[allow(dead_code)] // Not used by the CLI yet, provided for tools that maintain project manifests
+pub fn unpin_component(manifest_path: &Path, component_name: &str) -> Result<()> {
+    update_manifest_entry(manifest_path, component_name, |entry| {
+        entry.uri = strip_digest(&entry.uri).to_string();
+        entry.pinned = false;
+        Ok(())
+    })
+}
+
+/// A URI without its `@sha256:` digest suffix, if it has one
+fn strip_digest(uri: &str) -> &str {
+    match uri.rsplit_once('@') {
+        Some((reference, digest)) if digest.starts_with("sha256:") => reference,
+        _ => uri,
+    }
+}
+
+/// Apply `update` to the entry with the given alias and rename the rewritten manifest over
+/// `manifest_path`, so an interrupted write never leaves a truncated manifest behind
+fn update_manifest_entry(
+    manifest_path: &Path,
+    component_name: &str,
+    update: impl FnOnce(&mut ManifestEntry) -> Result<()>,
+) -> Result<()> {
+    let contents = std::fs::read_to_string(manifest_path)
+        .with_context(|| format!("Failed to read manifest '{}'", manifest_path.display()))?;
+    let mut manifest = parse_component_manifest(&contents)?;
+    let Some(entry) = manifest
+        .components
+        .iter_mut()
+        .find(|entry| entry.alias.eq_ignore_ascii_case(component_name))
+    else {
+        bail!(
+            "Manifest '{}' has no component named '{}'",
+            manifest_path.display(),
+            component_name
+        );
+    };
+    update(entry)?;
+
+    let contents =
+        toml::to_string(&manifest).context("Failed to serialise component manifest as TOML")?;
+    let mut temp_name = manifest_path.file_name().unwrap_or_default().to_owned();
+    temp_name.push(".tmp");
+    let temp_path = manifest_path.with_file_name(temp_name);
+    std::fs::write(&temp_path, contents)
+        .with_context(|| format!("Failed to write '{}'", temp_path.display()))?;
+    if let Err(e) = std::fs::rename(&temp_path, manifest_path) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(e)
+            .with_context(|| format!("Failed to write manifest '{}'", manifest_path.display()));
+    }
+    Ok(())
+}
+
+/// A problem found in one entry of a component manifest by [`validate_manifest`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ManifestValidationError {
+    /// Position of the offending entry in the `components` array
+    pub component_index: usize,
+    /// The field the problem relates to
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for ManifestValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "component {}: {}: {}",
+            self.component_index, self.field, self.message
+        )
+    }
+}
+
+/// Check a component manifest for every problem at once instead of stopping at the first.
+///
+/// Reports empty or malformed aliases, duplicate aliases (compared case-insensitively), URIs
+/// with an unsupported scheme, malformed checksums, pinned entries without a digest and invalid
+/// environment variable names.
+/// A clean manifest yields an empty vec.
+#[allow(dead_code)] // Not used by the CLI yet, provided for tools that read project manifests
+pub fn validate_manifest(manifest: &ComponentManifest) -> Vec<ManifestValidationError> {
+    let mut errors = Vec::new();
+    let mut error = |component_index: usize, field: &str, message: String| {
+        errors.push(ManifestValidationError {
+            component_index,
+            field: field.to_string(),
+            message,
+        })
+    };
+    let mut first_index_by_alias: HashMap<String, usize> = HashMap::new();
+
+    for (index, entry) in manifest.components.iter().enumerate() {
+        if entry.alias.trim().is_empty() {
+            error(index, "alias", "must not be empty".to_string());
+        } else if !entry
+            .alias
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            error(
+                index,
+                "alias",
+                format!(
+                    "'{}' may only contain letters, digits, '-' and '_'",
+                    entry.alias
+                ),
+            );
+        } else {
+            match first_index_by_alias.get(&entry.alias.to_lowercase()) {
+                Some(first) => error(
+                    index,
+                    "alias",
+                    format!(
+                        "duplicate alias '{}' (first used by component {first})",
+                        entry.alias
+                    ),
+                ),
+                None => {
+                    first_index_by_alias.insert(entry.alias.to_lowercase(), index);
+                }
+            }
+        }
+
+        let valid_schemes = ["file://", "oci://", "https://", "http://"];
+        if entry.uri.trim().is_empty() {
+            error(index, "uri", "must not be empty".to_string());
+        } else if !valid_schemes
+            .iter()
+            .any(|scheme| entry.uri.starts_with(scheme))
+        {
+            error(
+                index,
+                "uri",
+                format!(
+                    "'{}' must start with one of: {}",
+                    entry.uri,
+                    valid_schemes.join(", ")
+                ),
+            );
+        }
+
+        if let Some(checksum) = &entry.checksum {
+            if let Err(e) = parse_checksum(checksum) {
+                error(index, "checksum", e.to_string());
+            }
+        }
+
+        if entry.pinned && strip_digest(&entry.uri) == entry.uri {
+            error(
+                index,
+                "pinned",
+                format!(
+                    "'{}' is marked pinned but has no @sha256: digest",
+                    entry.uri
+                ),
+            );
+        }
+
+        for key in entry.env.keys() {
+            if key.is_empty() || key.contains('=') || key.chars().any(char::is_whitespace) {
+                error(
+                    index,
+                    "env",
+                    format!("'{key}' is not a valid environment variable name"),
+                );
+            }
+        }
+    }
+
+    errors
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -501,4 +749,146 @@ components:
         let manifest = ProvisioningManifest::from_yaml(yaml).unwrap();
         assert!(manifest.validate().is_err());
     }
+
+    #[test]
+    fn test_parse_component_manifest() {
+        let toml = r#"
+[[components]]
+alias = "weather"
+uri = "oci://ghcr.io/microsoft/get-weather-js:1.2.3"
+checksum = "sha256:1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
+
+[components.env]
+OPENWEATHER_API_KEY = "secret"
+
+[[components]]
+alias = "fs"
+uri = "file:///opt/components/filesystem.wasm"
+"#;
+
+        let manifest = parse_component_manifest(toml).unwrap();
+        assert_eq!(manifest.components.len(), 2);
+        assert_eq!(manifest.components[0].alias, "weather");
+        assert_eq!(
+            manifest.components[0].env.get("OPENWEATHER_API_KEY"),
+            Some(&"secret".to_string())
+        );
+        assert_eq!(manifest.components[1].checksum, None);
+        assert!(manifest.components[1].env.is_empty());
+        assert!(validate_manifest(&manifest).is_empty());
+    }
+
+    #[test]
+    fn test_pin_and_unpin_component() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wassette.toml");
+        std::fs::write(
+            &path,
+            r#"
+[[components]]
+alias = "weather"
+uri = "oci://ghcr.io/microsoft/get-weather-js:latest"
+
+[components.env]
+API_KEY = "secret"
+
+[[components]]
+alias = "fs"
+uri = "file:///opt/components/filesystem.wasm"
+"#,
+        )
+        .unwrap();
+        let digest = "AB".repeat(32);
+        let read = || parse_component_manifest(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+        pin_component(&path, "Weather", &digest).unwrap();
+        let manifest = read();
+        let weather = &manifest.components[0];
+        assert_eq!(
+            weather.uri,
+            format!(
+                "oci://ghcr.io/microsoft/get-weather-js:latest@sha256:{}",
+                "ab".repeat(32)
+            )
+        );
+        assert!(weather.pinned);
+        assert_eq!(weather.env.get("API_KEY"), Some(&"secret".to_string()));
+        assert!(validate_manifest(&manifest).is_empty());
+
+        // Pinning again replaces the digest rather than appending a second one
+        pin_component(&path, "weather", &format!("sha256:{}", "cd".repeat(32))).unwrap();
+        assert!(read().components[0]
+            .uri
+            .ends_with(&format!(":latest@sha256:{}", "cd".repeat(32))));
+
+        unpin_component(&path, "weather").unwrap();
+        let weather = &read().components[0];
+        assert_eq!(weather.uri, "oci://ghcr.io/microsoft/get-weather-js:latest");
+        assert!(!weather.pinned);
+
+        assert!(pin_component(&path, "weather", "not-a-digest").is_err());
+        assert!(pin_component(&path, "fs", &digest).is_err());
+        assert!(pin_component(&path, "missing", &digest).is_err());
+        assert!(!dir.path().join("wassette.toml.tmp").exists());
+    }
+
+    #[test]
+    fn test_parse_empty_component_manifest() {
+        let manifest = parse_component_manifest("").unwrap();
+        assert!(manifest.components.is_empty());
+        assert!(validate_manifest(&manifest).is_empty());
+    }
+
+    #[test]
+    fn test_parse_component_manifest_missing_uri() {
+        let toml = r#"
+[[components]]
+alias = "weather"
+"#;
+        assert!(parse_component_manifest(toml).is_err());
+    }
+
+    #[test]
+    fn test_validate_manifest_reports_every_problem() {
+        let toml = r#"
+[[components]]
+alias = "Weather"
+uri = "oci://ghcr.io/microsoft/get-weather-js:1.2.3"
+
+[[components]]
+alias = "weather"
+uri = "ftp://example.com/component.wasm"
+checksum = "md5:abc"
+
+[[components]]
+alias = "bad alias"
+uri = ""
+
+[components.env]
+"BAD KEY" = "value"
+"#;
+
+        let manifest = parse_component_manifest(toml).unwrap();
+        let errors = validate_manifest(&manifest);
+        let fields: Vec<_> = errors
+            .iter()
+            .map(|e| (e.component_index, e.field.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                (1, "alias"),
+                (1, "uri"),
+                (1, "checksum"),
+                (2, "alias"),
+                (2, "uri"),
+                (2, "env"),
+            ]
+        );
+        assert!(errors[0].message.contains("duplicate alias"));
+        assert_eq!(
+            errors[0].to_string(),
+            "component 1: alias: duplicate alias 'weather' (first used by component 0)"
+        );
+    }
 }
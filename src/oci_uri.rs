@@ -0,0 +1,245 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+//! Parsing and building `oci://registry/repository:tag@digest` component URIs
+
+use std::fmt;
+
+use anyhow::{bail, Context, Result};
+
+/// A component URI such as `oci://ghcr.io/microsoft/fetch-rs:0.1.0`.
+///
+/// Unlike a Docker reference, the registry host is always explicit, so parsing and then
+/// displaying a URI gives back the same string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OciUri {
+    /// Registry host, optionally with a port, e.g. `ghcr.io` or `localhost:5000`
+    pub registry: String,
+    /// Repository path within the registry, e.g. `microsoft/fetch-rs`
+    pub repository: String,
+    pub tag: Option<String>,
+    /// Content digest such as `sha256:<hex>`, pinning the exact manifest
+    pub digest: Option<String>,
+}
+
+impl OciUri {
+    /// Parse an `oci://` URI, validating each part
+    pub fn parse(s: &str) -> Result<Self> {
+        let rest = s
+            .strip_prefix("oci://")
+            .with_context(|| format!("'{s}' is not an oci:// URI"))?;
+
+        let (rest, digest) = match rest.split_once('@') {
+            Some((rest, digest)) => (rest, Some(digest)),
+            None => (rest, None),
+        };
+        let (registry, path) = rest
+            .split_once('/')
+            .with_context(|| format!("'{s}' must include a registry and a repository"))?;
+        // A ':' after the last '/' starts the tag; earlier ones belong to the registry port
+        let (repository, tag) = match path.rsplit_once(':') {
+            Some((repository, tag)) if !tag.contains('/') => (repository, Some(tag)),
+            _ => (path, None),
+        };
+
+        if registry.is_empty()
+            || !registry
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'))
+        {
+            bail!("'{s}' has an invalid registry '{registry}'");
+        }
+        if !(registry.contains(['.', ':']) || registry == "localhost") {
+            bail!("'{s}' must start with a registry host such as ghcr.io, found '{registry}'");
+        }
+        if repository.is_empty()
+            || repository.split('/').any(|segment| segment.is_empty())
+            || !repository.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-' | '/')
+            })
+        {
+            bail!(
+                "'{s}' has an invalid repository '{repository}', expected lowercase path segments"
+            );
+        }
+        if let Some(tag) = tag {
+            validate_tag(tag).with_context(|| format!("'{s}' has an invalid tag"))?;
+        }
+        if let Some(digest) = digest {
+            validate_digest(digest).with_context(|| format!("'{s}' has an invalid digest"))?;
+        }
+
+        Ok(Self {
+            registry: registry.to_string(),
+            repository: repository.to_string(),
+            tag: tag.map(str::to_string),
+            digest: digest.map(str::to_string),
+        })
+    }
+
+    /// The same URI with its tag replaced
+    #[allow(dead_code)] // Not used yet, provided for tools that rewrite registry URIs
+    pub fn with_tag(&self, tag: &str) -> Result<Self> {
+        validate_tag(tag)?;
+        Ok(Self {
+            tag: Some(tag.to_string()),
+            ..self.clone()
+        })
+    }
+
+    /// The same URI pinned to `digest`, keeping the tag for readability
+    #[allow(dead_code)] // Not used yet, provided for tools that rewrite registry URIs
+    pub fn with_digest(&self, digest: &str) -> Result<Self> {
+        validate_digest(digest)?;
+        Ok(Self {
+            digest: Some(digest.to_string()),
+            ..self.clone()
+        })
+    }
+
+    /// The reference an OCI client pulls, i.e. the URI without its `oci://` scheme
+    pub fn to_pull_reference(&self) -> String {
+        let mut reference = format!("{}/{}", self.registry, self.repository);
+        if let Some(tag) = &self.tag {
+            reference.push(':');
+            reference.push_str(tag);
+        }
+        if let Some(digest) = &self.digest {
+            reference.push('@');
+            reference.push_str(digest);
+        }
+        reference
+    }
+
+    /// The host serving the registry API; Docker Hub's API lives on a different host
+    pub fn registry_host(&self) -> &str {
+        match self.registry.as_str() {
+            "docker.io" => "index.docker.io",
+            registry => registry,
+        }
+    }
+
+    /// The manifest to request: the digest if pinned, else the tag, else `latest`
+    pub fn manifest_reference(&self) -> &str {
+        self.digest
+            .as_deref()
+            .or(self.tag.as_deref())
+            .unwrap_or("latest")
+    }
+}
+
+impl fmt::Display for OciUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "oci://{}", self.to_pull_reference())
+    }
+}
+
+fn validate_tag(tag: &str) -> Result<()> {
+    let valid = tag.len() <= 128
+        && tag
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+    if !valid {
+        bail!("'{tag}' is not a valid tag");
+    }
+    Ok(())
+}
+
+fn validate_digest(digest: &str) -> Result<()> {
+    let valid = digest.split_once(':').is_some_and(|(algorithm, hex)| {
+        !algorithm.is_empty()
+            && algorithm.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '+' | '.' | '_' | '-')
+            })
+            && hex.len() >= 32
+            && hex.chars().all(|c| c.is_ascii_hexdigit())
+    });
+    if !valid {
+        bail!("'{digest}' is not a valid digest, expected e.g. 'sha256:<hex>'");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
+
+    #[test]
+    fn test_parse_tagged_uri() {
+        let uri = OciUri::parse("oci://ghcr.io/microsoft/fetch-rs:0.1.0").unwrap();
+        assert_eq!(uri.registry, "ghcr.io");
+        assert_eq!(uri.repository, "microsoft/fetch-rs");
+        assert_eq!(uri.tag.as_deref(), Some("0.1.0"));
+        assert_eq!(uri.digest, None);
+        assert_eq!(uri.manifest_reference(), "0.1.0");
+    }
+
+    #[test]
+    fn test_parse_registry_with_port_and_digest() {
+        let uri = OciUri::parse(&format!("oci://localhost:5000/team/app@{DIGEST}")).unwrap();
+        assert_eq!(uri.registry, "localhost:5000");
+        assert_eq!(uri.repository, "team/app");
+        assert_eq!(uri.tag, None);
+        assert_eq!(uri.digest.as_deref(), Some(DIGEST));
+        assert_eq!(uri.manifest_reference(), DIGEST);
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        for s in [
+            "oci://ghcr.io/microsoft/fetch-rs",
+            "oci://ghcr.io/microsoft/fetch-rs:latest",
+            &format!("oci://ghcr.io/microsoft/fetch-rs:1.0.0@{DIGEST}"),
+            "oci://localhost:5000/app:v2",
+        ] {
+            assert_eq!(OciUri::parse(s).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn test_with_tag_and_digest() {
+        let uri = OciUri::parse("oci://ghcr.io/microsoft/fetch-rs:0.1.0").unwrap();
+        let retagged = uri.with_tag("0.2.0").unwrap();
+        assert_eq!(
+            retagged.to_string(),
+            "oci://ghcr.io/microsoft/fetch-rs:0.2.0"
+        );
+
+        let pinned = retagged.with_digest(DIGEST).unwrap();
+        assert_eq!(
+            pinned.to_pull_reference(),
+            format!("ghcr.io/microsoft/fetch-rs:0.2.0@{DIGEST}")
+        );
+
+        assert!(uri.with_tag("bad tag").is_err());
+        assert!(uri.with_digest("sha256:nothex").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_uris() {
+        for s in [
+            "https://ghcr.io/microsoft/fetch-rs",
+            "oci://fetch-rs",
+            "oci://microsoft/fetch-rs:latest",
+            "oci://ghcr.io/Microsoft/fetch-rs",
+            "oci://ghcr.io/microsoft//fetch-rs",
+            "oci://ghcr.io/microsoft/fetch-rs:",
+            "oci://ghcr.io/microsoft/fetch-rs@sha256:abc",
+        ] {
+            assert!(OciUri::parse(s).is_err(), "{s} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_registry_host() {
+        let uri = OciUri::parse("oci://docker.io/library/hello:latest").unwrap();
+        assert_eq!(uri.registry_host(), "index.docker.io");
+        assert_eq!(uri.to_string(), "oci://docker.io/library/hello:latest");
+    }
+}
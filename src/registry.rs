@@ -3,211 +3,2858 @@
 
 //! Registry operations for searching and fetching components from component-registry.json
 
-use anyhow::{Context, Result};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::oci_uri::OciUri;
+
+/// Represents a component in the registry
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegistryComponent {
+    pub name: String,
+    pub description: String,
+    pub uri: String,
+    /// Component version, taken from the `version` key or, if absent, from a semver URI tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Free-form categories such as `weather` or `rust`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Superseded components are hidden from search but can still be fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<bool>,
+    /// Link to the component's documentation or source
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// License of the component, ideally an SPDX identifier such as `MIT`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    /// Oldest wassette release able to run the component
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_wassette_version: Option<String>,
+    /// Expected digest of the component bytes, such as `sha256:<hex>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    /// Person or organisation that publishes the component
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// URL of the component's source repository
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+}
+
+impl RegistryComponent {
+    /// The component version parsed as semver, ignoring a leading `v`
+    pub fn semver(&self) -> Option<semver::Version> {
+        self.version.as_deref().and_then(parse_version)
+    }
+
+    /// Whether the component can run on the given wassette version. Components without a
+    /// minimum version, or with a version that isn't valid semver, are assumed compatible.
+    pub fn is_compatible_with(&self, wassette_version: &semver::Version) -> bool {
+        match self.min_wassette_version.as_deref().and_then(parse_version) {
+            Some(min_version) => *wassette_version >= min_version,
+            None => true,
+        }
+    }
+
+    /// Whether the component is marked deprecated; absent means not deprecated
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+    }
+}
+
+/// Parse the component registry JSON
+pub fn parse_registry(registry_json: &str) -> Result<Vec<RegistryComponent>> {
+    let mut components: Vec<RegistryComponent> =
+        serde_json::from_str(registry_json).context("Failed to parse component registry JSON")?;
+    fill_parsed_components(&mut components);
+    Ok(components)
+}
+
+/// Derive missing versions from URI tags
+fn fill_parsed_components(components: &mut [RegistryComponent]) {
+    for component in components {
+        if component.version.is_none() {
+            component.version = version_from_uri(&component.uri);
+        }
+    }
+}
+
+/// Registry layout in TOML, which needs a table rather than an array at the top level
+#[derive(Serialize)]
+struct TomlRegistryRef<'a> {
+    components: &'a [RegistryComponent],
+}
+
+#[derive(Deserialize)]
+struct TomlRegistry {
+    #[serde(default)]
+    components: Vec<RegistryComponent>,
+}
+
+/// Serialise components as TOML, one `[[components]]` table per component
+pub fn components_to_toml(components: &[RegistryComponent]) -> Result<String> {
+    toml::to_string(&TomlRegistryRef { components })
+        .context("Failed to serialise component registry as TOML")
+}
+
+/// Parse components from the TOML layout written by [`components_to_toml`].
+///
+/// Entries get the same defaults as [`parse_registry`], so a registry reads the same in
+/// either format.
+pub fn components_from_toml(toml_str: &str) -> Result<Vec<RegistryComponent>> {
+    let mut registry: TomlRegistry =
+        toml::from_str(toml_str).context("Failed to parse component registry TOML")?;
+    fill_parsed_components(&mut registry.components);
+    Ok(registry.components)
+}
+
+/// A registry entry as a CSV row. Tags share one `;`-separated cell.
+#[derive(Serialize, Deserialize)]
+struct CsvRow {
+    name: String,
+    description: String,
+    uri: String,
+    #[serde(default)]
+    tags: String,
+    #[serde(default)]
+    license: Option<String>,
+    #[serde(default)]
+    homepage: Option<String>,
+    #[serde(default)]
+    deprecated: Option<bool>,
+}
+
+/// Render components as CSV with a `name,description,uri,tags,license,homepage,deprecated`
+/// header row. Tags are joined with `;`, and absent values leave their cell empty.
+pub fn export_registry_to_csv(components: &[RegistryComponent]) -> String {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for component in components {
+        writer
+            .serialize(CsvRow {
+                name: component.name.clone(),
+                description: component.description.clone(),
+                uri: component.uri.clone(),
+                tags: component.tags.join(";"),
+                license: component.license.clone(),
+                homepage: component.homepage.clone(),
+                deprecated: component.deprecated,
+            })
+            .expect("registry components serialize to CSV");
+    }
+    if components.is_empty() {
+        // Serialising the first row is what normally writes the header
+        writer
+            .write_record([
+                "name",
+                "description",
+                "uri",
+                "tags",
+                "license",
+                "homepage",
+                "deprecated",
+            ])
+            .expect("CSV header writes to memory");
+    }
+    let bytes = writer.into_inner().expect("CSV writes to memory");
+    String::from_utf8(bytes).expect("CSV of UTF-8 strings is UTF-8")
+}
+
+/// Parse components from CSV laid out as by [`export_registry_to_csv`].
+///
+/// Columns are matched by header name, so they may come in any order. `name`, `description`
+/// and `uri` are required; the other columns, and trailing cells of short rows, may be
+/// missing. Entries get the same defaults as [`parse_registry`].
+pub fn parse_registry_from_csv(csv: &str) -> Result<Vec<RegistryComponent>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(csv.as_bytes());
+    let headers = reader
+        .headers()
+        .context("Failed to read registry CSV header")?
+        .clone();
+    let mut components = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        // Row 1 is the header
+        let row_context = || format!("Invalid registry CSV row {}", index + 2);
+        let mut record = record.with_context(row_context)?;
+        while record.len() < headers.len() {
+            record.push_field("");
+        }
+        let row: CsvRow = record
+            .deserialize(Some(&headers))
+            .with_context(row_context)?;
+        components.push(RegistryComponent {
+            name: row.name,
+            description: row.description,
+            uri: row.uri,
+            version: None,
+            tags: row
+                .tags
+                .split(';')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect(),
+            deprecated: row.deprecated,
+            homepage: row.homepage,
+            license: row.license,
+            min_wassette_version: None,
+            checksum: None,
+            author: None,
+            repository: None,
+        });
+    }
+    fill_parsed_components(&mut components);
+    Ok(components)
+}
+
+/// Parse the component registry JSON, keeping only the highest version of each component name.
+///
+/// Names are compared case-insensitively. Entries without a semver version rank below any
+/// versioned entry, and ties keep the entry that appears first. The result preserves the
+/// order in which each name first appears.
+pub fn parse_registry_with_dedup(registry_json: &str) -> Result<Vec<RegistryComponent>> {
+    let mut deduped: Vec<RegistryComponent> = Vec::new();
+    let mut index_by_name: HashMap<String, usize> = HashMap::new();
+
+    for component in parse_registry(registry_json)? {
+        match index_by_name.get(&component.name.to_lowercase()) {
+            Some(&index) => {
+                if component.semver() > deduped[index].semver() {
+                    deduped[index] = component;
+                }
+            }
+            None => {
+                index_by_name.insert(component.name.to_lowercase(), deduped.len());
+                deduped.push(component);
+            }
+        }
+    }
+
+    Ok(deduped)
+}
+
+/// Read and parse a registry file, annotating errors with its path.
+///
+/// Files with a `.toml` extension are read with [`components_from_toml`], `.csv` files with
+/// [`parse_registry_from_csv`], and anything else as JSON.
+pub fn parse_registry_from_file(path: &Path) -> Result<Vec<RegistryComponent>> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read registry file '{}'", path.display()))?;
+    let components = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => components_from_toml(&contents),
+        Some("csv") => parse_registry_from_csv(&contents),
+        _ => parse_registry(&contents),
+    };
+    components.with_context(|| format!("Failed to parse registry file '{}'", path.display()))
+}
+
+/// Check the registry file at `path` with [`validate_registry`].
+///
+/// JSON files are checked as written. `.toml` and `.csv` files are first parsed as for
+/// [`parse_registry_from_file`], so mistyped fields fail the parse with an error, and the parsed
+/// components are then checked the same way as JSON entries.
+pub fn validate_registry_file(path: &Path) -> Result<Vec<RegistryValidationError>> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml" | "csv") => {
+            let components = parse_registry_from_file(path)?;
+            validate_registry(&serde_json::to_string(&components)?)
+        }
+        _ => {
+            let contents = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read registry file '{}'", path.display()))?;
+            validate_registry(&contents)
+        }
+    }
+}
+
+/// Write components to a registry JSON file as pretty-printed JSON with a trailing newline
+#[allow(dead_code)] // Not used by the CLI yet, provided for tools that maintain registry files
+pub fn write_registry_to_file(components: &[RegistryComponent], path: &Path) -> Result<()> {
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create registry file '{}'", path.display()))?;
+    let mut writer = BufWriter::new(file);
+    serde_json::to_writer_pretty(&mut writer, components)
+        .with_context(|| format!("Failed to write registry file '{}'", path.display()))?;
+    writeln!(writer)?;
+    writer
+        .flush()
+        .with_context(|| format!("Failed to write registry file '{}'", path.display()))
+}
+
+/// Add `component` to the registry file at `path`, keeping it in [`sort_registry`] order.
+///
+/// A missing file is treated as an empty registry. Fails without touching the file if a
+/// component with the same name, compared case-insensitively, is already listed. The file
+/// is rewritten atomically in the format its extension selects, as for
+/// [`parse_registry_from_file`].
+#[allow(dead_code)] // Not wired into the CLI yet, adding needs a flag for every registry field
+pub fn add_component_to_registry(path: &Path, component: RegistryComponent) -> Result<()> {
+    let mut components = if path.exists() {
+        parse_registry_from_file(path)?
+    } else {
+        Vec::new()
+    };
+    if let Some(existing) = components
+        .iter()
+        .find(|c| c.name.eq_ignore_ascii_case(&component.name))
+    {
+        bail!(
+            "Registry '{}' already has a component named '{}'",
+            path.display(),
+            existing.name
+        );
+    }
+
+    components.push(component);
+    sort_registry(&mut components);
+    replace_registry_file(&components, path)
+}
+
+/// Remove the component named `name`, compared case-insensitively, from the registry file at
+/// `path` and return it. The file is rewritten atomically, keeping its format.
+#[allow(dead_code)] // Not wired into the CLI yet, provided alongside `add_component_to_registry`
+pub fn remove_component_from_registry(path: &Path, name: &str) -> Result<RegistryComponent> {
+    let mut components = parse_registry_from_file(path)?;
+    let index = components
+        .iter()
+        .position(|c| c.name.eq_ignore_ascii_case(name))
+        .with_context(|| {
+            format!(
+                "Registry '{}' has no component named '{name}'",
+                path.display()
+            )
+        })?;
+
+    let removed = components.remove(index);
+    replace_registry_file(&components, path)?;
+    Ok(removed)
+}
+
+/// Serialise components in the format matching the file extension and rename the result over
+/// `path`, so an interrupted write never leaves a truncated registry behind
+fn replace_registry_file(components: &[RegistryComponent], path: &Path) -> Result<()> {
+    let contents = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => components_to_toml(components)?,
+        Some("csv") => export_registry_to_csv(components),
+        _ => {
+            let mut json = serde_json::to_string_pretty(components)?;
+            json.push('\n');
+            json
+        }
+    };
+
+    let mut temp_name = path.file_name().unwrap_or_default().to_owned();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+    std::fs::write(&temp_path, contents)
+        .with_context(|| format!("Failed to write '{}'", temp_path.display()))?;
+    if let Err(e) = std::fs::rename(&temp_path, path) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(e)
+            .with_context(|| format!("Failed to write registry file '{}'", path.display()));
+    }
+    Ok(())
+}
+
+/// Check `bytes` against the component's `checksum`.
+///
+/// Returns `Ok(false)` on a mismatch. Errors if the component has no checksum or the checksum
+/// isn't `sha256:<hex>` or `sha512:<hex>`.
+pub fn verify_component_checksum(component: &RegistryComponent, bytes: &[u8]) -> Result<bool> {
+    let Some(checksum) = &component.checksum else {
+        bail!("Component '{}' has no checksum", component.name);
+    };
+    let (algorithm, expected) = parse_checksum(checksum)
+        .with_context(|| format!("Invalid checksum for component '{}'", component.name))?;
+
+    Ok(algorithm.hex_digest(bytes).eq_ignore_ascii_case(expected))
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum ChecksumAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl ChecksumAlgorithm {
+    /// The prefix of checksums using this algorithm, as in `sha256:<hex>`
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Sha512 => "sha512",
+        }
+    }
+
+    /// The lowercase hex digest of `bytes`
+    pub(crate) fn hex_digest(self, bytes: &[u8]) -> String {
+        match self {
+            Self::Sha256 => format!("{:x}", Sha256::digest(bytes)),
+            Self::Sha512 => format!("{:x}", Sha512::digest(bytes)),
+        }
+    }
+}
+
+/// Split an `algorithm:hex` checksum and check the digest has the right length
+pub(crate) fn parse_checksum(checksum: &str) -> Result<(ChecksumAlgorithm, &str)> {
+    let Some((algorithm, digest)) = checksum.split_once(':') else {
+        bail!("'{checksum}' must be prefixed with an algorithm, e.g. 'sha256:'");
+    };
+    let (algorithm, hex_len) = match algorithm.to_ascii_lowercase().as_str() {
+        "sha256" => (ChecksumAlgorithm::Sha256, 64),
+        "sha512" => (ChecksumAlgorithm::Sha512, 128),
+        other => bail!("unsupported checksum algorithm '{other}'"),
+    };
+    if digest.len() != hex_len || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("'{digest}' is not a valid hex digest");
+    }
+    Ok((algorithm, digest))
+}
+
+/// Load a registry file from disk, such as a project-local `wassette-registry.json`
+pub fn load_local_registry(path: &Path) -> Result<Vec<RegistryComponent>> {
+    parse_registry_from_file(path)
+}
+
+/// Merge an override registry into a primary one. Local entries win.
+///
+/// An entry in `override_` replaces the `primary` entry with the same name (compared
+/// case-insensitively) in its original position, and entries whose names don't appear in
+/// `primary` are appended in the order they appear in `override_`.
+pub fn merge_registries(
+    primary: &[RegistryComponent],
+    override_: &[RegistryComponent],
+) -> Vec<RegistryComponent> {
+    let mut merged = primary.to_vec();
+    let mut index_by_name: HashMap<String, usize> = merged
+        .iter()
+        .enumerate()
+        .map(|(index, c)| (c.name.to_lowercase(), index))
+        .collect();
+
+    for component in override_ {
+        match index_by_name.get(&component.name.to_lowercase()) {
+            Some(&index) => merged[index] = component.clone(),
+            None => {
+                index_by_name.insert(component.name.to_lowercase(), merged.len());
+                merged.push(component.clone());
+            }
+        }
+    }
+
+    merged
+}
+
+/// A problem found in one entry of a registry file by [`validate_registry`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegistryValidationError {
+    /// Position of the offending entry in the registry array
+    pub component_index: usize,
+    /// The field the problem relates to, or `entry` for the entry as a whole
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for RegistryValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "component {}: {}: {}",
+            self.component_index, self.field, self.message
+        )
+    }
+}
+
+/// Check a registry file for every problem at once instead of stopping at the first.
+///
+/// Reports missing or mistyped fields, empty names and descriptions, URIs that aren't valid
+/// `oci://` references and duplicate names (compared case-insensitively). A clean registry
+/// yields an empty vec. Only JSON that isn't an array at all is returned as an error.
+///
+/// Components with neither an `author` nor a `repository` are valid but logged as a warning,
+/// since there is no way to tell who published them. So are components without a `checksum`,
+/// whose downloads can't be verified; [`audit_registry`] lists them too.
+pub fn validate_registry(registry_json: &str) -> Result<Vec<RegistryValidationError>> {
+    let entries: Value =
+        serde_json::from_str(registry_json).context("Failed to parse component registry JSON")?;
+    let Value::Array(entries) = entries else {
+        bail!("Component registry must be a JSON array");
+    };
+
+    let mut errors = Vec::new();
+    let mut first_index_by_name: HashMap<String, usize> = HashMap::new();
+    let mut error = |component_index: usize, field: &str, message: String| {
+        errors.push(RegistryValidationError {
+            component_index,
+            field: field.to_string(),
+            message,
+        })
+    };
+
+    for (index, entry) in entries.iter().enumerate() {
+        let Value::Object(fields) = entry else {
+            error(index, "entry", "must be a JSON object".to_string());
+            continue;
+        };
+
+        let mut required_string = |field: &str| match fields.get(field) {
+            None => {
+                error(index, field, "missing required field".to_string());
+                None
+            }
+            Some(Value::String(value)) if value.trim().is_empty() => {
+                error(index, field, "must not be empty".to_string());
+                None
+            }
+            Some(Value::String(value)) => Some(value.as_str()),
+            Some(_) => {
+                error(index, field, "must be a string".to_string());
+                None
+            }
+        };
+        let name = required_string("name");
+        required_string("description");
+        let uri = required_string("uri");
+
+        if let Some(name) = name {
+            match first_index_by_name.get(&name.to_lowercase()) {
+                Some(first) => error(
+                    index,
+                    "name",
+                    format!("duplicate name '{name}' (first used by component {first})"),
+                ),
+                None => {
+                    first_index_by_name.insert(name.to_lowercase(), index);
+                }
+            }
+        }
+
+        if let Some(uri) = uri {
+            match uri.strip_prefix("oci://") {
+                None => error(index, "uri", format!("'{uri}' must start with 'oci://'")),
+                Some(reference) => {
+                    if let Err(e) = reference.parse::<oci_client::Reference>() {
+                        error(
+                            index,
+                            "uri",
+                            format!("malformed OCI reference '{uri}': {e}"),
+                        );
+                    }
+                }
+            }
+        }
+
+        match fields.get("version") {
+            None | Some(Value::Null) | Some(Value::String(_)) => {}
+            Some(_) => error(index, "version", "must be a string".to_string()),
+        }
+
+        match fields.get("min_wassette_version") {
+            None | Some(Value::Null) => {}
+            Some(Value::String(version)) if parse_version(version).is_some() => {}
+            Some(Value::String(version)) => error(
+                index,
+                "min_wassette_version",
+                format!("'{version}' is not a valid semver version"),
+            ),
+            Some(_) => error(
+                index,
+                "min_wassette_version",
+                "must be a string".to_string(),
+            ),
+        }
+
+        match fields.get("checksum") {
+            None | Some(Value::Null) => tracing::warn!(
+                component_index = index,
+                name = name.unwrap_or_default(),
+                "Registry component has no checksum, its contents can't be verified"
+            ),
+            Some(Value::String(checksum)) => {
+                if let Err(e) = parse_checksum(checksum) {
+                    error(index, "checksum", e.to_string());
+                }
+            }
+            Some(_) => error(index, "checksum", "must be a string".to_string()),
+        }
+
+        match fields.get("deprecated") {
+            None | Some(Value::Null) | Some(Value::Bool(_)) => {}
+            Some(_) => error(index, "deprecated", "must be a boolean".to_string()),
+        }
+
+        for field in ["homepage", "license", "author", "repository"] {
+            match fields.get(field) {
+                None | Some(Value::Null) | Some(Value::String(_)) => {}
+                Some(_) => error(index, field, "must be a string".to_string()),
+            }
+        }
+
+        // Not an error, but nothing tells users who made the component or where to audit it
+        let has_provenance = ["author", "repository"]
+            .iter()
+            .any(|field| matches!(fields.get(*field), Some(Value::String(_))));
+        if !has_provenance {
+            tracing::warn!(
+                component_index = index,
+                name = name.unwrap_or_default(),
+                "Registry component has neither an author nor a repository"
+            );
+        }
+
+        match fields.get("tags") {
+            None => {}
+            Some(Value::Array(tags)) if tags.iter().all(Value::is_string) => {}
+            Some(_) => error(index, "tags", "must be an array of strings".to_string()),
+        }
+    }
+
+    Ok(errors)
+}
+
+/// Extract a semver tag such as `1.2.3` from a URI like `oci://ghcr.io/foo/bar:1.2.3`
+fn version_from_uri(uri: &str) -> Option<String> {
+    let reference = uri.split('@').next().unwrap_or(uri);
+    let last_segment = reference.rsplit('/').next()?;
+    let (_, tag) = last_segment.rsplit_once(':')?;
+    parse_version(tag).map(|_| tag.to_string())
+}
+
+pub(crate) fn parse_version(version: &str) -> Option<semver::Version> {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    semver::Version::parse(version).ok()
+}
+
+/// A component matched by [`search_components`] together with its relevance score
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchResult {
+    #[serde(flatten)]
+    pub component: RegistryComponent,
+    pub score: u32,
+}
+
+/// One page of a larger result list
+#[derive(Debug, Clone, Serialize)]
+pub struct PagedResult<T> {
+    pub items: Vec<T>,
+    /// Number of results across all pages
+    pub total: usize,
+    /// Index of the first item of this page within all results
+    pub offset: usize,
+    /// Whether results remain after this page
+    pub has_more: bool,
+}
+
+/// Take the page of `items` starting at `offset` with at most `limit` entries
+pub fn paginate<T>(items: Vec<T>, offset: usize, limit: usize) -> PagedResult<T> {
+    let total = items.len();
+    let items: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+    PagedResult {
+        has_more: offset.saturating_add(items.len()) < total,
+        items,
+        total,
+        offset,
+    }
+}
+
+/// Search for non-deprecated components matching a query string, ranked by relevance.
+///
+/// This is [`search_components_paged`] without paging.
+pub fn search_components(
+    components: &[RegistryComponent],
+    query: Option<&str>,
+) -> Result<Vec<SearchResult>> {
+    Ok(search_components_paged(components, query, 0, usize::MAX)?.items)
+}
+
+/// Search for non-deprecated components matching a query string and return one page of the
+/// ranked results.
+///
+/// See [`search_components_including_deprecated`] for how results are scored.
+pub fn search_components_paged(
+    components: &[RegistryComponent],
+    query: Option<&str>,
+    offset: usize,
+    limit: usize,
+) -> Result<PagedResult<SearchResult>> {
+    let active: Vec<RegistryComponent> = components
+        .iter()
+        .filter(|c| !c.is_deprecated())
+        .cloned()
+        .collect();
+    Ok(paginate(
+        search_components_including_deprecated(&active, query)?,
+        offset,
+        limit,
+    ))
+}
+
+/// Search all components, deprecated ones included, ranked by relevance.
+///
+/// Each query term scores `+3` for an exact name match, `+2` for a name prefix, `+1` for any
+/// other name substring, `+1` for a description match, `+1` for a URI match and `+1` for a
+/// tag match. A multi-word query equal to the whole name scores a further `+3`. Components
+/// matching no term are dropped, and results are sorted by descending score, keeping registry
+/// order for ties. Without a query every component is returned with a score of `0`.
+///
+/// Words of the form `field:value` are operators that every result must satisfy rather than
+/// terms to score: `tag:` matches a whole tag, while `name:`, `author:` and `uri:` match part
+/// of that field, all ignoring case. A query made only of operators returns every component
+/// satisfying them with a score of `0`. An unknown operator is an error; a colon followed by
+/// `/`, as in `oci://`, doesn't make an operator.
+pub fn search_components_including_deprecated(
+    components: &[RegistryComponent],
+    query: Option<&str>,
+) -> Result<Vec<SearchResult>> {
+    let query = SearchQuery::parse(query.unwrap_or_default())?;
+    let candidates = components.iter().filter(|c| query.filters_match(c));
+
+    let query_terms = &query.terms;
+    if query_terms.is_empty() {
+        return Ok(candidates
+            .map(|c| SearchResult {
+                component: c.clone(),
+                score: 0,
+            })
+            .collect());
+    }
+    let full_query = query_terms.join(" ");
+
+    let mut results: Vec<SearchResult> = candidates
+        .filter_map(|c| {
+            let score = score_component(c, query_terms, &full_query);
+            // Every matching term scores, so this keeps components matching ANY term
+            (score > 0).then(|| SearchResult {
+                component: c.clone(),
+                score,
+            })
+        })
+        .collect();
+
+    // The sort is stable, so equally scored components keep their registry order
+    results.sort_by_key(|r| Reverse(r.score));
+    Ok(results)
+}
+
+/// A field a search operator such as `tag:rust` filters on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchField {
+    Tag,
+    Author,
+    Name,
+    Uri,
+}
+
+/// A search query split into `field:value` operators and lowercased full-text terms
+#[derive(Debug, Default)]
+struct SearchQuery {
+    filters: Vec<(SearchField, String)>,
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    fn parse(query: &str) -> Result<Self> {
+        let mut parsed = Self::default();
+        for word in query.split_whitespace() {
+            let word = word.to_lowercase();
+            let operator = word.split_once(':').filter(|(field, value)| {
+                !field.is_empty()
+                    && field.chars().all(|c| c.is_ascii_alphabetic())
+                    && !value.starts_with('/')
+            });
+            let Some((field, value)) = operator else {
+                parsed.terms.push(word);
+                continue;
+            };
+
+            let field = match field {
+                "tag" => SearchField::Tag,
+                "author" => SearchField::Author,
+                "name" => SearchField::Name,
+                "uri" => SearchField::Uri,
+                other => bail!(
+                    "Unknown search operator '{other}:', expected one of tag:, author:, name: or uri:"
+                ),
+            };
+            if value.is_empty() {
+                bail!("Search operator '{word}' needs a value, e.g. '{word}weather'");
+            }
+            parsed.filters.push((field, value.to_string()));
+        }
+        Ok(parsed)
+    }
+
+    /// Whether `component` satisfies every operator in the query
+    fn filters_match(&self, component: &RegistryComponent) -> bool {
+        let contains = |field: &str, value: &str| field.to_lowercase().contains(value);
+        self.filters.iter().all(|(field, value)| match field {
+            SearchField::Tag => component.tags.iter().any(|t| t.to_lowercase() == *value),
+            SearchField::Author => component
+                .author
+                .as_deref()
+                .is_some_and(|author| contains(author, value)),
+            SearchField::Name => contains(&component.name, value),
+            SearchField::Uri => contains(&component.uri, value),
+        })
+    }
+}
+
+/// Score a component against lowercased query terms as described in
+/// [`search_components_including_deprecated`]
+fn score_component(component: &RegistryComponent, query_terms: &[String], full_query: &str) -> u32 {
+    // Pre-compute lowercase versions once per component
+    let name_lower = component.name.to_lowercase();
+    let desc_lower = component.description.to_lowercase();
+    let uri_lower = component.uri.to_lowercase();
+    let tags_lower: Vec<String> = component.tags.iter().map(|t| t.to_lowercase()).collect();
+
+    let mut score = 0;
+    for term in query_terms {
+        if name_lower == *term {
+            score += 3;
+        } else if name_lower.starts_with(term.as_str()) {
+            score += 2;
+        } else if name_lower.contains(term.as_str()) {
+            score += 1;
+        }
+        if desc_lower.contains(term.as_str()) {
+            score += 1;
+        }
+        if uri_lower.contains(term.as_str()) {
+            score += 1;
+        }
+        if tags_lower.iter().any(|t| t.contains(term.as_str())) {
+            score += 1;
+        }
+    }
+    let name_terms: Vec<&str> = name_lower.split_whitespace().collect();
+    if query_terms.len() > 1 && name_terms.join(" ") == full_query {
+        score += 3;
+    }
+    score
+}
+
+/// A component found in one of several registries, with the label of the registry it came from
+#[derive(Debug, Clone, Serialize)]
+pub struct LabelledComponent {
+    #[serde(flatten)]
+    pub component: RegistryComponent,
+    /// Label of the source registry, such as `local` or `upstream`
+    pub source: String,
+}
+
+impl fmt::Display for LabelledComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}) {}", self.source, self.component.name)
+    }
+}
+
+/// Search several labelled registries at once, tagging each result with its source.
+///
+/// Each `(label, components)` source is searched like [`search_components`], and the results
+/// are merged by descending score. Ties keep the order of `sources`, then registry order, so a
+/// component listed by two registries appears once per registry, earlier sources first.
+#[allow(dead_code)] // Not wired into the CLI yet, `registry search` still merges registries first
+pub fn search_labelled(
+    sources: &[(&str, &[RegistryComponent])],
+    query: Option<&str>,
+) -> Result<Vec<LabelledComponent>> {
+    let mut results: Vec<(u32, LabelledComponent)> = Vec::new();
+    for (label, components) in sources {
+        for result in search_components(components, query)? {
+            let labelled = LabelledComponent {
+                component: result.component,
+                source: label.to_string(),
+            };
+            results.push((result.score, labelled));
+        }
+    }
+
+    // The sort is stable, so equal scores keep source order
+    results.sort_by_key(|(score, _)| Reverse(*score));
+    Ok(results.into_iter().map(|(_, labelled)| labelled).collect())
+}
+
+/// Rank non-deprecated components against `query` with a TF-IDF style score.
+///
+/// Names and descriptions are split into lowercased words. Each query word found in a
+/// component adds its term frequency there weighted by `ln(1 + n / df)`, where `n` is the
+/// number of components and `df` the number containing the word, so rare words count for more
+/// than ones shared by most of the registry. Components matching no query word are dropped, and
+/// results are sorted by descending score, keeping registry order for ties.
+#[allow(dead_code)] // Not wired into the CLI yet, `registry search` still uses the fixed-weight score
+pub fn search_components_ranked(
+    components: &[RegistryComponent],
+    query: &str,
+) -> Vec<(RegistryComponent, f64)> {
+    let active: Vec<&RegistryComponent> =
+        components.iter().filter(|c| !c.is_deprecated()).collect();
+
+    // Inverted index from each word to the components containing it and how often
+    let mut postings: HashMap<String, HashMap<usize, u32>> = HashMap::new();
+    for (position, component) in active.iter().enumerate() {
+        let text = format!("{} {}", component.name, component.description);
+        for word in index_words(&text) {
+            *postings
+                .entry(word)
+                .or_default()
+                .entry(position)
+                .or_default() += 1;
+        }
+    }
+
+    let mut query_words: Vec<String> = index_words(query).collect();
+    query_words.sort();
+    query_words.dedup();
+
+    let corpus_size = active.len() as f64;
+    let mut scores: BTreeMap<usize, f64> = BTreeMap::new();
+    for word in &query_words {
+        let Some(frequencies) = postings.get(word) else {
+            continue;
+        };
+        let idf = (1.0 + corpus_size / frequencies.len() as f64).ln();
+        for (&position, &tf) in frequencies {
+            *scores.entry(position).or_default() += f64::from(tf) * idf;
+        }
+    }
+
+    // Scores are collected in registry order and the sort is stable, so ties keep that order
+    let mut results: Vec<(RegistryComponent, f64)> = scores
+        .into_iter()
+        .map(|(position, score)| (active[position].clone(), score))
+        .collect();
+    results.sort_by(|a, b| b.1.total_cmp(&a.1));
+    results
+}
+
+/// Split components into those that run on `current_version` and those that need a newer
+/// wassette, so the caller can hide the latter and suggest an upgrade.
+///
+/// Versions are compared as semver. If `current_version` isn't valid semver every component
+/// is treated as compatible.
+pub fn filter_compatible_components(
+    components: &[RegistryComponent],
+    current_version: &str,
+) -> (Vec<RegistryComponent>, Vec<RegistryComponent>) {
+    let Some(current_version) = parse_version(current_version) else {
+        return (components.to_vec(), Vec::new());
+    };
+    components
+        .iter()
+        .cloned()
+        .partition(|c| c.is_compatible_with(&current_version))
+}
+
+/// Return the components carrying `tag`, compared case-insensitively against each whole tag
+pub fn search_components_by_tag(
+    components: &[RegistryComponent],
+    tag: &str,
+) -> Vec<RegistryComponent> {
+    let tag = tag.trim();
+    components
+        .iter()
+        .filter(|c| c.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        .cloned()
+        .collect()
+}
+
+/// Group components by tag for display. Components appear under every tag they carry, tags
+/// are lowercased so differently cased tags share a bucket, and components without tags are
+/// collected under `"untagged"`.
+pub fn group_components_by_tag(
+    components: &[RegistryComponent],
+) -> BTreeMap<String, Vec<RegistryComponent>> {
+    let mut groups: BTreeMap<String, Vec<RegistryComponent>> = BTreeMap::new();
+
+    for component in components {
+        if component.tags.is_empty() {
+            groups
+                .entry("untagged".to_string())
+                .or_default()
+                .push(component.clone());
+            continue;
+        }
+
+        let tags: BTreeSet<String> = component.tags.iter().map(|t| t.to_lowercase()).collect();
+        for tag in tags {
+            groups.entry(tag).or_default().push(component.clone());
+        }
+    }
+
+    groups
+}
+
+/// Render the registry as a Markdown catalog.
+///
+/// Produces a table with Name, Description, URI, Tags and License columns, plus Author and
+/// Repository columns when any component has them, using `—` for absent values. When any
+/// component has tags, one `##` section per tag is emitted (see [`group_components_by_tag`])
+/// instead of a single table.
+pub fn export_registry_to_markdown(components: &[RegistryComponent]) -> String {
+    // Decided once so every tag section has the same columns
+    let provenance = components
+        .iter()
+        .any(|c| c.author.is_some() || c.repository.is_some());
+    if components.iter().all(|c| c.tags.is_empty()) {
+        return markdown_table(components, provenance);
+    }
+
+    group_components_by_tag(components)
+        .iter()
+        .map(|(tag, components)| {
+            format!("## {}\n\n{}", tag, markdown_table(components, provenance))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A table of components, with Author and Repository columns if `provenance` is set
+fn markdown_table(components: &[RegistryComponent], provenance: bool) -> String {
+    let mut table = if provenance {
+        String::from(
+            "| Name | Description | URI | Tags | License | Author | Repository |\n\
+             | --- | --- | --- | --- | --- | --- | --- |\n",
+        )
+    } else {
+        String::from(
+            "| Name | Description | URI | Tags | License |\n\
+             | --- | --- | --- | --- | --- |\n",
+        )
+    };
+    for c in components {
+        let tags = if c.tags.is_empty() {
+            "—".to_string()
+        } else {
+            markdown_cell(&c.tags.join(", "))
+        };
+        let license = c.license.as_deref().map_or("—".to_string(), markdown_cell);
+        table.push_str(&format!(
+            "| {} | {} | `{}` | {} | {} |",
+            markdown_cell(&c.name),
+            markdown_cell(&c.description),
+            c.uri,
+            tags,
+            license
+        ));
+        if provenance {
+            table.push_str(&format!(
+                " {} | {} |",
+                c.author.as_deref().map_or("—".to_string(), markdown_cell),
+                c.repository
+                    .as_deref()
+                    .map_or("—".to_string(), markdown_cell)
+            ));
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Escape text so it stays inside a single Markdown table cell
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Search for non-deprecated components whose name is within `max_distance` edits of the query.
+///
+/// See [`search_components_fuzzy_including_deprecated`] for how names are matched.
+pub fn search_components_fuzzy(
+    components: &[RegistryComponent],
+    query: &str,
+    max_distance: usize,
+) -> Vec<RegistryComponent> {
+    let active: Vec<RegistryComponent> = components
+        .iter()
+        .filter(|c| !c.is_deprecated())
+        .cloned()
+        .collect();
+    search_components_fuzzy_including_deprecated(&active, query, max_distance)
+}
+
+/// Search for components, deprecated ones included, whose name is within `max_distance` edits
+/// of the query.
+///
+/// Each query term is compared against every whitespace-, `-`- or `_`-separated token of each
+/// component name using case-insensitive Levenshtein distance, and a component's distance is
+/// the smallest over all term/token pairs. Results are sorted by ascending distance, keeping
+/// registry order for ties.
+///
+/// This costs O(n·k·q) edit-distance computations for n components, k name tokens and q query
+/// terms, so callers searching repeatedly should cache the component list rather than reload it.
+pub fn search_components_fuzzy_including_deprecated(
+    components: &[RegistryComponent],
+    query: &str,
+    max_distance: usize,
+) -> Vec<RegistryComponent> {
+    let query_terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| term.to_lowercase())
+        .collect();
+
+    if query_terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<(usize, &RegistryComponent)> = components
+        .iter()
+        .filter_map(|c| {
+            let name_lower = c.name.to_lowercase();
+            name_lower
+                .split(|ch: char| ch.is_whitespace() || ch == '-' || ch == '_')
+                .filter(|token| !token.is_empty())
+                .flat_map(|token| query_terms.iter().map(move |term| levenshtein(term, token)))
+                .min()
+                .filter(|distance| *distance <= max_distance)
+                .map(|distance| (distance, c))
+        })
+        .collect();
+
+    matches.sort_by_key(|(distance, _)| *distance);
+    matches.into_iter().map(|(_, c)| c.clone()).collect()
+}
+
+/// Whether a search query holds any `field:value` operator words.
+///
+/// A query that fails to parse, e.g. because of an unknown operator, is reported as having none.
+pub fn has_search_operators(query: &str) -> bool {
+    SearchQuery::parse(query).is_ok_and(|parsed| !parsed.filters.is_empty())
+}
+
+/// Levenshtein edit distance between two strings, counted in chars
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+
+    // Only the previous row of the DP table is needed to compute the next one
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Format every available field of a component as `Label: value` lines for display
+pub fn format_component_detail(component: &RegistryComponent) -> String {
+    let mut lines = vec![
+        format!("Name: {}", component.name),
+        format!("Description: {}", component.description),
+        format!("URI: {}", component.uri),
+    ];
+    if let Some(version) = &component.version {
+        lines.push(format!("Version: {version}"));
+    }
+    if !component.tags.is_empty() {
+        lines.push(format!("Tags: {}", component.tags.join(", ")));
+    }
+    if let Some(homepage) = &component.homepage {
+        lines.push(format!("Homepage: {homepage}"));
+    }
+    if let Some(license) = &component.license {
+        lines.push(format!("License: {license}"));
+    }
+    if let Some(author) = &component.author {
+        lines.push(format!("Author: {author}"));
+    }
+    if let Some(repository) = &component.repository {
+        lines.push(format!("Repository: {repository}"));
+    }
+    if let Some(min_version) = &component.min_wassette_version {
+        lines.push(format!("Requires wassette: >= {min_version}"));
+    }
+    if let Some(checksum) = &component.checksum {
+        lines.push(format!("Checksum: {checksum}"));
+    }
+    if component.is_deprecated() {
+        lines.push("Deprecated: yes".to_string());
+    }
+    lines.join("\n")
+}
+
+/// Find every component whose name matches `name`, ignoring case
+pub fn find_all_components_by_name(
+    components: &[RegistryComponent],
+    name: &str,
+) -> Vec<RegistryComponent> {
+    components
+        .iter()
+        .filter(|c| c.name.eq_ignore_ascii_case(name))
+        .cloned()
+        .collect()
+}
+
+/// Find a component by name or URI.
+///
+/// Names take precedence over URIs. If more than one component has the given name, an error
+/// listing their URIs is returned so the caller can ask for a URI instead. Deprecated
+/// components are still returned; check [`RegistryComponent::is_deprecated`] to warn the user
+/// about them.
+pub fn find_component_by_name_or_uri(
+    components: &[RegistryComponent],
+    name_or_uri: &str,
+) -> Result<Option<RegistryComponent>> {
+    let mut by_name = find_all_components_by_name(components, name_or_uri);
+    if by_name.len() > 1 {
+        let uris: Vec<&str> = by_name.iter().map(|c| c.uri.as_str()).collect();
+        bail!(
+            "Component name '{}' is ambiguous, matching {} entries: {}. Use the URI instead.",
+            name_or_uri,
+            by_name.len(),
+            uris.join(", ")
+        );
+    }
+    if let Some(component) = by_name.pop() {
+        return Ok(Some(component));
+    }
+
+    Ok(components.iter().find(|c| c.uri == name_or_uri).cloned())
+}
+
+/// Sort components into canonical order: by name ignoring case, then by URI, so registry
+/// files edited by several contributors diff cleanly
+pub fn sort_registry(components: &mut [RegistryComponent]) {
+    components.sort_by(canonical_order);
+}
+
+/// Whether components are already in the order [`sort_registry`] produces
+pub fn is_registry_sorted(components: &[RegistryComponent]) -> bool {
+    components.is_sorted_by(|a, b| canonical_order(a, b) != Ordering::Greater)
+}
+
+fn canonical_order(a: &RegistryComponent, b: &RegistryComponent) -> Ordering {
+    a.name
+        .to_lowercase()
+        .cmp(&b.name.to_lowercase())
+        .then_with(|| a.uri.cmp(&b.uri))
+}
+
+/// What changed between two registry snapshots, as computed by [`diff_registries`]
+#[derive(Debug, Clone, Default)]
+pub struct RegistryDiff {
+    pub added: Vec<RegistryComponent>,
+    pub removed: Vec<RegistryComponent>,
+    /// `(old, new)` pairs whose URI or description changed
+    pub changed: Vec<(RegistryComponent, RegistryComponent)>,
+}
+
+impl RegistryDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compare two registry snapshots, matching components by name (case-insensitive).
+///
+/// A component counts as changed when its URI or description differs. Added and changed
+/// components are listed in `new` order, removed ones in `old` order. If a name appears more
+/// than once in a snapshot, only its first entry is compared.
+pub fn diff_registries(old: &[RegistryComponent], new: &[RegistryComponent]) -> RegistryDiff {
+    let first_by_name = |components: &[RegistryComponent]| {
+        let mut by_name: HashMap<String, usize> = HashMap::new();
+        for (index, component) in components.iter().enumerate() {
+            by_name
+                .entry(component.name.to_lowercase())
+                .or_insert(index);
+        }
+        by_name
+    };
+    let old_by_name = first_by_name(old);
+    let new_by_name = first_by_name(new);
+
+    let mut diff = RegistryDiff::default();
+    for (index, component) in new.iter().enumerate() {
+        let name = component.name.to_lowercase();
+        if new_by_name[&name] != index {
+            continue;
+        }
+        match old_by_name.get(&name) {
+            None => diff.added.push(component.clone()),
+            Some(&old_index) => {
+                let previous = &old[old_index];
+                if previous.uri != component.uri || previous.description != component.description {
+                    diff.changed.push((previous.clone(), component.clone()));
+                }
+            }
+        }
+    }
+    diff.removed = old
+        .iter()
+        .enumerate()
+        .filter(|(index, c)| {
+            let name = c.name.to_lowercase();
+            old_by_name[&name] == *index && !new_by_name.contains_key(&name)
+        })
+        .map(|(_, c)| c.clone())
+        .collect();
+    diff
+}
+
+/// Components flagged by [`audit_registry`]. A component appears in every list that applies.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AuditReport {
+    /// `oci://` components referenced by a tag rather than pinned to an `@sha256:` digest, so
+    /// the bytes behind them can change without the registry changing
+    pub unpinned: Vec<RegistryComponent>,
+    /// Components without a `checksum` to verify downloads against
+    pub no_checksum: Vec<RegistryComponent>,
+    pub deprecated: Vec<RegistryComponent>,
+}
+
+/// Report reproducibility and trust risks in a registry, without any I/O.
+///
+/// A CI step can run this after [`parse_registry`] and fail when `unpinned` is non-empty.
+/// URIs that don't parse as OCI references count as unpinned, since nothing pins them either;
+/// `file://` and other non-OCI URIs are not tags and are left out of `unpinned`.
+#[allow(dead_code)] // Not wired into the CLI yet, provided for CI checks over registry files
+pub fn audit_registry(components: &[RegistryComponent]) -> AuditReport {
+    let mut report = AuditReport::default();
+    for component in components {
+        if component.uri.starts_with("oci://") {
+            let pinned = OciUri::parse(&component.uri).is_ok_and(|uri| uri.digest.is_some());
+            if !pinned {
+                report.unpinned.push(component.clone());
+            }
+        }
+        if component.checksum.is_none() {
+            report.no_checksum.push(component.clone());
+        }
+        if component.is_deprecated() {
+            report.deprecated.push(component.clone());
+        }
+    }
+    report
+}
+
+/// Render a [`RegistryDiff`] for reviewers, one `+`, `-` or `~` line per component with the
+/// old and new values of changed fields
+pub fn format_diff(diff: &RegistryDiff) -> String {
+    if diff.is_empty() {
+        return "No changes\n".to_string();
+    }
+
+    let mut output = String::new();
+    if !diff.added.is_empty() {
+        output.push_str(&format!("Added ({}):\n", diff.added.len()));
+        for component in &diff.added {
+            output.push_str(&format!("  + {} ({})\n", component.name, component.uri));
+        }
+    }
+    if !diff.removed.is_empty() {
+        output.push_str(&format!("Removed ({}):\n", diff.removed.len()));
+        for component in &diff.removed {
+            output.push_str(&format!("  - {} ({})\n", component.name, component.uri));
+        }
+    }
+    if !diff.changed.is_empty() {
+        output.push_str(&format!("Changed ({}):\n", diff.changed.len()));
+        for (old, new) in &diff.changed {
+            output.push_str(&format!("  ~ {}\n", new.name));
+            if old.uri != new.uri {
+                output.push_str(&format!("      uri: {} -> {}\n", old.uri, new.uri));
+            }
+            if old.description != new.description {
+                output.push_str(&format!(
+                    "      description: {} -> {}\n",
+                    old.description, new.description
+                ));
+            }
+        }
+    }
+    output
+}
+
+/// A registry with indexed lookups, for registries too large to scan on every query
+#[derive(Debug, Clone, Default)]
+pub struct RegistryIndex {
+    components: Vec<RegistryComponent>,
+    /// Lowercased name to the positions of every component with that name
+    by_name: HashMap<String, Vec<usize>>,
+    by_uri: HashMap<String, usize>,
+    /// Lowercased words of each component's name, description, URI and tags
+    by_word: BTreeMap<String, BTreeSet<usize>>,
+}
+
+#[allow(dead_code)] // Not used by the CLI yet, whose bundled registry is small enough to scan
+impl RegistryIndex {
+    /// Index `components`, keeping their order
+    pub fn build(components: Vec<RegistryComponent>) -> Self {
+        let mut index = Self::default();
+        for (position, component) in components.iter().enumerate() {
+            index
+                .by_name
+                .entry(component.name.to_lowercase())
+                .or_default()
+                .push(position);
+            index
+                .by_uri
+                .entry(component.uri.clone())
+                .or_insert(position);
+
+            let fields = [&component.name, &component.description, &component.uri]
+                .into_iter()
+                .chain(&component.tags);
+            for field in fields {
+                for word in index_words(field) {
+                    index.by_word.entry(word).or_default().insert(position);
+                }
+            }
+        }
+        index.components = components;
+        index
+    }
+
+    /// All indexed components in their original order
+    pub fn components(&self) -> &[RegistryComponent] {
+        &self.components
+    }
+
+    /// Every component whose name matches `name`, ignoring case
+    pub fn find_by_name(&self, name: &str) -> Vec<&RegistryComponent> {
+        self.by_name
+            .get(&name.to_lowercase())
+            .map(|positions| positions.iter().map(|&p| &self.components[p]).collect())
+            .unwrap_or_default()
+    }
+
+    /// The first component with exactly this URI
+    pub fn find_by_uri(&self, uri: &str) -> Option<&RegistryComponent> {
+        self.by_uri.get(uri).map(|&p| &self.components[p])
+    }
+
+    /// Indexed equivalent of [`find_component_by_name_or_uri`], with the same precedence and
+    /// ambiguity rules
+    pub fn find_by_name_or_uri(&self, name_or_uri: &str) -> Result<Option<&RegistryComponent>> {
+        match self.find_by_name(name_or_uri).as_slice() {
+            [] => Ok(self.find_by_uri(name_or_uri)),
+            [component] => Ok(Some(component)),
+            by_name => {
+                let uris: Vec<&str> = by_name.iter().map(|c| c.uri.as_str()).collect();
+                bail!(
+                    "Component name '{}' is ambiguous, matching {} entries: {}. Use the URI instead.",
+                    name_or_uri,
+                    by_name.len(),
+                    uris.join(", ")
+                );
+            }
+        }
+    }
+
+    /// Search non-deprecated components, scored like [`search_components`] but without its
+    /// `field:value` operators.
+    ///
+    /// Only components with a word starting with one of the query's words are scored, so a
+    /// term matching in the middle of a word (`eath` in `weather`) finds nothing here.
+    pub fn search(&self, query: Option<&str>) -> Vec<SearchResult> {
+        let query_terms: Vec<String> = query
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|term| term.to_lowercase())
+            .collect();
+        if query_terms.is_empty() {
+            return self
+                .components
+                .iter()
+                .filter(|c| !c.is_deprecated())
+                .map(|c| SearchResult {
+                    component: c.clone(),
+                    score: 0,
+                })
+                .collect();
+        }
+        let full_query = query_terms.join(" ");
+
+        let mut candidates: BTreeSet<usize> = BTreeSet::new();
+        for word in query_terms.iter().flat_map(|term| index_words(term)) {
+            for (_, positions) in self
+                .by_word
+                .range(word.clone()..)
+                .take_while(|(indexed, _)| indexed.starts_with(word.as_str()))
+            {
+                candidates.extend(positions);
+            }
+        }
+
+        let mut results: Vec<SearchResult> = candidates
+            .into_iter()
+            .map(|position| &self.components[position])
+            .filter(|c| !c.is_deprecated())
+            .filter_map(|c| {
+                let score = score_component(c, &query_terms, &full_query);
+                (score > 0).then(|| SearchResult {
+                    component: c.clone(),
+                    score,
+                })
+            })
+            .collect();
+        // Candidates are visited in registry order, so ties keep it as in `search_components`
+        results.sort_by_key(|r| Reverse(r.score));
+        results
+    }
+}
+
+/// Split text into lowercased alphanumeric words for [`RegistryIndex`] and
+/// [`search_components_ranked`]
+fn index_words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_registry() {
+        let json = r#"[
+            {
+                "name": "Weather Server",
+                "description": "A weather component",
+                "uri": "oci://ghcr.io/microsoft/get-weather-js:latest"
+            }
+        ]"#;
+
+        let components = parse_registry(json).unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].name, "Weather Server");
+    }
+
+    #[test]
+    fn test_search_components_no_query() {
+        let components = vec![
+            RegistryComponent {
+                name: "Component A".to_string(),
+                description: "Description A".to_string(),
+                uri: "oci://example.com/a".to_string(),
+                ..Default::default()
+            },
+            RegistryComponent {
+                name: "Component B".to_string(),
+                description: "Description B".to_string(),
+                uri: "oci://example.com/b".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let results = search_components(&components, None).unwrap();
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_search_components_with_query() {
+        let components = vec![
+            RegistryComponent {
+                name: "Weather Server".to_string(),
+                description: "A weather component".to_string(),
+                uri: "oci://example.com/weather".to_string(),
+                ..Default::default()
+            },
+            RegistryComponent {
+                name: "Time Server".to_string(),
+                description: "A time component".to_string(),
+                uri: "oci://example.com/time".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let results = search_components(&components, Some("weather")).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].component.name, "Weather Server");
+    }
+
+    #[test]
+    fn test_search_components_case_insensitive() {
+        let components = vec![RegistryComponent {
+            name: "Weather Server".to_string(),
+            description: "A weather component".to_string(),
+            uri: "oci://example.com/weather".to_string(),
+            ..Default::default()
+        }];
+
+        let results = search_components(&components, Some("WEATHER")).unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_find_component_by_name() {
+        let components = vec![RegistryComponent {
+            name: "Weather Server".to_string(),
+            description: "A weather component".to_string(),
+            uri: "oci://example.com/weather".to_string(),
+            ..Default::default()
+        }];
+
+        let result = find_component_by_name_or_uri(&components, "Weather Server").unwrap();
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().name, "Weather Server");
+    }
+
+    #[test]
+    fn test_find_component_by_uri() {
+        let components = vec![RegistryComponent {
+            name: "Weather Server".to_string(),
+            description: "A weather component".to_string(),
+            uri: "oci://example.com/weather".to_string(),
+            ..Default::default()
+        }];
+
+        let result =
+            find_component_by_name_or_uri(&components, "oci://example.com/weather").unwrap();
+        assert!(result.is_some());
+        assert_eq!(result.unwrap().name, "Weather Server");
+    }
+
+    #[test]
+    fn test_search_components_multi_term() {
+        let components = vec![
+            RegistryComponent {
+                name: "Weather Server".to_string(),
+                description: "JavaScript weather component".to_string(),
+                uri: "oci://example.com/weather-js".to_string(),
+                ..Default::default()
+            },
+            RegistryComponent {
+                name: "Time Server".to_string(),
+                description: "Rust time component".to_string(),
+                uri: "oci://example.com/time-rs".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        // Multi-term search should match any term
+        let results = search_components(&components, Some("weather rust")).unwrap();
+        assert_eq!(results.len(), 2); // Both match (weather matches first, rust matches second)
+    }
+
+    #[test]
+    fn test_search_components_matches_uri() {
+        let components = vec![RegistryComponent {
+            name: "Component".to_string(),
+            description: "A test component".to_string(),
+            uri: "oci://ghcr.io/microsoft/weather".to_string(),
+            ..Default::default()
+        }];
+
+        // Should match URI as well
+        let results = search_components(&components, Some("microsoft")).unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_search_components_empty_query() {
+        let components = vec![RegistryComponent {
+            name: "Component".to_string(),
+            description: "Description".to_string(),
+            uri: "oci://example.com/comp".to_string(),
+            ..Default::default()
+        }];
+
+        // Empty string query should return all components
+        let results = search_components(&components, Some("   ")).unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    #[test]
+    fn test_search_components_ranks_name_matches_first() {
+        let component = |name: &str, description: &str, uri: &str| RegistryComponent {
+            name: name.to_string(),
+            description: description.to_string(),
+            uri: uri.to_string(),
+            ..Default::default()
+        };
+        let components = vec![
+            component(
+                "Time Server",
+                "Reports time for any weather station",
+                "oci://example.com/time",
+            ),
+            component("Get Weather", "Fetch forecasts", "oci://example.com/get"),
+            component("Weather Server", "Forecasts", "oci://example.com/server"),
+            component("Weather", "Forecasts", "oci://example.com/weather"),
+        ];
+
+        let results = search_components(&components, Some("weather")).unwrap();
+        let ranked: Vec<(&str, u32)> = results
+            .iter()
+            .map(|r| (r.component.name.as_str(), r.score))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![
+                ("Weather", 4),
+                ("Weather Server", 2),
+                ("Time Server", 1),
+                ("Get Weather", 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_components_exact_multi_word_name() {
+        let components = vec![
+            RegistryComponent {
+                name: "Server Weather".to_string(),
+                description: "".to_string(),
+                uri: "oci://example.com/a".to_string(),
+                ..Default::default()
+            },
+            RegistryComponent {
+                name: "Weather Server".to_string(),
+                description: "".to_string(),
+                uri: "oci://example.com/b".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let results = search_components(&components, Some("weather server")).unwrap();
+        assert_eq!(results[0].component.name, "Weather Server");
+        assert_eq!(results[0].score, 6);
+        assert_eq!(results[1].score, 3);
+    }
+
+    #[test]
+    fn test_search_components_no_query_scores_zero() {
+        let components = vec![RegistryComponent {
+            name: "Component".to_string(),
+            description: "Description".to_string(),
+            uri: "oci://example.com/comp".to_string(),
+            ..Default::default()
+        }];
+
+        let results = search_components(&components, None).unwrap();
+        assert_eq!(results[0].score, 0);
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("weather", "weather"), 0);
+        assert_eq!(levenshtein("wether", "weather"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_search_components_fuzzy() {
+        let components = vec![
+            RegistryComponent {
+                name: "Time Server".to_string(),
+                description: "A time component".to_string(),
+                uri: "oci://example.com/time".to_string(),
+                ..Default::default()
+            },
+            RegistryComponent {
+                name: "Weathr-Tool".to_string(),
+                description: "Another weather component".to_string(),
+                uri: "oci://example.com/weathr".to_string(),
+                ..Default::default()
+            },
+            RegistryComponent {
+                name: "Weather Server".to_string(),
+                description: "A weather component".to_string(),
+                uri: "oci://example.com/weather".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let results = search_components_fuzzy(&components, "WETHER", 2);
+        let names: Vec<&str> = results.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Weather Server", "Weathr-Tool"]);
+
+        assert!(search_components_fuzzy(&components, "wether", 0).is_empty());
+        assert!(search_components_fuzzy(&components, "  ", 2).is_empty());
+    }
+
+    #[test]
+    fn test_parse_registry_tags_default_to_empty() {
+        let json = r#"[
+            {"name": "A", "description": "", "uri": "oci://example.com/a"},
+            {"name": "B", "description": "", "uri": "oci://example.com/b", "tags": ["Weather", "js"]}
+        ]"#;
+
+        let components = parse_registry(json).unwrap();
+        assert!(components[0].tags.is_empty());
+        assert_eq!(components[1].tags, vec!["Weather", "js"]);
+    }
+
+    #[test]
+    fn test_search_components_operators() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Filesystem", "description": "Read and write files", "uri": "oci://ghcr.io/microsoft/filesystem:latest", "tags": ["filesystem", "rust"], "author": "Microsoft"},
+                {"name": "File Watcher", "description": "Watch files", "uri": "oci://ghcr.io/example/watcher:latest", "tags": ["filesystem"], "author": "Example Org"},
+                {"name": "Weather", "description": "Forecasts", "uri": "oci://ghcr.io/microsoft/weather:latest", "tags": ["network"]}
+            ]"#,
+        )
+        .unwrap();
+        let names = |query: &str| -> Vec<String> {
+            search_components(&components, Some(query))
+                .unwrap()
+                .into_iter()
+                .map(|r| r.component.name)
+                .collect()
+        };
+
+        assert_eq!(names("tag:filesystem AUTHOR:microsoft"), vec!["Filesystem"]);
+        assert_eq!(names("tag:FileSystem"), vec!["Filesystem", "File Watcher"]);
+        // Tags must match whole, other fields in part
+        assert!(names("tag:file").is_empty());
+        assert_eq!(
+            names("uri:ghcr.io/microsoft"),
+            vec!["Filesystem", "Weather"]
+        );
+        assert_eq!(names("name:watch"), vec!["File Watcher"]);
+        // Bare words still score against every field, among the filtered components
+        assert_eq!(names("tag:filesystem watch"), vec!["File Watcher"]);
+        assert_eq!(names("oci://ghcr.io/example"), vec!["File Watcher"]);
+
+        assert!(search_components(&components, Some("owner:microsoft"))
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown search operator 'owner:'"));
+        assert!(search_components(&components, Some("tag:")).is_err());
+
+        assert!(has_search_operators("weather tag:network"));
+        assert!(!has_search_operators("weather oci://ghcr.io/example"));
+    }
+
+    #[test]
+    fn test_search_components_by_tag() {
+        let components = vec![
+            RegistryComponent {
+                name: "Weather Server".to_string(),
+                description: "A weather component".to_string(),
+                uri: "oci://example.com/weather".to_string(),
+                tags: vec!["network".to_string(), "JavaScript".to_string()],
+                ..Default::default()
+            },
+            RegistryComponent {
+                name: "Time Server".to_string(),
+                description: "A time component".to_string(),
+                uri: "oci://example.com/time".to_string(),
+                tags: vec!["rust".to_string()],
+                ..Default::default()
+            },
+        ];
+
+        let results = search_components_by_tag(&components, "javascript");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "Weather Server");
+
+        // Tags match exactly, not by substring
+        assert!(search_components_by_tag(&components, "java").is_empty());
+
+        // Full-text search also looks at tags
+        let results = search_components(&components, Some("rust")).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].component.name, "Time Server");
+    }
+
+    #[test]
+    fn test_validate_registry_clean() {
+        let json = r#"[
+            {"name": "A", "description": "First", "uri": "oci://ghcr.io/foo/a:1.0.0", "tags": ["x"]},
+            {"name": "B", "description": "Second", "uri": "oci://localhost:5000/foo/b"}
+        ]"#;
+
+        assert!(validate_registry(json).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_registry_reports_all_problems() {
+        let json = r#"[
+            {"name": "Weather", "description": "", "uri": "https://example.com/weather"},
+            {"name": "weather", "description": "Duplicate", "uri": "oci://ghcr.io/foo/Weather"},
+            {"description": "No name", "uri": 5, "tags": "x"},
+            "not an object"
+        ]"#;
+
+        let errors = validate_registry(json).unwrap();
+        let summary: Vec<(usize, &str)> = errors
+            .iter()
+            .map(|e| (e.component_index, e.field.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, "description"),
+                (0, "uri"),
+                (1, "name"),
+                (1, "uri"),
+                (2, "name"),
+                (2, "uri"),
+                (2, "tags"),
+                (3, "entry"),
+            ]
+        );
+        assert!(errors[2].message.contains("first used by component 0"));
+    }
+
+    #[test]
+    fn test_validate_registry_checks_provenance_types() {
+        let json = r#"[
+            {"name": "A", "description": "First", "uri": "oci://ghcr.io/foo/a", "author": "Foo", "repository": 5}
+        ]"#;
+
+        let errors = validate_registry(json).unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "repository");
+    }
+
+    #[test]
+    fn test_validate_registry_rejects_non_array() {
+        assert!(validate_registry(r#"{"name": "A"}"#).is_err());
+        assert!(validate_registry("not json").is_err());
+    }
+
+    #[test]
+    fn test_deprecated_components_hidden_from_search() {
+        let json = r#"[
+            {"name": "Weather Server", "description": "Old weather", "uri": "oci://example.com/weather-old", "deprecated": true},
+            {"name": "Weather Server 2", "description": "New weather", "uri": "oci://example.com/weather", "deprecated": false},
+            {"name": "Time Server", "description": "A time component", "uri": "oci://example.com/time"}
+        ]"#;
+        let components = parse_registry(json).unwrap();
+        assert!(components[0].is_deprecated());
+        assert!(!components[1].is_deprecated());
+        assert!(!components[2].is_deprecated());
+
+        assert_eq!(search_components(&components, None).unwrap().len(), 2);
+        let results = search_components(&components, Some("weather")).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].component.name, "Weather Server 2");
+
+        let results = search_components_including_deprecated(&components, Some("weather")).unwrap();
+        assert_eq!(results.len(), 2);
+
+        let fuzzy = search_components_fuzzy(&components, "wether", 2);
+        assert_eq!(fuzzy.len(), 1);
+        assert_eq!(fuzzy[0].name, "Weather Server 2");
 
-/// Represents a component in the registry
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RegistryComponent {
-    pub name: String,
-    pub description: String,
-    pub uri: String,
-}
+        let fuzzy = search_components_fuzzy_including_deprecated(&components, "wether", 2);
+        assert_eq!(fuzzy.len(), 2);
 
-/// Parse the component registry JSON
-pub fn parse_registry(registry_json: &str) -> Result<Vec<RegistryComponent>> {
-    serde_json::from_str(registry_json).context("Failed to parse component registry JSON")
-}
+        // Lookups still resolve deprecated components so callers can warn about them
+        let found = find_component_by_name_or_uri(&components, "weather server")
+            .unwrap()
+            .unwrap();
+        assert!(found.is_deprecated());
+    }
 
-/// Search for components matching a query string with optimized full-text search
-pub fn search_components(
-    components: &[RegistryComponent],
-    query: Option<&str>,
-) -> Vec<RegistryComponent> {
-    match query {
-        None => components.to_vec(),
-        Some(q) => {
-            // Split query into words for multi-term matching
-            let query_terms: Vec<String> = q
-                .split_whitespace()
-                .map(|term| term.to_lowercase())
-                .collect();
+    #[test]
+    fn test_merge_registries_local_entries_win() {
+        let primary = parse_registry(
+            r#"[
+                {"name": "Weather", "description": "upstream", "uri": "oci://ghcr.io/up/weather"},
+                {"name": "Time", "description": "upstream", "uri": "oci://ghcr.io/up/time"}
+            ]"#,
+        )
+        .unwrap();
+        let local = parse_registry(
+            r#"[
+                {"name": "Private", "description": "local", "uri": "oci://registry.corp/private"},
+                {"name": "weather", "description": "local", "uri": "oci://registry.corp/weather"}
+            ]"#,
+        )
+        .unwrap();
 
-            if query_terms.is_empty() {
-                return components.to_vec();
-            }
+        let merged = merge_registries(&primary, &local);
+        let summary: Vec<(&str, &str)> = merged
+            .iter()
+            .map(|c| (c.name.as_str(), c.description.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("weather", "local"),
+                ("Time", "upstream"),
+                ("Private", "local"),
+            ]
+        );
+    }
 
-            components
-                .iter()
-                .filter(|c| {
-                    // Pre-compute lowercase versions once per component
-                    let name_lower = c.name.to_lowercase();
-                    let desc_lower = c.description.to_lowercase();
-                    let uri_lower = c.uri.to_lowercase();
-
-                    // Match if ANY query term is found in name, description, or URI
-                    query_terms.iter().any(|term| {
-                        name_lower.contains(term)
-                            || desc_lower.contains(term)
-                            || uri_lower.contains(term)
-                    })
-                })
-                .cloned()
-                .collect()
-        }
+    #[test]
+    fn test_load_local_registry() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wassette-registry.json");
+        std::fs::write(
+            &path,
+            r#"[{"name": "A", "description": "B", "uri": "oci://example.com/a"}]"#,
+        )
+        .unwrap();
+
+        let components = load_local_registry(&path).unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].name, "A");
+
+        let missing = load_local_registry(&dir.path().join("missing.json"));
+        assert!(missing.is_err());
     }
-}
 
-/// Find a component by name or URI
-pub fn find_component_by_name_or_uri(
-    components: &[RegistryComponent],
-    name_or_uri: &str,
-) -> Option<RegistryComponent> {
-    components
-        .iter()
-        .find(|c| c.name.eq_ignore_ascii_case(name_or_uri) || c.uri == name_or_uri)
-        .cloned()
-}
+    #[test]
+    fn test_find_component_ambiguous_name() {
+        let upstream = parse_registry(
+            r#"[{"name": "Weather Server", "description": "upstream", "uri": "oci://ghcr.io/up/weather"}]"#,
+        )
+        .unwrap();
+        let mut components = upstream.clone();
+        components.extend(
+            parse_registry(
+                r#"[{"name": "weather server", "description": "local", "uri": "oci://registry.corp/weather"}]"#,
+            )
+            .unwrap(),
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let all = find_all_components_by_name(&components, "WEATHER SERVER");
+        assert_eq!(all.len(), 2);
+
+        let err = find_component_by_name_or_uri(&components, "Weather Server").unwrap_err();
+        assert!(err.to_string().contains("oci://ghcr.io/up/weather"));
+        assert!(err.to_string().contains("oci://registry.corp/weather"));
+
+        // URIs stay unambiguous
+        let found = find_component_by_name_or_uri(&components, "oci://registry.corp/weather")
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.description, "local");
+
+        assert!(find_component_by_name_or_uri(&components, "Missing")
+            .unwrap()
+            .is_none());
+    }
 
     #[test]
-    fn test_parse_registry() {
+    fn test_format_component_detail() {
         let json = r#"[
+            {"name": "Minimal", "description": "Bare entry", "uri": "oci://example.com/minimal"},
             {
                 "name": "Weather Server",
                 "description": "A weather component",
-                "uri": "oci://ghcr.io/microsoft/get-weather-js:latest"
+                "uri": "oci://ghcr.io/microsoft/get-weather-js:1.0.0",
+                "tags": ["weather", "javascript"],
+                "homepage": "https://github.com/microsoft/wassette",
+                "license": "MIT",
+                "author": "Microsoft",
+                "repository": "https://github.com/microsoft/wassette",
+                "deprecated": true
             }
         ]"#;
+        let components = parse_registry(json).unwrap();
+        assert_eq!(components[0].homepage, None);
+        assert_eq!(components[0].license, None);
+        assert_eq!(components[0].author, None);
+
+        assert_eq!(
+            format_component_detail(&components[0]),
+            "Name: Minimal\nDescription: Bare entry\nURI: oci://example.com/minimal"
+        );
+        assert_eq!(
+            format_component_detail(&components[1]),
+            "Name: Weather Server\n\
+             Description: A weather component\n\
+             URI: oci://ghcr.io/microsoft/get-weather-js:1.0.0\n\
+             Version: 1.0.0\n\
+             Tags: weather, javascript\n\
+             Homepage: https://github.com/microsoft/wassette\n\
+             License: MIT\n\
+             Author: Microsoft\n\
+             Repository: https://github.com/microsoft/wassette\n\
+             Deprecated: yes"
+        );
+    }
+
+    #[test]
+    fn test_write_and_parse_registry_file_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("registry.json");
+        let components = parse_registry(
+            r#"[
+                {"name": "A", "description": "First", "uri": "oci://example.com/a:1.0.0", "tags": ["x"]},
+                {"name": "B", "description": "Second", "uri": "oci://example.com/b", "license": "MIT"}
+            ]"#,
+        )
+        .unwrap();
+
+        write_registry_to_file(&components, &path).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert!(written.starts_with("[\n  {"));
+        assert!(written.ends_with("]\n"));
+
+        let parsed = parse_registry_from_file(&path).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].version.as_deref(), Some("1.0.0"));
+        assert_eq!(parsed[0].tags, vec!["x"]);
+        assert_eq!(parsed[1].license.as_deref(), Some("MIT"));
+    }
+
+    #[test]
+    fn test_parse_registry_from_file_errors_mention_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.json");
+        std::fs::write(&path, "{ not json").unwrap();
+
+        let err = parse_registry_from_file(&path).unwrap_err();
+        assert!(err.to_string().contains("broken.json"));
+    }
+
+    #[test]
+    fn test_validate_registry_file_checks_toml_components() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("registry.toml");
+        std::fs::write(
+            &path,
+            r#"
+            [[components]]
+            name = "Weather"
+            description = "Weather"
+            uri = "https://example.com/weather"
+
+            [[components]]
+            name = "weather"
+            description = ""
+            uri = "oci://example.com/weather:1.0.0"
+            "#,
+        )
+        .unwrap();
+
+        let errors = validate_registry_file(&path).unwrap();
+        let fields: Vec<(usize, &str)> = errors
+            .iter()
+            .map(|e| (e.component_index, e.field.as_str()))
+            .collect();
+        assert_eq!(fields, vec![(0, "uri"), (1, "description"), (1, "name")]);
+
+        std::fs::write(&path, "[[components]]\nname = 1\n").unwrap();
+        let err = validate_registry_file(&path).unwrap_err();
+        assert!(err.to_string().contains("registry.toml"), "{err}");
+    }
+
+    #[test]
+    fn test_filter_compatible_components() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Any", "description": "", "uri": "oci://example.com/any"},
+                {"name": "Old", "description": "", "uri": "oci://example.com/old", "min_wassette_version": "0.3.0"},
+                {"name": "New", "description": "", "uri": "oci://example.com/new", "min_wassette_version": "v0.4.0"},
+                {"name": "Bad", "description": "", "uri": "oci://example.com/bad", "min_wassette_version": "soon"}
+            ]"#,
+        )
+        .unwrap();
+
+        let (compatible, incompatible) = filter_compatible_components(&components, "0.3.4");
+        let names: Vec<&str> = compatible.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Any", "Old", "Bad"]);
+        assert_eq!(incompatible.len(), 1);
+        assert_eq!(incompatible[0].name, "New");
+
+        let (compatible, incompatible) = filter_compatible_components(&components, "0.4.0");
+        assert_eq!(compatible.len(), 4);
+        assert!(incompatible.is_empty());
+
+        let (compatible, _) = filter_compatible_components(&components, "not-a-version");
+        assert_eq!(compatible.len(), 4);
+
+        let errors = validate_registry(
+            r#"[{"name": "Bad", "description": "x", "uri": "oci://example.com/bad", "min_wassette_version": "soon"}]"#,
+        )
+        .unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "min_wassette_version");
+    }
+
+    #[test]
+    fn test_search_components_paged() {
+        let components: Vec<RegistryComponent> = (0..5)
+            .map(|i| RegistryComponent {
+                name: format!("Component {i}"),
+                description: "Test component".to_string(),
+                uri: format!("oci://example.com/c{i}"),
+                ..Default::default()
+            })
+            .collect();
+
+        let page = search_components_paged(&components, Some("component"), 0, 2).unwrap();
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.total, 5);
+        assert_eq!(page.offset, 0);
+        assert!(page.has_more);
+        assert_eq!(page.items[0].component.name, "Component 0");
+
+        let page = search_components_paged(&components, Some("component"), 4, 2).unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].component.name, "Component 4");
+        assert!(!page.has_more);
+
+        let page = search_components_paged(&components, None, 10, 2).unwrap();
+        assert!(page.items.is_empty());
+        assert_eq!(page.total, 5);
+        assert!(!page.has_more);
+
+        assert_eq!(search_components(&components, None).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_group_components_by_tag() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Weather", "description": "", "uri": "oci://example.com/weather", "tags": ["network", "JavaScript"]},
+                {"name": "Fetch", "description": "", "uri": "oci://example.com/fetch", "tags": ["Network", "network"]},
+                {"name": "Time", "description": "", "uri": "oci://example.com/time"}
+            ]"#,
+        )
+        .unwrap();
+
+        let groups = group_components_by_tag(&components);
+        let summary: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|(tag, cs)| (tag.as_str(), cs.iter().map(|c| c.name.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("javascript", vec!["Weather"]),
+                ("network", vec!["Weather", "Fetch"]),
+                ("untagged", vec!["Time"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_component_checksum() {
+        // sha256 and sha512 of "hello"
+        let sha256 = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        let sha512 = "9b71d224bd62f3785d96d46ad3ea3d73319bfbc2890caadae2dff72519673ca7\
+                      2323c3d99ba5c11d7c7acc6e14b8c5da0c4663475c2e5c3adef46f73bcdec043";
+        let mut component =
+            parse_registry(r#"[{"name": "A", "description": "", "uri": "oci://example.com/a"}]"#)
+                .unwrap()
+                .remove(0);
+
+        assert!(verify_component_checksum(&component, b"hello").is_err());
+
+        component.checksum = Some(format!("sha256:{sha256}"));
+        assert!(verify_component_checksum(&component, b"hello").unwrap());
+        assert!(!verify_component_checksum(&component, b"hello!").unwrap());
+
+        component.checksum = Some(format!("SHA512:{}", sha512.to_uppercase()));
+        assert!(verify_component_checksum(&component, b"hello").unwrap());
+
+        component.checksum = Some(sha256.to_string());
+        assert!(verify_component_checksum(&component, b"hello").is_err());
+        component.checksum = Some(format!("md5:{sha256}"));
+        assert!(verify_component_checksum(&component, b"hello").is_err());
+        component.checksum = Some("sha256:abc".to_string());
+        assert!(verify_component_checksum(&component, b"hello").is_err());
+
+        let errors = validate_registry(
+            r#"[{"name": "A", "description": "x", "uri": "oci://example.com/a", "checksum": "sha256:xyz"}]"#,
+        )
+        .unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "checksum");
+    }
+
+    #[test]
+    fn test_export_registry_to_markdown_untagged() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Weather", "description": "Forecasts | alerts", "uri": "oci://example.com/weather", "license": "MIT"},
+                {"name": "Time", "description": "Line one\nline two", "uri": "oci://example.com/time"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            export_registry_to_markdown(&components),
+            "| Name | Description | URI | Tags | License |\n\
+             | --- | --- | --- | --- | --- |\n\
+             | Weather | Forecasts \\| alerts | `oci://example.com/weather` | — | MIT |\n\
+             | Time | Line one line two | `oci://example.com/time` | — | — |\n"
+        );
+    }
+
+    #[test]
+    fn test_export_registry_to_markdown_grouped_by_tag() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Weather", "description": "Forecasts", "uri": "oci://example.com/weather", "tags": ["network"]},
+                {"name": "Time", "description": "Clock", "uri": "oci://example.com/time"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            export_registry_to_markdown(&components),
+            "## network\n\n\
+             | Name | Description | URI | Tags | License |\n\
+             | --- | --- | --- | --- | --- |\n\
+             | Weather | Forecasts | `oci://example.com/weather` | network | — |\n\
+             \n\
+             ## untagged\n\n\
+             | Name | Description | URI | Tags | License |\n\
+             | --- | --- | --- | --- | --- |\n\
+             | Time | Clock | `oci://example.com/time` | — | — |\n"
+        );
+    }
+
+    #[test]
+    fn test_export_registry_to_markdown_with_provenance() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Weather", "description": "Forecasts", "uri": "oci://example.com/weather", "author": "Microsoft"},
+                {"name": "Time", "description": "Clock", "uri": "oci://example.com/time", "repository": "https://github.com/example/time"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            export_registry_to_markdown(&components),
+            "| Name | Description | URI | Tags | License | Author | Repository |\n\
+             | --- | --- | --- | --- | --- | --- | --- |\n\
+             | Weather | Forecasts | `oci://example.com/weather` | — | — | Microsoft | — |\n\
+             | Time | Clock | `oci://example.com/time` | — | — | — | https://github.com/example/time |\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_registry_version_from_uri_tag() {
+        let json = r#"[
+            {"name": "A", "description": "", "uri": "oci://ghcr.io/foo/a:1.2.3"},
+            {"name": "B", "description": "", "uri": "oci://ghcr.io/foo/b:latest"},
+            {"name": "C", "description": "", "uri": "oci://localhost:5000/foo/c"},
+            {"name": "D", "description": "", "uri": "oci://ghcr.io/foo/d:1.0.0", "version": "2.0.0"}
+        ]"#;
 
         let components = parse_registry(json).unwrap();
+        assert_eq!(components[0].version.as_deref(), Some("1.2.3"));
+        assert_eq!(components[1].version, None);
+        assert_eq!(components[2].version, None);
+        assert_eq!(components[3].version.as_deref(), Some("2.0.0"));
+    }
+
+    #[test]
+    fn test_parse_registry_with_dedup_keeps_highest_version() {
+        let json = r#"[
+            {"name": "Weather", "description": "old", "uri": "oci://ghcr.io/foo/weather:1.9.0"},
+            {"name": "Time", "description": "", "uri": "oci://ghcr.io/foo/time:latest"},
+            {"name": "weather", "description": "new", "uri": "oci://ghcr.io/foo/weather:1.10.0"},
+            {"name": "Weather", "description": "pre", "uri": "oci://ghcr.io/foo/weather:1.10.0-rc.1"},
+            {"name": "Time", "description": "", "uri": "oci://ghcr.io/foo/time:v0.1.0"}
+        ]"#;
+
+        let components = parse_registry_with_dedup(json).unwrap();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].description, "new");
+        assert_eq!(components[0].version.as_deref(), Some("1.10.0"));
+        assert_eq!(components[1].version.as_deref(), Some("v0.1.0"));
+    }
+
+    #[test]
+    fn test_parse_registry_with_dedup_unversioned_keeps_first() {
+        let json = r#"[
+            {"name": "A", "description": "first", "uri": "oci://ghcr.io/foo/a:latest"},
+            {"name": "A", "description": "second", "uri": "oci://ghcr.io/foo/a:main"}
+        ]"#;
+
+        let components = parse_registry_with_dedup(json).unwrap();
         assert_eq!(components.len(), 1);
-        assert_eq!(components[0].name, "Weather Server");
+        assert_eq!(components[0].description, "first");
+    }
+
+    fn index_fixture() -> RegistryIndex {
+        RegistryIndex::build(
+            parse_registry(
+                r#"[
+                    {"name": "Weather Server", "description": "Get the weather forecast", "uri": "oci://ghcr.io/microsoft/get-weather-js:latest", "tags": ["weather"]},
+                    {"name": "Time Server", "description": "Current time", "uri": "oci://ghcr.io/microsoft/time-server-js:latest"},
+                    {"name": "weather server", "description": "Mirror", "uri": "oci://registry.corp/weather:latest"},
+                    {"name": "Old Time", "description": "Deprecated time", "uri": "oci://example.com/old-time", "deprecated": true}
+                ]"#,
+            )
+            .unwrap(),
+        )
     }
 
     #[test]
-    fn test_search_components_no_query() {
-        let components = vec![
-            RegistryComponent {
-                name: "Component A".to_string(),
-                description: "Description A".to_string(),
-                uri: "oci://example.com/a".to_string(),
-            },
-            RegistryComponent {
-                name: "Component B".to_string(),
-                description: "Description B".to_string(),
-                uri: "oci://example.com/b".to_string(),
-            },
-        ];
+    fn test_registry_index_find_by_name_and_uri() {
+        let index = index_fixture();
+        assert_eq!(index.components().len(), 4);
+        assert_eq!(index.find_by_name("WEATHER SERVER").len(), 2);
+        assert_eq!(index.find_by_name("time server")[0].name, "Time Server");
+        assert!(index.find_by_name("missing").is_empty());
 
-        let results = search_components(&components, None);
-        assert_eq!(results.len(), 2);
+        let found = index
+            .find_by_uri("oci://registry.corp/weather:latest")
+            .unwrap();
+        assert_eq!(found.description, "Mirror");
+        assert!(index.find_by_uri("oci://nowhere/none").is_none());
     }
 
     #[test]
-    fn test_search_components_with_query() {
-        let components = vec![
-            RegistryComponent {
-                name: "Weather Server".to_string(),
-                description: "A weather component".to_string(),
-                uri: "oci://example.com/weather".to_string(),
-            },
-            RegistryComponent {
-                name: "Time Server".to_string(),
-                description: "A time component".to_string(),
-                uri: "oci://example.com/time".to_string(),
-            },
-        ];
+    fn test_registry_index_find_by_name_or_uri_matches_scan() {
+        let index = index_fixture();
+        let components = index.components().to_vec();
 
-        let results = search_components(&components, Some("weather"));
-        assert_eq!(results.len(), 1);
-        assert_eq!(results[0].name, "Weather Server");
+        for query in [
+            "Time Server",
+            "oci://registry.corp/weather:latest",
+            "Missing",
+        ] {
+            assert_eq!(
+                index
+                    .find_by_name_or_uri(query)
+                    .unwrap()
+                    .map(|c| c.uri.clone()),
+                find_component_by_name_or_uri(&components, query)
+                    .unwrap()
+                    .map(|c| c.uri),
+            );
+        }
+        let err = index.find_by_name_or_uri("weather server").unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
     }
 
     #[test]
-    fn test_search_components_case_insensitive() {
-        let components = vec![RegistryComponent {
-            name: "Weather Server".to_string(),
-            description: "A weather component".to_string(),
-            uri: "oci://example.com/weather".to_string(),
-        }];
+    fn test_registry_index_search_matches_scan() {
+        let index = index_fixture();
+        let components = index.components().to_vec();
 
-        let results = search_components(&components, Some("WEATHER"));
-        assert_eq!(results.len(), 1);
+        for query in [
+            None,
+            Some("weather"),
+            Some("time server"),
+            Some("get-weather"),
+        ] {
+            let indexed: Vec<_> = index
+                .search(query)
+                .into_iter()
+                .map(|r| (r.component.uri, r.score))
+                .collect();
+            let scanned: Vec<_> = search_components(&components, query)
+                .unwrap()
+                .into_iter()
+                .map(|r| (r.component.uri, r.score))
+                .collect();
+            assert_eq!(indexed, scanned, "query {query:?}");
+        }
+
+        // Mid-word matches are only found by the full scan
+        assert!(index.search(Some("eath")).is_empty());
+        assert!(!search_components(&components, Some("eath"))
+            .unwrap()
+            .is_empty());
     }
 
     #[test]
-    fn test_find_component_by_name() {
-        let components = vec![RegistryComponent {
-            name: "Weather Server".to_string(),
-            description: "A weather component".to_string(),
-            uri: "oci://example.com/weather".to_string(),
-        }];
+    fn test_diff_registries() {
+        let old = parse_registry(
+            r#"[
+                {"name": "Weather", "description": "Weather", "uri": "oci://ghcr.io/a/weather:1.0.0"},
+                {"name": "Time", "description": "Time", "uri": "oci://ghcr.io/a/time:1.0.0"},
+                {"name": "Fetch", "description": "Fetch", "uri": "oci://ghcr.io/a/fetch:1.0.0"}
+            ]"#,
+        )
+        .unwrap();
+        let new = parse_registry(
+            r#"[
+                {"name": "weather", "description": "Weather", "uri": "oci://ghcr.io/a/weather:1.1.0"},
+                {"name": "Fetch", "description": "Fetch", "uri": "oci://ghcr.io/a/fetch:1.0.0", "tags": ["web"]},
+                {"name": "Memory", "description": "Memory", "uri": "oci://ghcr.io/a/memory:0.1.0"}
+            ]"#,
+        )
+        .unwrap();
 
-        let result = find_component_by_name_or_uri(&components, "Weather Server");
-        assert!(result.is_some());
-        assert_eq!(result.unwrap().name, "Weather Server");
+        let diff = diff_registries(&old, &new);
+        let names = |components: &[RegistryComponent]| -> Vec<String> {
+            components.iter().map(|c| c.name.clone()).collect()
+        };
+        assert_eq!(names(&diff.added), vec!["Memory"]);
+        assert_eq!(names(&diff.removed), vec!["Time"]);
+        // Only URI and description count as changes, so the new tag on Fetch is ignored
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].0.uri, "oci://ghcr.io/a/weather:1.0.0");
+        assert_eq!(diff.changed[0].1.uri, "oci://ghcr.io/a/weather:1.1.0");
+
+        assert_eq!(
+            format_diff(&diff),
+            "Added (1):\n  + Memory (oci://ghcr.io/a/memory:0.1.0)\n\
+             Removed (1):\n  - Time (oci://ghcr.io/a/time:1.0.0)\n\
+             Changed (1):\n  ~ weather\n      uri: oci://ghcr.io/a/weather:1.0.0 -> oci://ghcr.io/a/weather:1.1.0\n"
+        );
     }
 
     #[test]
-    fn test_find_component_by_uri() {
-        let components = vec![RegistryComponent {
-            name: "Weather Server".to_string(),
-            description: "A weather component".to_string(),
-            uri: "oci://example.com/weather".to_string(),
-        }];
+    fn test_audit_registry() {
+        let digest = "ab".repeat(32);
+        let components = parse_registry(&format!(
+            r#"[
+                {{"name": "Latest", "description": "Tagged", "uri": "oci://ghcr.io/a/latest:latest"}},
+                {{"name": "Pinned", "description": "Digest", "uri": "oci://ghcr.io/a/pinned:1.0.0@sha256:{digest}", "checksum": "sha256:{digest}"}},
+                {{"name": "Old", "description": "Deprecated", "uri": "oci://ghcr.io/a/old@sha256:{digest}", "deprecated": true}},
+                {{"name": "Local", "description": "File", "uri": "file:///opt/local.wasm", "checksum": "sha256:{digest}"}}
+            ]"#
+        ))
+        .unwrap();
 
-        let result = find_component_by_name_or_uri(&components, "oci://example.com/weather");
-        assert!(result.is_some());
-        assert_eq!(result.unwrap().name, "Weather Server");
+        let report = audit_registry(&components);
+        let names = |components: &[RegistryComponent]| -> Vec<String> {
+            components.iter().map(|c| c.name.clone()).collect()
+        };
+        assert_eq!(names(&report.unpinned), vec!["Latest"]);
+        assert_eq!(names(&report.no_checksum), vec!["Latest", "Old"]);
+        assert_eq!(names(&report.deprecated), vec!["Old"]);
+
+        let report = audit_registry(&[]);
+        assert!(report.unpinned.is_empty() && report.no_checksum.is_empty());
     }
 
     #[test]
-    fn test_search_components_multi_term() {
-        let components = vec![
-            RegistryComponent {
-                name: "Weather Server".to_string(),
-                description: "JavaScript weather component".to_string(),
-                uri: "oci://example.com/weather-js".to_string(),
-            },
-            RegistryComponent {
-                name: "Time Server".to_string(),
-                description: "Rust time component".to_string(),
-                uri: "oci://example.com/time-rs".to_string(),
-            },
-        ];
+    fn test_diff_registries_identical() {
+        let components = index_fixture().components().to_vec();
+        let diff = diff_registries(&components, &components);
+        assert!(diff.is_empty());
+        assert_eq!(format_diff(&diff), "No changes\n");
+    }
 
-        // Multi-term search should match any term
-        let results = search_components(&components, Some("weather rust"));
-        assert_eq!(results.len(), 2); // Both match (weather matches first, rust matches second)
+    #[test]
+    fn test_sort_registry() {
+        let mut components = parse_registry(
+            r#"[
+                {"name": "weather", "description": "", "uri": "oci://ghcr.io/b/weather"},
+                {"name": "Time", "description": "", "uri": "oci://ghcr.io/a/time"},
+                {"name": "Weather", "description": "", "uri": "oci://ghcr.io/a/weather"},
+                {"name": "fetch", "description": "", "uri": "oci://ghcr.io/a/fetch"}
+            ]"#,
+        )
+        .unwrap();
+        assert!(!is_registry_sorted(&components));
+
+        sort_registry(&mut components);
+        let order: Vec<&str> = components.iter().map(|c| c.uri.as_str()).collect();
+        assert_eq!(
+            order,
+            vec![
+                "oci://ghcr.io/a/fetch",
+                "oci://ghcr.io/a/time",
+                // Names equal ignoring case are ordered by URI
+                "oci://ghcr.io/a/weather",
+                "oci://ghcr.io/b/weather",
+            ]
+        );
+        assert!(is_registry_sorted(&components));
     }
 
     #[test]
-    fn test_search_components_matches_uri() {
-        let components = vec![RegistryComponent {
-            name: "Component".to_string(),
-            description: "A test component".to_string(),
-            uri: "oci://ghcr.io/microsoft/weather".to_string(),
-        }];
+    fn test_is_registry_sorted_ties_by_uri() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Weather", "description": "", "uri": "oci://ghcr.io/b/weather"},
+                {"name": "weather", "description": "", "uri": "oci://ghcr.io/a/weather"}
+            ]"#,
+        )
+        .unwrap();
+        assert!(!is_registry_sorted(&components));
+        assert!(is_registry_sorted(&components[..1]));
+        assert!(is_registry_sorted(&[]));
+    }
 
-        // Should match URI as well
-        let results = search_components(&components, Some("microsoft"));
+    #[test]
+    fn test_search_components_ranked_prefers_rare_terms() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Fetch", "description": "Fetch a web page as markdown", "uri": "oci://ghcr.io/microsoft/fetch-rs:latest"},
+                {"name": "Weather", "description": "Get the weather forecast for a city", "uri": "oci://ghcr.io/microsoft/get-weather-js:latest"},
+                {"name": "Time", "description": "Get the current time for a city", "uri": "oci://ghcr.io/microsoft/time-server-js:latest"},
+                {"name": "Old Weather", "description": "Weather weather weather", "uri": "oci://example.com/old-weather", "deprecated": true}
+            ]"#,
+        )
+        .unwrap();
+
+        // "weather" appears in one active component, "city" in two
+        let results = search_components_ranked(&components, "weather city");
+        let names: Vec<&str> = results.iter().map(|(c, _)| c.name.as_str()).collect();
+        assert_eq!(names, ["Weather", "Time"]);
+        assert!(results[0].1 > results[1].1);
+
+        // Repeated words count more, and each match contributes ln(1 + n / df)
+        let results = search_components_ranked(&components, "FETCH");
         assert_eq!(results.len(), 1);
+        assert!((results[0].1 - 2.0 * 4.0_f64.ln()).abs() < 1e-9);
     }
 
     #[test]
-    fn test_search_components_empty_query() {
-        let components = vec![RegistryComponent {
-            name: "Component".to_string(),
-            description: "Description".to_string(),
-            uri: "oci://example.com/comp".to_string(),
-        }];
+    fn test_search_components_ranked_no_match() {
+        let components = index_fixture().components().to_vec();
+        assert!(search_components_ranked(&components, "database").is_empty());
+        assert!(search_components_ranked(&components, "  ").is_empty());
+    }
 
-        // Empty string query should return all components
-        let results = search_components(&components, Some("   "));
-        assert_eq!(results.len(), 1);
+    #[test]
+    fn test_search_components_ranked_ties_keep_registry_order() {
+        let components = index_fixture().components().to_vec();
+        let results = search_components_ranked(&components, "server");
+        let names: Vec<&str> = results.iter().map(|(c, _)| c.name.as_str()).collect();
+        assert_eq!(names, ["Weather Server", "Time Server", "weather server"]);
+    }
+
+    #[test]
+    fn test_components_toml_round_trip() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Fetch", "description": "Fetch URLs", "uri": "oci://ghcr.io/microsoft/fetch-rs:0.1.0", "checksum": "sha256:abcd"},
+                {"name": "Weather", "description": "Forecasts", "uri": "oci://ghcr.io/microsoft/get-weather-js:latest", "tags": ["weather"], "deprecated": true}
+            ]"#,
+        )
+        .unwrap();
+
+        let toml_str = components_to_toml(&components).unwrap();
+        assert!(
+            toml_str.starts_with("[[components]]\nname = \"Fetch\"\n"),
+            "{toml_str}"
+        );
+        let parsed = components_from_toml(&toml_str).unwrap();
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&components).unwrap()
+        );
+
+        assert!(components_from_toml("").unwrap().is_empty());
+        assert!(components_from_toml("[[components]]\nname = \"missing fields\"").is_err());
+    }
+
+    #[test]
+    fn test_components_csv_round_trip() {
+        let components = parse_registry(
+            r#"[
+                {"name": "Fetch", "description": "Fetch URLs, \"quickly\"", "uri": "oci://ghcr.io/microsoft/fetch-rs:0.1.0", "tags": ["http", "rust"], "license": "MIT", "homepage": "https://example.com/fetch", "deprecated": false},
+                {"name": "Weather", "description": "Forecasts\nand alerts", "uri": "oci://ghcr.io/microsoft/get-weather-js:latest", "tags": ["weather"], "license": "Apache-2.0", "homepage": "https://example.com/weather", "deprecated": true}
+            ]"#,
+        )
+        .unwrap();
+
+        let csv = export_registry_to_csv(&components);
+        assert!(
+            csv.starts_with("name,description,uri,tags,license,homepage,deprecated\n"),
+            "{csv}"
+        );
+        assert!(csv.contains(",http;rust,MIT,"), "{csv}");
+        let parsed = parse_registry_from_csv(&csv).unwrap();
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            serde_json::to_value(&components).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_registry_from_csv_defaults() {
+        let components = parse_registry_from_csv(
+            "uri,name,description,tags\n\
+             oci://ghcr.io/microsoft/fetch-rs:0.1.0,Fetch,Fetch URLs\n\
+             oci://ghcr.io/microsoft/time:latest,Time,Tells the time,\n",
+        )
+        .unwrap();
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].name, "Fetch");
+        assert_eq!(components[0].version.as_deref(), Some("0.1.0"));
+        assert!(components[1].tags.is_empty());
+        assert!(!components[1].is_deprecated());
+        assert_eq!(components[1].license, None);
+
+        assert!(parse_registry_from_csv("name,uri\nFetch,oci://ghcr.io/a/b:1\n").is_err());
+        assert!(parse_registry_from_csv("name,description,uri,deprecated\na,b,c,maybe\n").is_err());
+        assert_eq!(
+            export_registry_to_csv(&[]),
+            "name,description,uri,tags,license,homepage,deprecated\n"
+        );
+    }
+
+    #[test]
+    fn test_add_and_remove_component_in_registry_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("registry.json");
+        let component = |name: &str| {
+            parse_registry(&format!(
+                r#"[{{"name": "{name}", "description": "Test", "uri": "oci://ghcr.io/example/{}:1.0.0"}}]"#,
+                name.to_lowercase()
+            ))
+            .unwrap()
+            .remove(0)
+        };
+
+        add_component_to_registry(&path, component("Weather")).unwrap();
+        add_component_to_registry(&path, component("Fetch")).unwrap();
+        let err = add_component_to_registry(&path, component("fetch")).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("already has a component named 'Fetch'"),
+            "{err}"
+        );
+
+        let components = parse_registry_from_file(&path).unwrap();
+        let names: Vec<&str> = components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["Fetch", "Weather"]);
+        assert!(std::fs::read_to_string(&path).unwrap().ends_with("]\n"));
+
+        let removed = remove_component_from_registry(&path, "weather").unwrap();
+        assert_eq!(removed.name, "Weather");
+        assert_eq!(parse_registry_from_file(&path).unwrap().len(), 1);
+        assert!(remove_component_from_registry(&path, "Weather").is_err());
+        assert!(!dir.path().join("registry.json.tmp").exists());
+    }
+
+    #[test]
+    fn test_add_component_to_toml_registry_keeps_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("registry.toml");
+        let component = parse_registry(
+            r#"[{"name": "Fetch", "description": "Fetch URLs", "uri": "oci://ghcr.io/microsoft/fetch-rs:0.1.0"}]"#,
+        )
+        .unwrap()
+        .remove(0);
+
+        add_component_to_registry(&path, component).unwrap();
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .starts_with("[[components]]\n"));
+        assert_eq!(parse_registry_from_file(&path).unwrap()[0].name, "Fetch");
+    }
+
+    #[test]
+    fn test_parse_registry_from_toml_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("registry.toml");
+        std::fs::write(
+            &path,
+            "[[components]]\nname = \"Fetch\"\ndescription = \"Fetch URLs\"\nuri = \"oci://ghcr.io/microsoft/fetch-rs:0.1.0\"\n",
+        )
+        .unwrap();
+
+        let components = parse_registry_from_file(&path).unwrap();
+        assert_eq!(components[0].name, "Fetch");
+        // The version comes from the URI tag, as it does for JSON registries
+        assert_eq!(components[0].version.as_deref(), Some("0.1.0"));
+    }
+
+    fn arb_component() -> impl proptest::strategy::Strategy<Value = RegistryComponent> {
+        use proptest::prelude::*;
+
+        let optional = || proptest::option::of(any::<String>());
+        (
+            (
+                any::<String>(),
+                any::<String>(),
+                any::<String>(),
+                optional(),
+            ),
+            (
+                proptest::collection::vec(any::<String>(), 0..4),
+                proptest::option::of(any::<bool>()),
+                optional(),
+                optional(),
+                optional(),
+                optional(),
+                optional(),
+                optional(),
+            ),
+        )
+            .prop_map(
+                |(
+                    (name, description, uri, version),
+                    (
+                        tags,
+                        deprecated,
+                        homepage,
+                        license,
+                        min_wassette_version,
+                        checksum,
+                        author,
+                        repository,
+                    ),
+                )| RegistryComponent {
+                    name,
+                    description,
+                    uri,
+                    version,
+                    tags,
+                    deprecated,
+                    homepage,
+                    license,
+                    min_wassette_version,
+                    checksum,
+                    author,
+                    repository,
+                },
+            )
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_json_toml_json_round_trip(
+            components in proptest::collection::vec(arb_component(), 0..5)
+        ) {
+            let json = serde_json::to_string(&components).unwrap();
+            let from_json = parse_registry(&json).unwrap();
+
+            let toml_str = components_to_toml(&from_json).unwrap();
+            let from_toml = components_from_toml(&toml_str).unwrap();
+            proptest::prop_assert_eq!(
+                serde_json::to_string(&from_toml).unwrap(),
+                serde_json::to_string(&from_json).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_search_labelled() {
+        let upstream = parse_registry(
+            r#"[
+                {"name": "Weather Server", "description": "Forecasts", "uri": "oci://ghcr.io/microsoft/get-weather-js:latest"},
+                {"name": "Time Server", "description": "Current time", "uri": "oci://ghcr.io/microsoft/time-server-js:latest"}
+            ]"#,
+        )
+        .unwrap();
+        let local = parse_registry(
+            r#"[
+                {"name": "Weather Server", "description": "Patched forecasts", "uri": "oci://localhost:5000/weather:dev"},
+                {"name": "Weather Tools", "description": "Other", "uri": "oci://localhost:5000/tools:dev"}
+            ]"#,
+        )
+        .unwrap();
+        let sources: [(&str, &[RegistryComponent]); 2] =
+            [("local", &local), ("upstream", &upstream)];
+
+        let results = search_labelled(&sources, Some("weather server")).unwrap();
+        let shown: Vec<String> = results.iter().map(|r| r.to_string()).collect();
+        assert_eq!(
+            shown,
+            [
+                "(local) Weather Server",
+                "(upstream) Weather Server",
+                "(local) Weather Tools",
+                "(upstream) Time Server"
+            ]
+        );
+        assert_eq!(results[0].component.description, "Patched forecasts");
+
+        assert_eq!(search_labelled(&sources, None).unwrap().len(), 4);
+        assert!(search_labelled(&[], Some("weather")).unwrap().is_empty());
     }
 }
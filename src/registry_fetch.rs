@@ -0,0 +1,303 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+//! Fetching the component registry from a URL with an on-disk HTTP cache
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{bail, Context, Result};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::registry::{parse_registry, RegistryComponent};
+
+/// Validators from the last successful response, stored next to the cached registry
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheValidators {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_modified: Option<String>,
+}
+
+/// Fetch the registry JSON from `url`, caching it at `cache_path`.
+///
+/// A cache younger than `max_age_secs` is used without touching the network. Otherwise the
+/// request carries `If-None-Match` / `If-Modified-Since` from the previous response, and the
+/// cache is only rewritten when the server sends a new, valid registry. If the request fails
+/// and a stale cache exists, the stale data is returned with a warning.
+#[allow(dead_code)] // Not wired into the CLI yet, the bundled registry is still used by default
+pub async fn fetch_registry(
+    url: &str,
+    cache_path: &Path,
+    max_age_secs: u64,
+) -> Result<Vec<RegistryComponent>> {
+    if let Some(age) = cache_age(cache_path) {
+        if age < Duration::from_secs(max_age_secs) {
+            debug!(path = %cache_path.display(), "Using cached registry");
+            return read_cached_registry(cache_path);
+        }
+    }
+
+    match revalidate(url, cache_path).await {
+        Ok(components) => Ok(components),
+        Err(e) if cache_path.exists() => {
+            warn!(
+                url,
+                path = %cache_path.display(),
+                "Failed to refresh registry, using stale cache: {e:#}"
+            );
+            read_cached_registry(cache_path)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+async fn revalidate(url: &str, cache_path: &Path) -> Result<Vec<RegistryComponent>> {
+    let validators = if cache_path.exists() {
+        read_validators(cache_path)
+    } else {
+        CacheValidators::default()
+    };
+
+    let mut request = reqwest::Client::new().get(url);
+    if let Some(etag) = &validators.etag {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = &validators.last_modified {
+        request = request.header(IF_MODIFIED_SINCE, last_modified);
+    }
+
+    info!(url, "Fetching component registry");
+    let response = request
+        .send()
+        .await
+        .with_context(|| format!("Failed to fetch registry from '{url}'"))?;
+
+    if response.status() == StatusCode::NOT_MODIFIED {
+        if !cache_path.exists() {
+            bail!("Registry server answered 304 Not Modified but there is no cached registry");
+        }
+        debug!(url, "Registry not modified, refreshing cache timestamp");
+        std::fs::File::options()
+            .append(true)
+            .open(cache_path)
+            .and_then(|file| file.set_modified(SystemTime::now()))
+            .with_context(|| format!("Failed to update '{}'", cache_path.display()))?;
+        return read_cached_registry(cache_path);
+    }
+
+    let response = response
+        .error_for_status()
+        .with_context(|| format!("Failed to fetch registry from '{url}'"))?;
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let validators = CacheValidators {
+        etag: header(ETAG),
+        last_modified: header(LAST_MODIFIED),
+    };
+    let body = response.text().await?;
+
+    // Only a registry that parses replaces the cache
+    let components =
+        parse_registry(&body).with_context(|| format!("Registry served by '{url}' is invalid"))?;
+    write_cache(cache_path, &body, &validators)?;
+    info!(url, components = components.len(), "Updated registry cache");
+    Ok(components)
+}
+
+fn cache_age(cache_path: &Path) -> Option<Duration> {
+    let modified = std::fs::metadata(cache_path).ok()?.modified().ok()?;
+    // A modification time in the future counts as fresh
+    Some(modified.elapsed().unwrap_or_default())
+}
+
+fn read_cached_registry(cache_path: &Path) -> Result<Vec<RegistryComponent>> {
+    let registry_json = std::fs::read_to_string(cache_path)
+        .with_context(|| format!("Failed to read cached registry '{}'", cache_path.display()))?;
+    parse_registry(&registry_json)
+        .with_context(|| format!("Failed to parse cached registry '{}'", cache_path.display()))
+}
+
+fn validators_path(cache_path: &Path) -> PathBuf {
+    let mut path = cache_path.as_os_str().to_owned();
+    path.push(".validators.json");
+    PathBuf::from(path)
+}
+
+fn read_validators(cache_path: &Path) -> CacheValidators {
+    std::fs::read_to_string(validators_path(cache_path))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn write_cache(cache_path: &Path, body: &str, validators: &CacheValidators) -> Result<()> {
+    if let Some(parent) = cache_path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory '{}'", parent.display()))?;
+    }
+
+    let mut temp_path = cache_path.as_os_str().to_owned();
+    temp_path.push(".partial");
+    let temp_path = PathBuf::from(temp_path);
+    std::fs::write(&temp_path, body)
+        .and_then(|_| std::fs::rename(&temp_path, cache_path))
+        .with_context(|| format!("Failed to write registry cache '{}'", cache_path.display()))?;
+
+    std::fs::write(
+        validators_path(cache_path),
+        serde_json::to_string(validators)?,
+    )
+    .with_context(|| format!("Failed to write registry cache '{}'", cache_path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY_JSON: &str = r#"[{"name": "fetch", "description": "Fetch URLs", "uri": "oci://ghcr.io/microsoft/fetch-rs:0.1.0"}]"#;
+    // Nothing listens on the discard port, so requests fail fast
+    const UNREACHABLE_URL: &str = "http://127.0.0.1:9/registry.json";
+
+    #[tokio::test]
+    async fn test_fresh_cache_skips_network() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("registry.json");
+        std::fs::write(&cache_path, REGISTRY_JSON).unwrap();
+
+        let components = fetch_registry(UNREACHABLE_URL, &cache_path, 3600)
+            .await
+            .unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].name, "fetch");
+    }
+
+    #[tokio::test]
+    async fn test_stale_cache_used_when_network_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("registry.json");
+        std::fs::write(&cache_path, REGISTRY_JSON).unwrap();
+
+        let components = fetch_registry(UNREACHABLE_URL, &cache_path, 0)
+            .await
+            .unwrap();
+        assert_eq!(components[0].name, "fetch");
+    }
+
+    #[tokio::test]
+    async fn test_network_failure_without_cache_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("registry.json");
+
+        assert!(fetch_registry(UNREACHABLE_URL, &cache_path, 3600)
+            .await
+            .is_err());
+        assert!(!cache_path.exists());
+    }
+
+    #[test]
+    fn test_validators_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("cache").join("registry.json");
+        let validators = CacheValidators {
+            etag: Some("\"abc\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
+        };
+
+        write_cache(&cache_path, REGISTRY_JSON, &validators).unwrap();
+        let read = read_validators(&cache_path);
+        assert_eq!(read.etag, validators.etag);
+        assert_eq!(read.last_modified, validators.last_modified);
+        assert_eq!(read_cached_registry(&cache_path).unwrap().len(), 1);
+    }
+
+    /// Answer a single HTTP request with `head` and `body`, returning the URL to request and a
+    /// handle resolving to the request that arrived, with header names lowercased
+    async fn serve_once(
+        head: &'static str,
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/registry.json", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0; 4096];
+            let read = socket.read(&mut request).await.unwrap();
+            let response = format!(
+                "{head}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request[..read]).to_lowercase()
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn test_not_modified_sends_validators_and_keeps_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("registry.json");
+        let validators = CacheValidators {
+            etag: Some("\"abc\"".to_string()),
+            last_modified: Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
+        };
+        write_cache(&cache_path, REGISTRY_JSON, &validators).unwrap();
+        let stale = SystemTime::now() - Duration::from_secs(7200);
+        std::fs::File::options()
+            .append(true)
+            .open(&cache_path)
+            .unwrap()
+            .set_modified(stale)
+            .unwrap();
+
+        let (url, request) = serve_once("HTTP/1.1 304 Not Modified", "").await;
+        let components = fetch_registry(&url, &cache_path, 3600).await.unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].name, "fetch");
+
+        let request = request.await.unwrap();
+        assert!(request.contains("if-none-match: \"abc\"\r\n"), "{request}");
+        assert!(
+            request.contains("if-modified-since: wed, 21 oct 2015 07:28:00 gmt\r\n"),
+            "{request}"
+        );
+        assert_eq!(std::fs::read_to_string(&cache_path).unwrap(), REGISTRY_JSON);
+        assert!(cache_age(&cache_path).unwrap() < Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_modified_registry_replaces_cache_and_validators() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("registry.json");
+        let body = r#"[{"name": "time", "description": "Time", "uri": "oci://ghcr.io/microsoft/time:1.0.0"}]"#;
+
+        let (url, request) = serve_once(
+            "HTTP/1.1 200 OK\r\nETag: \"def\"\r\nLast-Modified: Thu, 22 Oct 2015 07:28:00 GMT",
+            body,
+        )
+        .await;
+        let components = fetch_registry(&url, &cache_path, 3600).await.unwrap();
+        assert_eq!(components[0].name, "time");
+
+        let request = request.await.unwrap();
+        assert!(!request.contains("if-none-match"), "{request}");
+        assert_eq!(std::fs::read_to_string(&cache_path).unwrap(), body);
+        let validators = read_validators(&cache_path);
+        assert_eq!(validators.etag.as_deref(), Some("\"def\""));
+        assert_eq!(
+            validators.last_modified.as_deref(),
+            Some("Thu, 22 Oct 2015 07:28:00 GMT")
+        );
+    }
+}
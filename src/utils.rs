@@ -4,7 +4,7 @@
 //! Utility functions for the wassette command
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Context, Result};
 
@@ -79,10 +79,24 @@ pub fn load_env_file(path: &PathBuf) -> Result<HashMap<String, String>, anyhow::
     Ok(env_vars)
 }
 
-/// Load and parse the component registry JSON
+/// Project-local registry file merged over the bundled component registry
+const LOCAL_REGISTRY_FILE: &str = "wassette-registry.json";
+
+/// Load and parse the component registry JSON.
+///
+/// If a `wassette-registry.json` file exists in the current directory, its entries are
+/// merged over the bundled registry, replacing bundled entries with the same name.
 pub fn load_component_registry() -> Result<Vec<registry::RegistryComponent>> {
     const COMPONENT_REGISTRY: &str = include_str!("../component-registry.json");
-    registry::parse_registry(COMPONENT_REGISTRY).context("Failed to parse component registry")
+    let components = registry::parse_registry_with_dedup(COMPONENT_REGISTRY)
+        .context("Failed to parse component registry")?;
+
+    let local_path = Path::new(LOCAL_REGISTRY_FILE);
+    if !local_path.exists() {
+        return Ok(components);
+    }
+    let local_components = registry::load_local_registry(local_path)?;
+    Ok(registry::merge_registries(&components, &local_components))
 }
 
 /// Formats build information similar to agentgateway's version output
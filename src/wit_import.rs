@@ -0,0 +1,195 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT license.
+
+//! Generating WIT `import` stanzas for registry components
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use wit_component::{DecodedWasm, Output, WitPrinter};
+use wit_parser::WorldItem;
+
+use crate::registry::RegistryComponent;
+
+/// Generate an `import <name>: interface { ... }` block describing what `component` exports.
+///
+/// The component's URI must point at a local `.wasm` file, either as a `file://` URI or a plain
+/// path. The import is named after the world recorded in the component's `package-docs`
+/// section, falling back to the registry name when the component carries no such section.
+/// Types that exported functions borrow from other interfaces are referenced by name only.
+#[allow(dead_code)] // Not wired into the CLI yet, bundled registry components are all oci:// URIs
+pub fn generate_wit_import(component: &RegistryComponent) -> Result<String> {
+    let path = local_wasm_path(&component.uri)?;
+    let bytes = std::fs::read(&path)
+        .with_context(|| format!("Failed to read component '{}'", path.display()))?;
+
+    let (resolve, world_id) = match wit_component::decode(&bytes)
+        .with_context(|| format!("Failed to decode component '{}'", path.display()))?
+    {
+        DecodedWasm::Component(resolve, world_id) => (resolve, world_id),
+        DecodedWasm::WitPackage(..) => {
+            bail!("'{}' is a WIT package, not a component", path.display())
+        }
+    };
+
+    let name = component2json::extract_package_docs(&bytes)
+        .and_then(|docs| single_world_name(&docs))
+        .unwrap_or_else(|| to_kebab_case(&component.name));
+
+    let mut printer = WitPrinter::default();
+    printer.output.keyword("import");
+    printer.output.str(" ");
+    printer.output.str(&name);
+    printer.output.str(": ");
+    printer.output.keyword("interface");
+    printer.output.indent_start();
+    let mut first = true;
+    for item in resolve.worlds[world_id].exports.values() {
+        if !first {
+            printer.output.newline();
+        }
+        first = false;
+        match item {
+            WorldItem::Function(func) => {
+                printer.output.str(func.item_name());
+                printer.output.str(": ");
+                printer.output.keyword("func");
+                printer.output.str("(");
+                for (i, (param, ty)) in func.params.iter().enumerate() {
+                    if i > 0 {
+                        printer.output.str(", ");
+                    }
+                    printer.output.param(param);
+                    printer.output.str(": ");
+                    printer.print_type_name(&resolve, ty)?;
+                }
+                printer.output.str(")");
+                if let Some(result) = &func.result {
+                    printer.output.str(" -> ");
+                    printer.print_type_name(&resolve, result)?;
+                }
+                printer.output.semicolon();
+            }
+            WorldItem::Interface { id, .. } => printer.print_interface(&resolve, *id)?,
+            WorldItem::Type(_) => {}
+        }
+    }
+    printer.output.indent_end();
+
+    Ok(printer.output.to_string())
+}
+
+/// Resolve a `file://` URI or plain path to a `.wasm` file on disk
+fn local_wasm_path(uri: &str) -> Result<PathBuf> {
+    if uri.starts_with("oci://") || uri.starts_with("https://") || uri.starts_with("http://") {
+        bail!("'{uri}' is not a local file, install the component first and use its path");
+    }
+    let path = PathBuf::from(uri.strip_prefix("file://").unwrap_or(uri));
+    if path.extension().is_none_or(|ext| ext != "wasm") {
+        bail!("'{uri}' does not point to a .wasm file");
+    }
+    Ok(path)
+}
+
+/// The world name from a `package-docs` section, if it describes exactly one world
+fn single_world_name(package_docs: &serde_json::Value) -> Option<String> {
+    let worlds = package_docs.get("worlds")?.as_object()?;
+    match worlds.keys().collect::<Vec<_>>().as_slice() {
+        [name] => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+/// Turn a registry name such as `Fetch RS` into a WIT identifier like `fetch-rs`
+fn to_kebab_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CALCULATOR_WAT: &str = r#"
+        (component
+          (core module $m
+            (func (export "add") (param i32 i32) (result i32)
+              local.get 0
+              local.get 1
+              i32.add))
+          (core instance $i (instantiate $m))
+          (func $add (param "a" u32) (param "b" u32) (result u32)
+            (canon lift (core func $i "add")))
+          (instance $math (export "add" (func $add)))
+          (export "example:calc/math" (instance $math))
+          (export "sum" (func $add)))
+    "#;
+
+    fn component(name: &str, uri: &str) -> RegistryComponent {
+        RegistryComponent {
+            name: name.to_string(),
+            description: "Test component".to_string(),
+            uri: uri.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_generate_wit_import_lists_exports() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("calculator.wasm");
+        std::fs::write(&path, wat::parse_str(CALCULATOR_WAT).unwrap()).unwrap();
+
+        let uri = format!("file://{}", path.display());
+        let wit = generate_wit_import(&component("Simple Calculator", &uri)).unwrap();
+        assert!(
+            wit.starts_with("import simple-calculator: interface {\n"),
+            "{wit}"
+        );
+        // One function comes from the exported interface, the other is exported directly
+        assert!(
+            wit.contains("  add: func(a: u32, b: u32) -> u32;\n"),
+            "{wit}"
+        );
+        assert!(
+            wit.contains("  sum: func(a: u32, b: u32) -> u32;\n"),
+            "{wit}"
+        );
+        assert!(wit.ends_with("}\n"), "{wit}");
+    }
+
+    #[test]
+    fn test_generate_wit_import_rejects_remote_and_non_wasm_uris() {
+        for uri in [
+            "oci://ghcr.io/microsoft/fetch-rs:0.1.0",
+            "https://example.com/component.wasm",
+            "file:///tmp/component.txt",
+        ] {
+            assert!(
+                generate_wit_import(&component("fetch", uri)).is_err(),
+                "{uri} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_wit_import_rejects_invalid_wasm() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.wasm");
+        std::fs::write(&path, b"not wasm").unwrap();
+
+        let err =
+            generate_wit_import(&component("broken", &path.display().to_string())).unwrap_err();
+        assert!(err.to_string().contains("Failed to decode"), "{err:#}");
+    }
+
+    #[test]
+    fn test_to_kebab_case() {
+        assert_eq!(to_kebab_case("Fetch RS"), "fetch-rs");
+        assert_eq!(to_kebab_case("get_weather"), "get-weather");
+        assert_eq!(to_kebab_case("filesystem"), "filesystem");
+    }
+}